@@ -13,7 +13,7 @@ use tokio::fs::{read_to_string, write};
 
 pub const CONFIG_FILE: &str = "config.toml";
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct ConfigFile {
     #[serde(
@@ -42,7 +42,7 @@ pub struct SyncConfig {
     pub interval: Duration,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct NetworkConfig {
     pub swarm: SwarmConfig,
@@ -73,7 +73,7 @@ impl NetworkConfig {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct ServerConfig {
     pub listen: Vec<Multiaddr>,
@@ -105,7 +105,7 @@ impl ServerConfig {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct DataStoreConfig {
     pub path: Utf8PathBuf,
@@ -118,7 +118,7 @@ impl DataStoreConfig {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct BlobStoreConfig {
     pub path: Utf8PathBuf,
@@ -165,7 +165,13 @@ impl ConfigFile {
             )
         })?;
 
-        toml::from_str(&content).map_err(Into::into)
+        Self::from_toml_str(&content)
+    }
+
+    /// Deserializes a config straight from TOML text, with no file
+    /// involved; validation tooling round-trips through this.
+    pub fn from_toml_str(content: &str) -> EyreResult<Self> {
+        toml::from_str(content).map_err(Into::into)
     }
 
     pub async fn save(&self, dir: &Utf8Path) -> EyreResult<()> {
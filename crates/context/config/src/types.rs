@@ -333,6 +333,11 @@ pub enum Capability {
     Proxy,
 }
 
+impl Capability {
+    /// Every capability that can be granted or revoked.
+    pub const ALL: [Self; 3] = [Self::ManageApplication, Self::ManageMembers, Self::Proxy];
+}
+
 #[derive(Eq, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Signed<T> {
     payload: Repr<Box<[u8]>>,
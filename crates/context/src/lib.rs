@@ -1,10 +1,11 @@
 #![expect(clippy::unwrap_in_result, reason = "Repr transmute")]
 
 use core::error::Error;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::io::Error as IoError;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use calimero_blobstore::{Blob, BlobManager, Size};
 use calimero_context_config::client::config::ClientConfig;
@@ -16,7 +17,7 @@ use calimero_context_config::repr::{Repr, ReprBytes, ReprTransmute};
 use calimero_context_config::types::{
     Application as ApplicationConfig, ApplicationMetadata as ApplicationMetadataConfig,
     ApplicationSource as ApplicationSourceConfig, Capability, ContextIdentity, ContextStorageEntry,
-    ProposalId,
+    ProposalId, SignerId,
 };
 use calimero_context_config::{Proposal, ProposalAction, ProposalWithApprovals};
 use calimero_network::client::NetworkClient;
@@ -43,12 +44,13 @@ use calimero_store::types::{
 };
 use calimero_store::Store;
 use camino::Utf8PathBuf;
-use eyre::{bail, OptionExt, Result as EyreResult};
+use eyre::{bail, OptionExt, Report, Result as EyreResult};
 use futures_util::{AsyncRead, TryStreamExt};
 use rand::rngs::StdRng;
 use rand::seq::IteratorRandom;
 use rand::SeedableRng;
 use reqwest::{Client as ReqClient, Url};
+use thiserror::Error as ThisError;
 use tokio::fs::File;
 use tokio::sync::{oneshot, RwLock};
 use tokio_util::compat::TokioAsyncReadCompatExt;
@@ -74,6 +76,18 @@ struct State {
     pending_catchup: HashSet<ContextId>,
 }
 
+/// Failures of [`ContextManager::invite_to_context`] that callers may
+/// want to react to rather than merely report.
+#[derive(Debug, ThisError)]
+#[non_exhaustive]
+pub enum InviteToContextError {
+    /// A concurrent membership change invalidated the nonce the invite
+    /// was signed with; fetching a fresh nonce and minting again usually
+    /// succeeds.
+    #[error("invite conflicted with a concurrent membership change")]
+    MembershipConflict,
+}
+
 impl ContextManager {
     pub async fn start(
         config: &ContextConfig,
@@ -352,6 +366,19 @@ impl ContextManager {
         let (context_id, invitee_id, protocol, network_id, contract_id) =
             invitation_payload.parts()?;
 
+        // The minting node may have stamped a deadline into the payload;
+        // a lapsed invitation is refused on whichever node accepts it.
+        if let Some(expires_at) = invitation_payload.expires_at()? {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+
+            if expires_at <= now {
+                bail!("the invitation has expired")
+            }
+        }
+
         if identity_secret.public_key() != invitee_id {
             bail!("identity mismatch")
         }
@@ -412,6 +439,7 @@ impl ContextManager {
         context_id: ContextId,
         inviter_id: PublicKey,
         invitee_id: PublicKey,
+        expires_at: Option<u64>,
     ) -> EyreResult<Option<ContextInvitationPayload>> {
         let handle = self.store.handle();
 
@@ -452,7 +480,19 @@ impl ContextManager {
                 &[invitee_id.rt().expect("infallible conversion")],
             )
             .send(requester_secret, nonce)
-            .await?;
+            .await
+            .map_err(|err| {
+                // The transports don't type nonce conflicts, so they are
+                // recognized here, where the contract semantics live, and
+                // surfaced as a typed error callers can match on.
+                let message = format!("{err:?}").to_lowercase();
+
+                if message.contains("conflict") || message.contains("nonce") {
+                    return InviteToContextError::MembershipConflict.into();
+                }
+
+                Report::from(err)
+            })?;
 
         let invitation_payload = ContextInvitationPayload::new(
             context_id,
@@ -460,6 +500,7 @@ impl ContextManager {
             context_config.protocol.into_string().into(),
             context_config.network.into_string().into(),
             context_config.contract.into_string().into(),
+            expires_at,
         )?;
 
         Ok(Some(invitation_payload))
@@ -1662,6 +1703,30 @@ impl ContextManager {
         Ok(())
     }
 
+    pub async fn get_capabilities(
+        &self,
+        context_id: ContextId,
+        identities: &[ContextIdentity],
+    ) -> EyreResult<BTreeMap<SignerId, Vec<Capability>>> {
+        let handle = self.store.handle();
+
+        let Some(context_config) = handle.get(&ContextConfigKey::new(context_id))? else {
+            bail!("Context not found");
+        };
+
+        let privileges = self
+            .config_client
+            .query::<ContextConfigEnv>(
+                context_config.protocol.as_ref().into(),
+                context_config.network.as_ref().into(),
+                context_config.contract.as_ref().into(),
+            )
+            .privileges(context_id.rt().expect("infallible conversion"), identities)
+            .await?;
+
+        Ok(privileges)
+    }
+
     pub async fn revoke_capabilities(
         &self,
         context_id: ContextId,
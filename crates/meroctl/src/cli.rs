@@ -1,4 +1,5 @@
 use std::process::ExitCode;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use bootstrap::BootstrapCommand;
 use camino::Utf8PathBuf;
@@ -9,8 +10,9 @@ use eyre::Report as EyreReport;
 use serde::{Serialize, Serializer};
 use thiserror::Error as ThisError;
 
+use crate::common::{AuthScheme, DEFAULT_RETRIES, DEFAULT_TIMEOUT_SECS};
 use crate::defaults;
-use crate::output::{Format, Output, Report};
+use crate::output::{set_theme, themed, Format, Output, Report, Theme};
 
 mod app;
 mod bootstrap;
@@ -70,12 +72,63 @@ pub struct RootArgs {
     #[arg(env = "CALIMERO_HOME", hide_env_values = true)]
     pub home: Utf8PathBuf,
 
-    /// Name of node
-    #[arg(short, long, value_name = "NAME")]
+    /// Name of node; discovered automatically when the home directory
+    /// holds exactly one node
+    #[arg(short, long, value_name = "NAME", default_value = "")]
     pub node_name: String,
 
-    #[arg(long, value_name = "FORMAT", default_value_t, value_enum)]
-    pub output_format: Format,
+    /// Force the output format; left unset, a TTY gets human output and
+    /// a pipe gets JSON
+    #[arg(long, visible_alias = "output", value_name = "FORMAT", value_enum)]
+    pub output_format: Option<Format>,
+
+    /// Append every reported value to this file as JSON lines, in
+    /// addition to the terminal output
+    #[arg(long, value_name = "PATH")]
+    pub output_file: Option<Utf8PathBuf>,
+
+    /// Color theme for human-readable tables; `mono` disables color
+    #[arg(long, value_name = "THEME", default_value_t, value_enum)]
+    pub theme: Theme,
+
+    /// PEM file with an extra CA certificate to trust when talking to
+    /// the node
+    #[arg(long, value_name = "PATH")]
+    pub ca_cert: Option<Utf8PathBuf>,
+
+    /// PEM file with the client certificate to present for mutual TLS
+    #[arg(long, value_name = "PATH", requires = "client_key")]
+    pub client_cert: Option<Utf8PathBuf>,
+
+    /// PEM file with the private key for `--client-cert`
+    #[arg(long, value_name = "PATH", requires = "client_cert")]
+    pub client_key: Option<Utf8PathBuf>,
+
+    /// Log every admin API call - method, URL, request body, status and
+    /// response body - to stderr, with obvious secrets redacted
+    #[arg(long)]
+    pub trace_http: bool,
+
+    /// How requests authenticate to the node: `signed` signs each
+    /// request with the node identity (the default), `bearer` sends
+    /// `--auth-token`, `dev` sends no credentials
+    #[arg(long, value_name = "SCHEME", default_value_t, value_enum)]
+    pub auth: AuthScheme,
+
+    /// Token for `--auth bearer`
+    #[arg(long, value_name = "TOKEN")]
+    #[arg(env = "CALIMERO_AUTH_TOKEN", hide_env_values = true)]
+    pub auth_token: Option<String>,
+
+    /// Seconds before a single request attempt to the node times out
+    #[arg(long, value_name = "SECONDS", default_value_t = DEFAULT_TIMEOUT_SECS)]
+    pub timeout: u64,
+
+    /// How many times a request is retried, with exponential backoff,
+    /// after a transient failure (connection refused, timeout, 5xx);
+    /// 4xx responses are never retried
+    #[arg(long, value_name = "COUNT", default_value_t = DEFAULT_RETRIES)]
+    pub retries: u32,
 }
 
 impl RootArgs {
@@ -83,25 +136,70 @@ impl RootArgs {
         Self {
             home,
             node_name,
-            output_format,
+            output_format: Some(output_format),
+            output_file: None,
+            theme: Theme::Default,
+            ca_cert: None,
+            client_cert: None,
+            client_key: None,
+            trace_http: false,
+            auth: AuthScheme::Signed,
+            auth_token: None,
+            timeout: DEFAULT_TIMEOUT_SECS,
+            retries: DEFAULT_RETRIES,
         }
     }
 }
 
+/// Exit code for a command that succeeded without changing anything,
+/// e.g. granting a capability the member already holds. Scripts use it
+/// to detect idempotent no-ops; errors keep their non-zero codes.
+pub const EXIT_NO_OP: u8 = 3;
+
 pub struct Environment {
     pub args: RootArgs,
     pub output: Output,
+    no_op: AtomicBool,
 }
 
 impl Environment {
     pub const fn new(args: RootArgs, output: Output) -> Self {
-        Self { args, output }
+        Self {
+            args,
+            output,
+            no_op: AtomicBool::new(false),
+        }
+    }
+
+    /// Records that the command completed without changing anything, so
+    /// the process exits with [`EXIT_NO_OP`] instead of 0.
+    pub fn mark_no_op(&self) {
+        self.no_op.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_no_op(&self) -> bool {
+        self.no_op.load(Ordering::Relaxed)
     }
 }
 
 impl RootCommand {
-    pub async fn run(self) -> Result<(), CliError> {
-        let output = Output::new(self.args.output_format);
+    pub async fn run(self) -> Result<ExitCode, CliError> {
+        set_theme(self.args.theme);
+
+        let format = self.args.output_format.unwrap_or_else(Format::detect);
+
+        let output = Output::new(format, self.args.output_file.clone());
+
+        crate::common::init_client(&self.args).map_err(CliError::Other)?;
+
+        crate::common::init_auth(&self.args).map_err(CliError::Other)?;
+
+        crate::common::init_retries(&self.args);
+
+        if self.args.trace_http {
+            crate::common::enable_http_trace();
+        }
+
         let environment = Environment::new(self.args, output);
 
         let result = match self.action {
@@ -122,7 +220,11 @@ impl RootCommand {
             return Err(err);
         }
 
-        Ok(())
+        if environment.is_no_op() {
+            return Ok(ExitCode::from(EXIT_NO_OP));
+        }
+
+        Ok(ExitCode::SUCCESS)
     }
 }
 
@@ -151,7 +253,7 @@ impl From<CliError> for ExitCode {
 impl Report for CliError {
     fn report(&self) {
         let mut table = Table::new();
-        let _ = table.set_header(vec![Cell::new("ERROR").fg(Color::Red)]);
+        let _ = table.set_header(vec![Cell::new("ERROR").fg(themed(Color::Red))]);
         let _ = table.add_row(vec![match self {
             CliError::ApiError(e) => format!("API Error ({}): {}", e.status_code, e.message),
             CliError::Other(e) => format!("Error: {}", e),
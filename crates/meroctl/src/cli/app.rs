@@ -8,7 +8,7 @@ use crate::cli::app::get::GetCommand;
 use crate::cli::app::install::InstallCommand;
 use crate::cli::app::list::ListCommand;
 use crate::cli::Environment;
-use crate::output::Report;
+use crate::output::{themed, Report};
 
 mod get;
 pub mod install;
@@ -45,8 +45,8 @@ impl Report for Application {
     fn report(&self) {
         let mut table = Table::new();
         let _ = table.set_header(vec![
-            Cell::new("Field").fg(Color::Blue),
-            Cell::new("Value").fg(Color::Blue),
+            Cell::new("Field").fg(themed(Color::Blue)),
+            Cell::new("Value").fg(themed(Color::Blue)),
         ]);
         let _ = table.add_row(vec!["Application ID", &self.id.to_string()]);
         let _ = table.add_row(vec!["Size", &self.size.to_string()]);
@@ -2,10 +2,11 @@ use calimero_primitives::application::ApplicationId;
 use calimero_server_primitives::admin::GetApplicationResponse;
 use clap::{Parser, ValueEnum};
 use eyre::Result as EyreResult;
-use reqwest::Client;
 
 use crate::cli::Environment;
-use crate::common::{do_request, fetch_multiaddr, load_config, multiaddr_to_url, RequestType};
+use crate::common::{
+    client, do_request, fetch_multiaddr, load_config, multiaddr_to_url, RequestType,
+};
 use crate::output::Report;
 
 #[derive(Parser, Debug)]
@@ -39,7 +40,7 @@ impl GetCommand {
         )?;
 
         let response: GetApplicationResponse = do_request(
-            &Client::new(),
+            &client(),
             url,
             None::<()>,
             &config.identity,
@@ -9,14 +9,15 @@ use comfy_table::{Cell, Color, Table};
 use eyre::{bail, Result as EyreResult};
 use notify::event::ModifyKind;
 use notify::{EventKind, RecursiveMode, Watcher};
-use reqwest::Client;
 use tokio::runtime::Handle;
 use tokio::sync::mpsc;
 use url::Url;
 
 use crate::cli::Environment;
-use crate::common::{do_request, fetch_multiaddr, load_config, multiaddr_to_url, RequestType};
-use crate::output::{ErrorLine, InfoLine, Report};
+use crate::common::{
+    client, do_request, fetch_multiaddr, load_config, multiaddr_to_url, RequestType,
+};
+use crate::output::{themed, ErrorLine, InfoLine, Report};
 
 #[derive(Debug, Parser)]
 #[command(about = "Install an application")]
@@ -40,7 +41,7 @@ pub struct InstallCommand {
 impl Report for InstallApplicationResponse {
     fn report(&self) {
         let mut table = Table::new();
-        let _ = table.set_header(vec![Cell::new("Application Installed").fg(Color::Green)]);
+        let _ = table.set_header(vec![Cell::new("Application Installed").fg(themed(Color::Green))]);
         let _ = table.add_row(vec![format!(
             "Application ID: {}",
             self.data.application_id
@@ -92,7 +93,7 @@ impl InstallCommand {
         )?;
 
         let response: InstallApplicationResponse = do_request(
-            &Client::new(),
+            &client(),
             url,
             Some(request),
             &config.identity,
@@ -2,11 +2,12 @@ use calimero_server_primitives::admin::ListApplicationsResponse;
 use clap::Parser;
 use comfy_table::{Cell, Color, Table};
 use eyre::Result as EyreResult;
-use reqwest::Client;
 
 use crate::cli::Environment;
-use crate::common::{do_request, fetch_multiaddr, load_config, multiaddr_to_url, RequestType};
-use crate::output::Report;
+use crate::common::{
+    client, do_request, fetch_multiaddr, load_config, multiaddr_to_url, RequestType,
+};
+use crate::output::{themed, Report};
 
 #[derive(Debug, Parser)]
 #[command(about = "List installed applications")]
@@ -16,10 +17,10 @@ impl Report for ListApplicationsResponse {
     fn report(&self) {
         let mut table = Table::new();
         let _ = table.set_header(vec![
-            Cell::new("Application ID").fg(Color::Blue),
-            Cell::new("Source").fg(Color::Blue),
-            Cell::new("Size").fg(Color::Blue),
-            Cell::new("Blob ID").fg(Color::Blue),
+            Cell::new("Application ID").fg(themed(Color::Blue)),
+            Cell::new("Source").fg(themed(Color::Blue)),
+            Cell::new("Size").fg(themed(Color::Blue)),
+            Cell::new("Blob ID").fg(themed(Color::Blue)),
         ]);
 
         for app in &self.data.apps {
@@ -36,7 +37,7 @@ impl Report for ListApplicationsResponse {
             if !app.metadata.is_empty() {
                 let mut meta_table = Table::new();
                 let _ = meta_table.set_header(vec![
-                    Cell::new(format!("Metadata for {}", app.id)).fg(Color::Green)
+                    Cell::new(format!("Metadata for {}", app.id)).fg(themed(Color::Green))
                 ]);
 
                 for data in &app.metadata {
@@ -53,7 +54,7 @@ impl ListCommand {
         let config = load_config(&environment.args.home, &environment.args.node_name).await?;
 
         let response: ListApplicationsResponse = do_request(
-            &Client::new(),
+            &client(),
             multiaddr_to_url(fetch_multiaddr(&config)?, "admin-api/dev/applications")?,
             None::<()>,
             &config.identity,
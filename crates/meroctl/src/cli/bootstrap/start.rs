@@ -8,7 +8,6 @@ use calimero_primitives::identity::{PrivateKey, PublicKey};
 use camino::Utf8PathBuf;
 use clap::Parser;
 use eyre::{bail, Result as EyreResult};
-use reqwest::Client;
 use tokio::fs::{create_dir_all, File};
 use tokio::io::copy;
 use tokio::process::{Child, Command};
@@ -16,10 +15,10 @@ use tokio::time::sleep;
 
 use crate::cli::app::install::InstallCommand;
 use crate::cli::context::create::create_context;
-use crate::cli::context::invite::InviteCommand;
+use crate::cli::context::invite::{Invitee, InviteCommand};
 use crate::cli::context::join::JoinCommand;
 use crate::cli::{Environment, RootArgs};
-use crate::common::{fetch_multiaddr, load_config};
+use crate::common::{client, fetch_multiaddr, load_config};
 use crate::output::Output;
 
 #[derive(Parser, Debug)]
@@ -68,7 +67,7 @@ impl StartBootstrapCommand {
                 node1_name.to_owned(),
                 crate::output::Format::Json,
             ),
-            Output::new(crate::output::Format::Json),
+            Output::new(crate::output::Format::Json, None),
         );
 
         let node1_process = self
@@ -96,7 +95,7 @@ impl StartBootstrapCommand {
                 node2_name.to_owned(),
                 crate::output::Format::Json,
             ),
-            Output::new(crate::output::Format::Json),
+            Output::new(crate::output::Format::Json, None),
         );
 
         let node2_process = self
@@ -196,10 +195,15 @@ impl StartBootstrapCommand {
         );
 
         let invite_command = InviteCommand {
-            context: context_id.as_str().parse()?,
-            inviter: inviter_public_key.as_str().parse()?,
-            invitee_id: invitee_private_key.public_key(),
+            context: Some(context_id.as_str().parse()?),
+            inviter: Some(inviter_public_key.as_str().parse()?),
+            invitee_id: Some(Invitee::Key(invitee_private_key.public_key())),
             name: None,
+            from_csv: None,
+            results: None,
+            // The bootstrap flow already verified the node is up.
+            no_precheck: true,
+            quiet: true,
         };
         let invitation_payload = invite_command.invite(invitor_environment).await?;
 
@@ -288,7 +292,7 @@ impl StartBootstrapCommand {
     ) -> EyreResult<(ContextId, PublicKey, ApplicationId)> {
         let config = load_config(&environment.args.home, &environment.args.node_name).await?;
         let multiaddr = fetch_multiaddr(&config)?;
-        let client = Client::new();
+        let client = client();
 
         let install_command = InstallCommand {
             path: self.app_path.clone(),
@@ -369,7 +373,7 @@ impl StartBootstrapCommand {
     }
 
     async fn download_wasm(&self, url: &str, output_path: Utf8PathBuf) -> EyreResult<()> {
-        let client = Client::new();
+        let client = client();
 
         let response = client
             .get(url)
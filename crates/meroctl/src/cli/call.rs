@@ -12,9 +12,10 @@ use serde_json::{json, Value};
 
 use crate::cli::Environment;
 use crate::common::{
-    do_request, fetch_multiaddr, load_config, multiaddr_to_url, resolve_alias, RequestType,
+    client, do_request, fetch_multiaddr, load_config, multiaddr_to_url, resolve_alias,
+    RequestType,
 };
-use crate::output::Report;
+use crate::output::{themed, Report};
 
 pub const EXAMPLES: &str = r"
   # Execute a RPC method call
@@ -67,7 +68,7 @@ fn serde_value(s: &str) -> serde_json::Result<Value> {
 impl Report for Response {
     fn report(&self) {
         let mut table = Table::new();
-        let _ = table.set_header(vec![Cell::new("RPC Response").fg(Color::Blue)]);
+        let _ = table.set_header(vec![Cell::new("RPC Response").fg(themed(Color::Blue))]);
 
         match &self.body {
             ResponseBody::Result(result) => {
@@ -116,7 +117,7 @@ impl CallCommand {
             payload,
         );
 
-        let client = reqwest::Client::new();
+        let client = client();
         let response: Response = do_request(
             &client,
             url,
@@ -4,30 +4,57 @@ use clap::{Parser, Subcommand};
 use comfy_table::{Cell, Table};
 use const_format::concatcp;
 use eyre::Result as EyreResult;
+use thiserror::Error as ThisError;
 
+use crate::cli::context::accept::AcceptInviteCommand;
 use crate::cli::context::alias::UseCommand;
+use crate::cli::context::capabilities::CapabilitiesCommand;
 use crate::cli::context::create::CreateCommand;
 use crate::cli::context::delete::DeleteCommand;
 use crate::cli::context::get::GetCommand;
+use crate::cli::context::grant::GrantCommand;
 use crate::cli::context::identity::ContextIdentityCommand;
 use crate::cli::context::invite::InviteCommand;
 use crate::cli::context::join::JoinCommand;
 use crate::cli::context::list::ListCommand;
+use crate::cli::context::member::MemberCommand;
+use crate::cli::context::permissions::PermissionsCommand;
+use crate::cli::context::revoke::RevokeCommand;
 use crate::cli::context::update::UpdateCommand;
 use crate::cli::context::watch::WatchCommand;
+use crate::cli::context::whoami::WhoamiCommand;
 use crate::cli::Environment;
-use crate::output::Report;
+use crate::output::{themed, Report};
 
+mod accept;
 mod alias;
+mod capabilities;
+mod capability;
 pub mod create;
 mod delete;
 mod get;
+mod grant;
 mod identity;
 pub mod invite;
 pub mod join;
 mod list;
+mod member;
+mod permissions;
+mod revoke;
 mod update;
 mod watch;
+mod whoami;
+
+/// A capability name that couldn't be mapped to a known capability.
+#[derive(Clone, Debug, ThisError)]
+#[error("unknown capability `{0}` (expected one of: manage-application, manage-members, proxy)")]
+pub struct InvalidCapability(String);
+
+impl InvalidCapability {
+    pub(crate) fn new(name: &str) -> Self {
+        Self(name.to_owned())
+    }
+}
 
 pub const EXAMPLES: &str = r"
   # List all contexts
@@ -58,7 +85,15 @@ pub enum ContextSubCommands {
     Create(Box<CreateCommand>),
     Join(JoinCommand),
     Invite(InviteCommand),
+    #[command(alias = "accept")]
+    AcceptInvite(AcceptInviteCommand),
     Get(GetCommand),
+    Grant(GrantCommand),
+    Member(MemberCommand),
+    #[command(alias = "perms")]
+    Permissions(PermissionsCommand),
+    Revoke(RevokeCommand),
+    Capabilities(CapabilitiesCommand),
     #[command(alias = "del")]
     Delete(DeleteCommand),
     #[command(alias = "ws")]
@@ -67,16 +102,17 @@ pub enum ContextSubCommands {
     Identity(ContextIdentityCommand),
     Alias(ContextAliasCommand),
     Use(UseCommand),
+    Whoami(WhoamiCommand),
 }
 
 impl Report for Context {
     fn report(&self) {
         let mut table = Table::new();
         let _ = table.set_header(vec![
-            Cell::new("Context Details").fg(comfy_table::Color::Blue)
+            Cell::new("Context Details").fg(themed(comfy_table::Color::Blue))
         ]);
         let _ = table.add_row(vec![
-            Cell::new(format!("ID: {}", self.id)).fg(comfy_table::Color::Yellow)
+            Cell::new(format!("ID: {}", self.id)).fg(themed(comfy_table::Color::Yellow))
         ]);
         let _ = table.add_row(vec![Cell::new(format!(
             "Application ID: {}",
@@ -93,14 +129,21 @@ impl ContextCommand {
             ContextSubCommands::Create(create) => create.run(environment).await,
             ContextSubCommands::Delete(delete) => delete.run(environment).await,
             ContextSubCommands::Get(get) => get.run(environment).await,
+            ContextSubCommands::Grant(grant) => grant.run(environment).await,
+            ContextSubCommands::Member(member) => member.run(environment).await,
+            ContextSubCommands::Permissions(permissions) => permissions.run(environment).await,
+            ContextSubCommands::Revoke(revoke) => revoke.run(environment).await,
+            ContextSubCommands::Capabilities(capabilities) => capabilities.run(environment).await,
             ContextSubCommands::Invite(invite) => invite.run(environment).await,
             ContextSubCommands::Join(join) => join.run(environment).await,
+            ContextSubCommands::AcceptInvite(accept) => accept.run(environment).await,
             ContextSubCommands::List(list) => list.run(environment).await,
             ContextSubCommands::Watch(watch) => watch.run(environment).await,
             ContextSubCommands::Update(update) => update.run(environment).await,
             ContextSubCommands::Identity(identity) => identity.run(environment).await,
             ContextSubCommands::Alias(alias) => alias.run(environment).await,
             ContextSubCommands::Use(use_cmd) => use_cmd.run(environment).await,
+            ContextSubCommands::Whoami(whoami) => whoami.run(environment).await,
         }
     }
 }
@@ -0,0 +1,152 @@
+use calimero_primitives::context::{ContextId, ContextInvitationPayload};
+use calimero_primitives::identity::{PrivateKey, PublicKey};
+use calimero_server_primitives::admin::{JoinContextRequest, JoinContextResponse};
+use camino::Utf8PathBuf;
+use clap::Parser;
+use comfy_table::{Cell, Color, Table};
+use eyre::{bail, Result as EyreResult, WrapErr};
+use serde::Serialize;
+use tokio::fs::read_to_string;
+
+use crate::cli::context::capabilities::GetCapabilitiesResponse;
+use crate::cli::Environment;
+use crate::common::{
+    client, do_request, ensure_reachable, fetch_multiaddr, load_config, multiaddr_to_url,
+    ApiEndpoint, RequestType,
+};
+use crate::output::{themed, Report};
+
+#[derive(Debug, Parser)]
+#[command(about = "Accept a context invitation and join the context")]
+pub struct AcceptInviteCommand {
+    #[clap(
+        value_name = "INVITATION",
+        help = "The invitation payload, as handed out by `context invite`"
+    )]
+    #[clap(required_unless_present = "from_file", conflicts_with = "from_file")]
+    pub invitation: Option<String>,
+
+    /// Read the invitation payload from this file instead of the command
+    /// line, so it never lands in shell history
+    #[clap(long, value_name = "PATH")]
+    pub from_file: Option<Utf8PathBuf>,
+
+    /// The private key of the invited identity, used to sign the join
+    #[clap(long = "key", value_name = "PRIVATE_KEY")]
+    pub private_key: PrivateKey,
+
+    /// Skip the upfront reachability check against the node
+    #[clap(long)]
+    pub no_precheck: bool,
+}
+
+/// What accepting an invitation ended up with: the joined context and
+/// the capabilities the membership came with.
+#[derive(Debug, Serialize)]
+struct AcceptSummary {
+    context_id: ContextId,
+    member: PublicKey,
+    capabilities: Vec<String>,
+}
+
+impl Report for AcceptSummary {
+    fn report(&self) {
+        let mut table = Table::new();
+        let _ = table.set_header(vec![Cell::new("Invitation Accepted").fg(themed(Color::Green))]);
+        let _ = table.add_row(vec![format!("Context ID: {}", self.context_id)]);
+        let _ = table.add_row(vec![format!("Member: {}", self.member)]);
+
+        let set = if self.capabilities.is_empty() {
+            "(none)".to_owned()
+        } else {
+            self.capabilities.join(", ")
+        };
+
+        let _ = table.add_row(vec![format!("Capabilities: {set}")]);
+
+        println!("{table}");
+    }
+}
+
+impl AcceptInviteCommand {
+    pub async fn run(self, environment: &Environment) -> EyreResult<()> {
+        let config = load_config(&environment.args.home, &environment.args.node_name).await?;
+
+        let multiaddr = fetch_multiaddr(&config)?;
+
+        if !self.no_precheck {
+            ensure_reachable(multiaddr).await?;
+        }
+
+        let raw = match (&self.invitation, &self.from_file) {
+            (Some(raw), _) => raw.clone(),
+            (None, Some(path)) => read_to_string(path)
+                .await
+                .wrap_err_with(|| format!("unable to read the invitation from {path}"))?,
+            (None, None) => unreachable!("clap requires INVITATION or --from-file"),
+        };
+
+        let raw = raw.trim();
+
+        if raw.is_empty() {
+            bail!("the invitation payload is empty");
+        }
+
+        // Catch mangled payloads before bothering the node; a truncated
+        // paste is far more common than a genuinely bad invitation.
+        let payload: ContextInvitationPayload = raw
+            .parse()
+            .wrap_err("malformed invitation: the payload is not valid base58")?;
+
+        let response: JoinContextResponse = do_request(
+            &client(),
+            multiaddr_to_url(multiaddr, "admin-api/dev/contexts/join")?,
+            Some(JoinContextRequest::new(self.private_key, payload)),
+            &config.identity,
+            RequestType::Post,
+        )
+        .await
+        .wrap_err("the node rejected the invitation; it may be expired or already used")?;
+
+        let Some(data) = response.data else {
+            bail!("the node accepted the request but returned no membership");
+        };
+
+        // The join response doesn't carry capabilities; ask the context
+        // what this membership came with.
+        let endpoint = ApiEndpoint::resolve(multiaddr)?;
+
+        let held: GetCapabilitiesResponse = do_request(
+            &client(),
+            endpoint.url(&format!(
+                "admin-api/dev/contexts/{}/capabilities",
+                data.context_id
+            )),
+            None::<()>,
+            &config.identity,
+            RequestType::Get,
+        )
+        .await?;
+
+        let capabilities = held
+            .data
+            .capabilities
+            .iter()
+            .find(|(member, _)| *member == data.member_public_key)
+            .map(|(_, capabilities)| {
+                capabilities
+                    .iter()
+                    .map(|capability| format!("{capability:?}"))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        environment.output.write(&AcceptSummary {
+            context_id: data.context_id,
+            member: data.member_public_key,
+            capabilities,
+        });
+
+        Ok(())
+    }
+}
@@ -5,11 +5,10 @@ use clap::Parser;
 use eyre::{OptionExt, Result as EyreResult, WrapErr};
 use libp2p::identity::Keypair;
 use libp2p::Multiaddr;
-use reqwest::Client;
 
 use crate::cli::{ApiError, Environment};
 use crate::common::{
-    create_alias, delete_alias, do_request, fetch_multiaddr, load_config, lookup_alias,
+    client, create_alias, delete_alias, do_request, fetch_multiaddr, load_config, lookup_alias,
     multiaddr_to_url, resolve_alias, RequestType,
 };
 use crate::output::{ErrorLine, WarnLine};
@@ -190,7 +189,7 @@ async fn context_exists(
     let url = multiaddr_to_url(multiaddr, &format!("admin-api/dev/contexts/{}", target_id))?;
 
     let result = do_request::<_, GetContextResponse>(
-        &Client::new(),
+        &client(),
         url,
         None::<()>,
         identity,
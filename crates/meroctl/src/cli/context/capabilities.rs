@@ -0,0 +1,652 @@
+use calimero_primitives::alias::Alias;
+use calimero_primitives::context::ContextId;
+use calimero_primitives::identity::PublicKey;
+use calimero_server_primitives::admin::GetContextIdentitiesResponse;
+use clap::{Parser, Subcommand};
+use comfy_table::{Cell, Color, Table};
+use eyre::{OptionExt, Result as EyreResult};
+use serde::{Deserialize, Serialize};
+
+use crate::cli::context::capability::{
+    sort_for_display, with_inherited, Capability, Holding, CAPABILITY_DISPLAY_ORDER,
+};
+use crate::cli::context::grant::{GrantPermissionRequest, GrantPermissionResponse};
+use crate::cli::context::revoke::{RevokePermissionRequest, RevokePermissionResponse};
+use crate::cli::Environment;
+use crate::common::{
+    client, do_request, fetch_multiaddr, load_config, lookup_alias, resolve_alias, ApiEndpoint,
+    RequestType,
+};
+use crate::output::{themed, Report};
+
+#[derive(Debug, Parser)]
+#[command(about = "Inspect member capabilities in a context")]
+pub struct CapabilitiesCommand {
+    #[command(subcommand)]
+    command: CapabilitiesSubcommand,
+}
+
+#[derive(Debug, Subcommand)]
+enum CapabilitiesSubcommand {
+    #[command(about = "Render all members against all capabilities")]
+    Matrix {
+        #[arg(help = "The context to audit")]
+        #[arg(long, short, default_value = "default")]
+        context: Alias<ContextId>,
+    },
+    #[command(about = "Show or set the capabilities invitations pre-grant by default")]
+    Defaults {
+        #[arg(help = "The context whose onboarding policy to inspect or change")]
+        #[arg(long, short, default_value = "default")]
+        context: Alias<ContextId>,
+
+        /// Replace the default set with these capabilities; omitted, the
+        /// current policy is printed. `--set` with no values clears it
+        #[arg(long, value_name = "CAPABILITY", num_args = 0..)]
+        set: Option<Vec<Capability>>,
+    },
+    #[command(about = "Show or set the only capabilities grants in this context may hand out")]
+    Ceiling {
+        #[arg(help = "The context whose policy ceiling to inspect or change")]
+        #[arg(long, short, default_value = "default")]
+        context: Alias<ContextId>,
+
+        /// Replace the ceiling with these capabilities; omitted, the
+        /// current ceiling is printed. `--set` with no values clears it
+        #[arg(long, value_name = "CAPABILITY", num_args = 0..)]
+        set: Option<Vec<Capability>>,
+
+        #[arg(long = "as", default_value = "default")]
+        #[arg(help = "The identity signing the policy change")]
+        signer: Alias<PublicKey>,
+    },
+    #[command(about = "Show which capabilities one member holds that another lacks")]
+    Diff {
+        #[arg(help = "The first member to compare")]
+        left: Alias<PublicKey>,
+
+        #[arg(help = "The second member to compare")]
+        right: Alias<PublicKey>,
+
+        #[arg(help = "The context to compare in")]
+        #[arg(long, short, default_value = "default")]
+        context: Alias<ContextId>,
+    },
+    #[command(about = "Reconcile a member's capabilities to exactly the given set")]
+    Ensure {
+        #[arg(help = "The member whose capabilities to reconcile")]
+        member: Alias<PublicKey>,
+
+        #[arg(
+            value_name = "CAPABILITY",
+            help = "The target capability set; empty revokes everything"
+        )]
+        capabilities: Vec<Capability>,
+
+        #[arg(help = "The context to reconcile in")]
+        #[arg(long, short, default_value = "default")]
+        context: Alias<ContextId>,
+
+        #[arg(long = "as", default_value = "default")]
+        #[arg(help = "The identity signing the grants and revokes")]
+        signer: Alias<PublicKey>,
+
+        /// Print the computed plan without applying it
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+/// The minimal set of grants and revokes that takes a member from what
+/// they hold to the requested target set.
+#[derive(Debug, Serialize)]
+pub struct CapabilityPlan {
+    pub member: PublicKey,
+    pub grant: Vec<Capability>,
+    pub revoke: Vec<Capability>,
+}
+
+impl Report for CapabilityPlan {
+    fn report(&self) {
+        if self.grant.is_empty() && self.revoke.is_empty() {
+            println!(
+                "`{}` already holds exactly the target set; nothing to do",
+                self.member
+            );
+
+            return;
+        }
+
+        let render = |capabilities: &[Capability]| {
+            if capabilities.is_empty() {
+                "(none)".to_owned()
+            } else {
+                capabilities
+                    .iter()
+                    .map(|capability| format!("{capability:?}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }
+        };
+
+        println!(
+            "plan for {}: grant {}; revoke {}",
+            self.member,
+            render(&self.grant),
+            render(&self.revoke)
+        );
+    }
+}
+
+/// The capabilities two members don't have in common.
+#[derive(Debug, Serialize)]
+pub struct CapabilityDiff {
+    pub left: PublicKey,
+    pub right: PublicKey,
+    /// Held by `left` but not `right`.
+    pub only_left: Vec<Capability>,
+    /// Held by `right` but not `left`.
+    pub only_right: Vec<Capability>,
+}
+
+impl Report for CapabilityDiff {
+    fn report(&self) {
+        if self.only_left.is_empty() && self.only_right.is_empty() {
+            println!(
+                "`{}` and `{}` hold the same capabilities",
+                self.left, self.right
+            );
+
+            return;
+        }
+
+        let mut table = Table::new();
+        let _ = table.set_header(vec![
+            Cell::new("Capability").fg(themed(Color::Blue)),
+            Cell::new(self.left.to_string()).fg(themed(Color::Blue)),
+            Cell::new(self.right.to_string()).fg(themed(Color::Blue)),
+        ]);
+
+        let mark = |held: bool| {
+            if held {
+                Cell::new("\u{2713}").fg(themed(Color::Green))
+            } else {
+                Cell::new("\u{2717}").fg(themed(Color::Red))
+            }
+        };
+
+        for capability in &self.only_left {
+            let _ = table.add_row(vec![
+                Cell::new(format!("{capability:?}")),
+                mark(true),
+                mark(false),
+            ]);
+        }
+
+        for capability in &self.only_right {
+            let _ = table.add_row(vec![
+                Cell::new(format!("{capability:?}")),
+                mark(false),
+                mark(true),
+            ]);
+        }
+
+        println!("{table}");
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GetCapabilitiesResponseData {
+    pub capabilities: Vec<(PublicKey, Vec<Capability>)>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GetCapabilitiesResponse {
+    pub data: GetCapabilitiesResponseData,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SetDefaultCapabilitiesRequest {
+    pub capabilities: Vec<Capability>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DefaultCapabilitiesResponseData {
+    pub capabilities: Vec<Capability>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DefaultCapabilitiesResponse {
+    pub data: DefaultCapabilitiesResponseData,
+}
+
+impl Report for DefaultCapabilitiesResponse {
+    fn report(&self) {
+        if self.data.capabilities.is_empty() {
+            println!("no default capabilities; invitations grant nothing unless asked");
+
+            return;
+        }
+
+        let mut capabilities = self.data.capabilities.clone();
+
+        sort_for_display(&mut capabilities);
+
+        let set = capabilities
+            .iter()
+            .map(|capability| format!("{capability:?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        println!("default capabilities: {set}");
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SetCapabilityCeilingRequest {
+    pub capabilities: Vec<Capability>,
+    pub signer_id: PublicKey,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CapabilityCeilingResponseData {
+    pub capabilities: Vec<Capability>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CapabilityCeilingResponse {
+    pub data: CapabilityCeilingResponseData,
+}
+
+impl Report for CapabilityCeilingResponse {
+    fn report(&self) {
+        if self.data.capabilities.is_empty() {
+            println!("no capability ceiling; any capability may be granted");
+
+            return;
+        }
+
+        let mut capabilities = self.data.capabilities.clone();
+
+        sort_for_display(&mut capabilities);
+
+        let ceiling = capabilities
+            .iter()
+            .map(|capability| format!("{capability:?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        println!("capability ceiling: {ceiling}");
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CapabilityMatrix {
+    pub rows: Vec<(String, [Option<Holding>; 3])>,
+}
+
+impl Report for CapabilityMatrix {
+    fn report(&self) {
+        // Columns follow the documented capability order, so matrices
+        // from different runs line up.
+        let mut header = vec![Cell::new("Member").fg(themed(Color::Blue))];
+
+        header.extend(
+            CAPABILITY_DISPLAY_ORDER
+                .iter()
+                .map(|capability| Cell::new(format!("{capability:?}")).fg(themed(Color::Blue))),
+        );
+
+        let mut table = Table::new();
+        let _ = table.set_header(header);
+
+        for (member, held) in &self.rows {
+            let mut row = vec![Cell::new(member)];
+
+            for holding in held {
+                row.push(match holding {
+                    Some(Holding::Granted) => Cell::new("\u{2713}").fg(themed(Color::Green)),
+                    Some(Holding::Inherited) => Cell::new("~").fg(themed(Color::Yellow)),
+                    None => Cell::new("\u{2717}").fg(themed(Color::Red)),
+                });
+            }
+
+            let _ = table.add_row(row);
+        }
+
+        println!("{table}");
+        println!("\u{2713} granted  ~ inherited from another capability");
+    }
+}
+
+impl CapabilitiesCommand {
+    pub async fn run(self, environment: &Environment) -> EyreResult<()> {
+        let config = load_config(&environment.args.home, &environment.args.node_name).await?;
+
+        let multiaddr = fetch_multiaddr(&config)?;
+
+        let context = match &self.command {
+            CapabilitiesSubcommand::Matrix { context }
+            | CapabilitiesSubcommand::Defaults { context, .. }
+            | CapabilitiesSubcommand::Ceiling { context, .. }
+            | CapabilitiesSubcommand::Diff { context, .. }
+            | CapabilitiesSubcommand::Ensure { context, .. } => *context,
+        };
+
+        let context_id = resolve_alias(multiaddr, &config.identity, context, None)
+            .await?
+            .value()
+            .cloned()
+            .ok_or_eyre("unable to resolve context")?;
+
+        let client = client();
+
+        let endpoint = ApiEndpoint::resolve(multiaddr)?;
+
+        if let CapabilitiesSubcommand::Defaults { set, .. } = &self.command {
+            let url = endpoint.url(&format!(
+                "admin-api/dev/contexts/{context_id}/default-capabilities"
+            ));
+
+            let response: DefaultCapabilitiesResponse = match set {
+                Some(capabilities) => {
+                    do_request(
+                        &client,
+                        url,
+                        Some(SetDefaultCapabilitiesRequest {
+                            capabilities: capabilities.clone(),
+                        }),
+                        &config.identity,
+                        RequestType::Post,
+                    )
+                    .await?
+                }
+                None => {
+                    do_request(&client, url, None::<()>, &config.identity, RequestType::Get)
+                        .await?
+                }
+            };
+
+            environment.output.write(&response);
+
+            return Ok(());
+        }
+
+        if let CapabilitiesSubcommand::Ceiling { set, signer, .. } = &self.command {
+            let url = endpoint.url(&format!(
+                "admin-api/dev/contexts/{context_id}/capability-ceiling"
+            ));
+
+            let response: CapabilityCeilingResponse = match set {
+                Some(capabilities) => {
+                    let signer_id =
+                        resolve_alias(multiaddr, &config.identity, *signer, Some(context_id))
+                            .await?
+                            .value()
+                            .cloned()
+                            .ok_or_eyre("unable to resolve signer")?;
+
+                    do_request(
+                        &client,
+                        url,
+                        Some(SetCapabilityCeilingRequest {
+                            capabilities: capabilities.clone(),
+                            signer_id,
+                        }),
+                        &config.identity,
+                        RequestType::Post,
+                    )
+                    .await?
+                }
+                None => {
+                    do_request(&client, url, None::<()>, &config.identity, RequestType::Get)
+                        .await?
+                }
+            };
+
+            environment.output.write(&response);
+
+            return Ok(());
+        }
+
+        if let CapabilitiesSubcommand::Diff { left, right, .. } = &self.command {
+            let left_id = resolve_alias(multiaddr, &config.identity, *left, Some(context_id))
+                .await?
+                .value()
+                .cloned()
+                .ok_or_eyre("unable to resolve the first member")?;
+
+            let right_id = resolve_alias(multiaddr, &config.identity, *right, Some(context_id))
+                .await?
+                .value()
+                .cloned()
+                .ok_or_eyre("unable to resolve the second member")?;
+
+            let held: GetCapabilitiesResponse = do_request(
+                &client,
+                endpoint.url(&format!("admin-api/dev/contexts/{context_id}/capabilities")),
+                None::<()>,
+                &config.identity,
+                RequestType::Get,
+            )
+            .await?;
+
+            let of = |member: PublicKey| -> Vec<Capability> {
+                held.data
+                    .capabilities
+                    .iter()
+                    .find(|(holder, _)| *holder == member)
+                    .map(|(_, capabilities)| capabilities.clone())
+                    .unwrap_or_default()
+            };
+
+            let left_held = of(left_id);
+            let right_held = of(right_id);
+
+            let mut only_left: Vec<Capability> = left_held
+                .iter()
+                .copied()
+                .filter(|capability| !right_held.contains(capability))
+                .collect();
+
+            let mut only_right: Vec<Capability> = right_held
+                .iter()
+                .copied()
+                .filter(|capability| !left_held.contains(capability))
+                .collect();
+
+            sort_for_display(&mut only_left);
+            sort_for_display(&mut only_right);
+
+            environment.output.write(&CapabilityDiff {
+                left: left_id,
+                right: right_id,
+                only_left,
+                only_right,
+            });
+
+            return Ok(());
+        }
+
+        if let CapabilitiesSubcommand::Ensure {
+            member,
+            capabilities: target,
+            signer,
+            dry_run,
+            ..
+        } = self.command
+        {
+            let member_id = resolve_alias(multiaddr, &config.identity, member, Some(context_id))
+                .await?
+                .value()
+                .cloned()
+                .ok_or_eyre("unable to resolve member")?;
+
+            let signer_id = resolve_alias(multiaddr, &config.identity, signer, Some(context_id))
+                .await?
+                .value()
+                .cloned()
+                .ok_or_eyre("unable to resolve signer")?;
+
+            let held: GetCapabilitiesResponse = do_request(
+                &client,
+                endpoint.url(&format!("admin-api/dev/contexts/{context_id}/capabilities")),
+                None::<()>,
+                &config.identity,
+                RequestType::Get,
+            )
+            .await?;
+
+            let current: Vec<Capability> = held
+                .data
+                .capabilities
+                .iter()
+                .find(|(holder, _)| *holder == member_id)
+                .map(|(_, capabilities)| capabilities.clone())
+                .unwrap_or_default();
+
+            let mut grant: Vec<Capability> = target
+                .iter()
+                .copied()
+                .filter(|capability| !current.contains(capability))
+                .collect();
+
+            let mut revoke: Vec<Capability> = current
+                .iter()
+                .copied()
+                .filter(|capability| !target.contains(capability))
+                .collect();
+
+            // The plan prints and serializes in the documented order.
+            sort_for_display(&mut grant);
+            sort_for_display(&mut revoke);
+
+            environment.output.write(&CapabilityPlan {
+                member: member_id,
+                grant: grant.clone(),
+                revoke: revoke.clone(),
+            });
+
+            if grant.is_empty() && revoke.is_empty() {
+                environment.mark_no_op();
+
+                return Ok(());
+            }
+
+            if dry_run {
+                return Ok(());
+            }
+
+            if !grant.is_empty() {
+                let response: GrantPermissionResponse = do_request(
+                    &client,
+                    endpoint
+                        .url(&format!("admin-api/dev/contexts/{context_id}/capabilities/grant")),
+                    Some(GrantPermissionRequest {
+                        capabilities: grant
+                            .into_iter()
+                            .map(|capability| (member_id, capability))
+                            .collect(),
+                        signer_id,
+                        reason: None,
+                        expiry: None,
+                    }),
+                    &config.identity,
+                    RequestType::Post,
+                )
+                .await?;
+
+                environment.output.write(&response);
+            }
+
+            if !revoke.is_empty() {
+                let response: RevokePermissionResponse = do_request(
+                    &client,
+                    endpoint
+                        .url(&format!("admin-api/dev/contexts/{context_id}/capabilities/revoke")),
+                    Some(RevokePermissionRequest {
+                        capabilities: revoke
+                            .into_iter()
+                            .map(|capability| (member_id, capability))
+                            .collect(),
+                        signer_id,
+                        reason: None,
+                    }),
+                    &config.identity,
+                    RequestType::Post,
+                )
+                .await?;
+
+                environment.output.write(&response);
+            }
+
+            return Ok(());
+        }
+
+        let identities: GetContextIdentitiesResponse = do_request(
+            &client,
+            endpoint.url(&format!("admin-api/dev/contexts/{context_id}/identities")),
+            None::<()>,
+            &config.identity,
+            RequestType::Get,
+        )
+        .await?;
+
+        let capabilities: GetCapabilitiesResponse = do_request(
+            &client,
+            endpoint.url(&format!("admin-api/dev/contexts/{context_id}/capabilities")),
+            None::<()>,
+            &config.identity,
+            RequestType::Get,
+        )
+        .await?;
+
+        // Label the default identity where one is aliased, so the row
+        // reads as the operator knows it.
+        let default_alias: Alias<PublicKey> =
+            "default".parse().expect("'default' is a valid alias name");
+
+        let default_identity = lookup_alias(
+            multiaddr,
+            &config.identity,
+            default_alias,
+            Some(context_id),
+        )
+        .await
+        .ok()
+        .and_then(|lookup| lookup.data.value);
+
+        let mut rows = Vec::new();
+
+        for identity in identities.data.identities {
+            let expanded = capabilities
+                .data
+                .capabilities
+                .iter()
+                .find(|(member, _)| *member == identity)
+                .map(|(_, capabilities)| with_inherited(capabilities))
+                .unwrap_or_default();
+
+            let holding = |capability: Capability| {
+                expanded
+                    .iter()
+                    .find(|&&(c, _)| c == capability)
+                    .map(|&(_, holding)| holding)
+            };
+
+            let held = CAPABILITY_DISPLAY_ORDER.map(holding);
+
+            let member = if default_identity == Some(identity) {
+                format!("{identity} (default)")
+            } else {
+                identity.to_string()
+            };
+
+            rows.push((member, held));
+        }
+
+        environment.output.write(&CapabilityMatrix { rows });
+
+        Ok(())
+    }
+}
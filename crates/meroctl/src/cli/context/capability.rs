@@ -0,0 +1,100 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cli::context::InvalidCapability;
+
+/// The capabilities the admin API knows about, as one CLI-facing enum
+/// shared by every subcommand that names a capability, so they all
+/// accept the same spellings and there is a single place to extend when
+/// a new capability is added upstream.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum Capability {
+    ManageApplication,
+    ManageMembers,
+    Proxy,
+}
+
+impl FromStr for Capability {
+    type Err = InvalidCapability;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Accept PascalCase, kebab-case and snake_case in any casing.
+        let normalized: String = s
+            .chars()
+            .filter(|c| !matches!(c, '-' | '_'))
+            .collect::<String>()
+            .to_lowercase();
+
+        match normalized.as_str() {
+            "manageapplication" => Ok(Self::ManageApplication),
+            "managemembers" => Ok(Self::ManageMembers),
+            "proxy" => Ok(Self::Proxy),
+            _ => Err(InvalidCapability::new(s)),
+        }
+    }
+}
+
+/// Capabilities implied by holding another: holding the left column lets
+/// a member act as if granted everything on the right, with no explicit
+/// grant recorded. Views mark these as inherited rather than granted.
+/// The config contracts currently treat every capability as independent,
+/// so the table is empty; listing an implication here that the contracts
+/// don't enforce would mislabel members as able to do things they would
+/// be denied. The table is shallow; implications are not chased
+/// transitively.
+pub const CAPABILITY_IMPLICATIONS: &[(Capability, &[Capability])] = &[];
+
+/// The documented display order for capabilities. Every table and list
+/// renders them in this sequence, regardless of the order the node
+/// returned them in, so output is stable across runs and diffable.
+pub const CAPABILITY_DISPLAY_ORDER: [Capability; 3] = [
+    Capability::ManageApplication,
+    Capability::ManageMembers,
+    Capability::Proxy,
+];
+
+/// Where `capability` sits in [`CAPABILITY_DISPLAY_ORDER`].
+fn display_rank(capability: Capability) -> usize {
+    CAPABILITY_DISPLAY_ORDER
+        .iter()
+        .position(|&ordered| ordered == capability)
+        .unwrap_or(CAPABILITY_DISPLAY_ORDER.len())
+}
+
+/// Sorts capabilities into the documented display order.
+pub fn sort_for_display(capabilities: &mut [Capability]) {
+    capabilities.sort_by_key(|&capability| display_rank(capability));
+}
+
+/// How a member comes to hold a capability.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub enum Holding {
+    /// Explicitly granted.
+    Granted,
+    /// Implied by another granted capability.
+    Inherited,
+}
+
+/// Expands `held` with the capabilities it implies, labelling each one
+/// as granted or inherited.
+pub fn with_inherited(held: &[Capability]) -> Vec<(Capability, Holding)> {
+    let mut out: Vec<(Capability, Holding)> = held
+        .iter()
+        .map(|&capability| (capability, Holding::Granted))
+        .collect();
+
+    for (holder, implied) in CAPABILITY_IMPLICATIONS {
+        if !held.contains(holder) {
+            continue;
+        }
+
+        for &capability in *implied {
+            if !out.iter().any(|&(c, _)| c == capability) {
+                out.push((capability, Holding::Inherited));
+            }
+        }
+    }
+
+    out
+}
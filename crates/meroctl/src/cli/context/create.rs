@@ -23,9 +23,10 @@ use tokio::sync::mpsc;
 
 use crate::cli::Environment;
 use crate::common::{
-    create_alias, do_request, fetch_multiaddr, load_config, multiaddr_to_url, RequestType,
+    client, create_alias, do_request, fetch_multiaddr, load_config, multiaddr_to_url,
+    RequestType,
 };
-use crate::output::{ErrorLine, InfoLine, Report};
+use crate::output::{themed, ErrorLine, InfoLine, Report};
 
 #[derive(Debug, Parser)]
 #[command(about = "Create a new context")]
@@ -78,7 +79,7 @@ pub struct CreateCommand {
 impl Report for CreateContextResponse {
     fn report(&self) {
         let mut table = Table::new();
-        let _ = table.set_header(vec![Cell::new("Context Created").fg(Color::Green)]);
+        let _ = table.set_header(vec![Cell::new("Context Created").fg(themed(Color::Green))]);
         let _ = table.add_row(vec![format!("Context ID: {}", self.data.context_id)]);
         let _ = table.add_row(vec![format!(
             "Member Public Key: {}",
@@ -91,7 +92,7 @@ impl Report for CreateContextResponse {
 impl Report for UpdateContextApplicationResponse {
     fn report(&self) {
         let mut table = Table::new();
-        let _ = table.set_header(vec![Cell::new("Context Updated").fg(Color::Green)]);
+        let _ = table.set_header(vec![Cell::new("Context Updated").fg(themed(Color::Green))]);
         let _ = table.add_row(vec!["Application successfully updated"]);
         println!("{table}");
     }
@@ -101,7 +102,7 @@ impl CreateCommand {
     pub async fn run(self, environment: &Environment) -> EyreResult<()> {
         let config = load_config(&environment.args.home, &environment.args.node_name).await?;
         let multiaddr = fetch_multiaddr(&config)?;
-        let client = Client::new();
+        let client = client();
 
         match self {
             Self {
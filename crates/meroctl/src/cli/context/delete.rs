@@ -4,13 +4,13 @@ use calimero_server_primitives::admin::DeleteContextResponse;
 use clap::Parser;
 use comfy_table::{Cell, Table};
 use eyre::{OptionExt, Result as EyreResult};
-use reqwest::Client;
 
 use crate::cli::Environment;
 use crate::common::{
-    do_request, fetch_multiaddr, load_config, multiaddr_to_url, resolve_alias, RequestType,
+    client, do_request, fetch_multiaddr, load_config, multiaddr_to_url, resolve_alias,
+    RequestType,
 };
-use crate::output::Report;
+use crate::output::{themed, Report};
 
 #[derive(Debug, Parser)]
 #[command(about = "Delete a context")]
@@ -23,12 +23,12 @@ impl Report for DeleteContextResponse {
     fn report(&self) {
         let mut table = Table::new();
         let _ = table.set_header(vec![
-            Cell::new("Context Deletion Status").fg(comfy_table::Color::Blue)
+            Cell::new("Context Deletion Status").fg(themed(comfy_table::Color::Blue))
         ]);
         let _ = table.add_row(vec![if self.data.is_deleted {
-            Cell::new("✓ Deleted").fg(comfy_table::Color::Green)
+            Cell::new("✓ Deleted").fg(themed(comfy_table::Color::Green))
         } else {
-            Cell::new("✗ Not Deleted").fg(comfy_table::Color::Red)
+            Cell::new("✗ Not Deleted").fg(themed(comfy_table::Color::Red))
         }]);
         println!("{table}");
     }
@@ -49,7 +49,7 @@ impl DeleteCommand {
         let url = multiaddr_to_url(multiaddr, &format!("admin-api/dev/contexts/{}", context_id))?;
 
         let response: DeleteContextResponse = do_request(
-            &Client::new(),
+            &client(),
             url,
             None::<()>,
             &config.identity,
@@ -13,9 +13,10 @@ use reqwest::Client;
 
 use crate::cli::Environment;
 use crate::common::{
-    fetch_multiaddr, load_config, make_request, multiaddr_to_url, resolve_alias, RequestType,
+    client, fetch_multiaddr, load_config, make_request, multiaddr_to_url, resolve_alias,
+    RequestType,
 };
-use crate::output::Report;
+use crate::output::{themed, Report};
 
 #[derive(Parser, Debug)]
 #[command(about = "Fetch details about the context")]
@@ -53,9 +54,9 @@ impl Report for GetContextUsersResponse {
     fn report(&self) {
         let mut table = Table::new();
         let _ = table.set_header(vec![
-            Cell::new("Context Users").fg(Color::Blue),
-            Cell::new("User ID").fg(Color::Blue),
-            Cell::new("Joined At").fg(Color::Blue),
+            Cell::new("Context Users").fg(themed(Color::Blue)),
+            Cell::new("User ID").fg(themed(Color::Blue)),
+            Cell::new("Joined At").fg(themed(Color::Blue)),
         ]);
 
         for user in &self.data.context_users {
@@ -69,11 +70,11 @@ impl Report for GetContextClientKeysResponse {
     fn report(&self) {
         let mut table = Table::new();
         let _ = table.set_header(vec![
-            Cell::new("Client Keys").fg(Color::Blue),
-            Cell::new("Wallet Type").fg(Color::Blue),
-            Cell::new("Signing Key").fg(Color::Blue),
-            Cell::new("Created At").fg(Color::Blue),
-            Cell::new("Context ID").fg(Color::Blue),
+            Cell::new("Client Keys").fg(themed(Color::Blue)),
+            Cell::new("Wallet Type").fg(themed(Color::Blue)),
+            Cell::new("Signing Key").fg(themed(Color::Blue)),
+            Cell::new("Created At").fg(themed(Color::Blue)),
+            Cell::new("Context ID").fg(themed(Color::Blue)),
         ]);
 
         for key in &self.data.client_keys {
@@ -92,7 +93,7 @@ impl Report for GetContextClientKeysResponse {
 impl Report for GetContextStorageResponse {
     fn report(&self) {
         let mut table = Table::new();
-        let _ = table.set_header(vec![Cell::new("Context Storage").fg(Color::Blue)]);
+        let _ = table.set_header(vec![Cell::new("Context Storage").fg(themed(Color::Blue))]);
         let _ = table.add_row(vec![format!("Size: {} bytes", self.data.size_in_bytes)]);
         println!("{table}");
     }
@@ -101,7 +102,7 @@ impl Report for GetContextStorageResponse {
 impl Report for GetContextIdentitiesResponse {
     fn report(&self) {
         let mut table = Table::new();
-        let _ = table.set_header(vec![Cell::new("Context Identities").fg(Color::Blue)]);
+        let _ = table.set_header(vec![Cell::new("Context Identities").fg(themed(Color::Blue))]);
 
         for identity in &self.data.identities {
             let _ = table.add_row(vec![identity.to_string()]);
@@ -114,7 +115,7 @@ impl GetCommand {
     pub async fn run(self, environment: &Environment) -> EyreResult<()> {
         let config = load_config(&environment.args.home, &environment.args.node_name).await?;
         let multiaddr = fetch_multiaddr(&config)?;
-        let client = Client::new();
+        let client = client();
 
         let resolve_response =
             resolve_alias(multiaddr, &config.identity, self.context, None).await?;
@@ -0,0 +1,722 @@
+use core::fmt;
+use std::fs::read_to_string;
+use std::str::FromStr;
+
+use calimero_config::ConfigFile;
+use calimero_primitives::alias::Alias;
+use calimero_primitives::context::ContextId;
+use calimero_primitives::identity::PublicKey;
+use calimero_server_primitives::admin::GetContextIdentitiesResponse;
+use camino::Utf8PathBuf;
+use chrono::{DateTime, Utc};
+use clap::Parser;
+use comfy_table::{Cell, Color, Table};
+use eyre::{bail, eyre, OptionExt, Result as EyreResult};
+use libp2p::Multiaddr;
+use serde::{Deserialize, Serialize};
+
+use crate::cli::context::capabilities::GetCapabilitiesResponse;
+use crate::cli::context::capability::{sort_for_display, with_inherited, Capability};
+use crate::cli::Environment;
+use crate::common::{
+    client, do_request, ensure_reachable, fetch_multiaddr, load_config, resolve_alias,
+    ApiEndpoint, RequestType,
+};
+use crate::output::{themed, InfoLine, Progress, Report, WarnLine};
+
+#[derive(Debug, Parser)]
+#[command(about = "Grant permissions to a member in a context")]
+pub struct GrantCommand {
+    #[clap(long, short)]
+    #[clap(
+        value_name = "CONTEXT",
+        help = "The context to grant permissions in; defaults to `default`"
+    )]
+    pub context: Option<Alias<ContextId>>,
+
+    #[clap(
+        long = "as",
+        value_name = "GRANTER",
+        help = "The identity granting the permission; defaults to `default`"
+    )]
+    #[clap(env = "CALIMERO_IDENTITY", hide_env_values = true)]
+    pub granter: Option<Alias<PublicKey>>,
+
+    #[clap(
+        value_name = "GRANTEE",
+        value_delimiter = ',',
+        num_args = 1,
+        required = true,
+        help = "The members receiving the permission, comma-separated; `@N` picks the N-th \
+                listed member, `@holding:<CAPABILITY>` targets every member already holding \
+                that capability"
+    )]
+    pub grantees: Vec<MemberSelector>,
+
+    #[clap(
+        value_name = "CAPABILITY",
+        value_delimiter = ',',
+        num_args = 1,
+        required = true,
+        help = "The capabilities to grant, comma-separated"
+    )]
+    pub capabilities: Vec<Capability>,
+
+    /// Why this grant is happening; recorded in the node's log for audits
+    #[clap(long, value_name = "TEXT")]
+    pub reason: Option<String>,
+
+    /// Revoke the grant automatically at this time (RFC 3339, e.g.
+    /// `2026-01-01T18:00:00Z`); the node holds the timer in memory
+    #[clap(long, value_name = "DATETIME")]
+    pub until: Option<DateTime<Utc>>,
+
+    /// Print the fully resolved request as JSON instead of sending it
+    #[clap(long)]
+    pub json: bool,
+
+    /// File with one context alias per line; the grant is applied in each
+    #[clap(long, value_name = "PATH", conflicts_with = "context")]
+    pub context_from_alias_file: Option<Utf8PathBuf>,
+
+    /// Skip the upfront reachability check against the node
+    #[clap(long)]
+    pub no_precheck: bool,
+
+    /// Don't announce which context and identity the `default` aliases
+    /// resolved to
+    #[clap(long, short)]
+    pub quiet: bool,
+
+    /// Allow the `@N` index selector; member order is whatever the node
+    /// returns, so indexes are for interactive use, not scripts
+    #[clap(long)]
+    pub by_index: bool,
+
+    /// Trace each resolution step - the alias inputs, the ids they
+    /// resolved to, and the final request - so a failing step is visible
+    #[clap(long)]
+    pub explain: bool,
+}
+
+/// How a target member is named: an alias or key as usual, `@N` for the
+/// N-th member (1-based) of the context's member list, or
+/// `@holding:<CAPABILITY>` for every member already holding that
+/// capability (explicitly or by inheritance).
+#[derive(Clone, Copy, Debug)]
+pub enum MemberSelector {
+    Named(Alias<PublicKey>),
+    Index(usize),
+    Holding(Capability),
+}
+
+impl FromStr for MemberSelector {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(selector) = s.strip_prefix('@') else {
+            return s.parse().map(Self::Named).map_err(|err| err.to_string());
+        };
+
+        if let Some(capability) = selector.strip_prefix("holding:") {
+            return capability
+                .parse()
+                .map(Self::Holding)
+                .map_err(|err| err.to_string());
+        }
+
+        let index: usize = selector
+            .parse()
+            .map_err(|_| format!("`{s}` is not a member selector; use `@1`, `@2`, ... or `@holding:<CAPABILITY>`"))?;
+
+        if index == 0 {
+            return Err("member indexes start at 1".to_owned());
+        }
+
+        Ok(Self::Index(index))
+    }
+}
+
+impl fmt::Display for MemberSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Named(alias) => alias.fmt(f),
+            Self::Index(index) => write!(f, "@{index}"),
+            Self::Holding(capability) => write!(f, "@holding:{capability:?}"),
+        }
+    }
+}
+
+/// Resolves a member selector in a context: names go through the usual
+/// alias resolution, `@N` is looked up in the node's member list - with
+/// `by_index` as an explicit opt-in, since the order is not stable.
+pub(super) async fn resolve_member(
+    multiaddr: &Multiaddr,
+    config: &ConfigFile,
+    endpoint: &ApiEndpoint,
+    selector: MemberSelector,
+    context_id: ContextId,
+    by_index: bool,
+) -> EyreResult<PublicKey> {
+    match selector {
+        MemberSelector::Named(alias) => {
+            resolve_alias(multiaddr, &config.identity, alias, Some(context_id))
+                .await?
+                .value()
+                .cloned()
+                .ok_or_eyre("unable to resolve member")
+        }
+        MemberSelector::Index(index) => {
+            if !by_index {
+                bail!(
+                    "`@{index}` picks a member by position, which is brittle in scripts; \
+                     pass --by-index to allow it"
+                );
+            }
+
+            let members = fetch_members(config, endpoint, context_id).await?;
+
+            members.get(index - 1).copied().ok_or_else(|| {
+                eyre!(
+                    "`@{index}` is out of range; context {context_id} has {} member(s)",
+                    members.len()
+                )
+            })
+        }
+        MemberSelector::Holding(_) => bail!(
+            "`{selector}` names a set of members, not one; it is only supported as a grant target"
+        ),
+    }
+}
+
+/// Fetches the member list of a context from the node.
+pub(super) async fn fetch_members(
+    config: &ConfigFile,
+    endpoint: &ApiEndpoint,
+    context_id: ContextId,
+) -> EyreResult<Vec<PublicKey>> {
+    let response: GetContextIdentitiesResponse = do_request(
+        &client(),
+        endpoint.url(&format!("admin-api/dev/contexts/{context_id}/identities")),
+        None::<()>,
+        &config.identity,
+        RequestType::Get,
+    )
+    .await?;
+
+    Ok(response.data.identities)
+}
+
+#[derive(Debug, Serialize)]
+pub struct GrantPermissionRequest {
+    pub capabilities: Vec<(PublicKey, Capability)>,
+    pub signer_id: PublicKey,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiry: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GrantPermissionResponseData {
+    pub capabilities: Vec<(PublicKey, Vec<Capability>)>,
+    #[serde(default)]
+    pub expiry: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GrantPermissionResponse {
+    pub data: GrantPermissionResponseData,
+}
+
+impl Report for GrantPermissionResponse {
+    fn report(&self) {
+        let mut table = Table::new();
+        let _ = table.set_header(vec![
+            Cell::new("Member").fg(themed(Color::Green)),
+            Cell::new("Capabilities").fg(themed(Color::Green)),
+        ]);
+
+        for (identity, capabilities) in &self.data.capabilities {
+            let mut capabilities = capabilities.clone();
+
+            sort_for_display(&mut capabilities);
+
+            let set = capabilities
+                .iter()
+                .map(|capability| format!("{capability:?}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            let _ = table.add_row(vec![identity.to_string(), set]);
+        }
+
+        println!("{table}");
+
+        if let Some(expiry) = self.data.expiry {
+            println!("expires at {expiry}");
+        }
+    }
+}
+
+/// The result of one (member, capability) grant within a batch.
+#[derive(Debug, Serialize)]
+pub struct GrantOutcome {
+    pub member: PublicKey,
+    pub capability: Capability,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Every outcome of a batched grant, successes and failures alike, so a
+/// partially failed batch still reports what did go through.
+#[derive(Debug, Serialize)]
+pub struct BatchGrantSummary {
+    pub outcomes: Vec<GrantOutcome>,
+}
+
+impl Report for BatchGrantSummary {
+    fn report(&self) {
+        let mut table = Table::new();
+        let _ = table.set_header(vec![
+            Cell::new("Member").fg(themed(Color::Green)),
+            Cell::new("Capability").fg(themed(Color::Green)),
+            Cell::new("Result").fg(themed(Color::Green)),
+        ]);
+
+        for outcome in &self.outcomes {
+            let result = match &outcome.error {
+                Some(error) => format!("failed: {error}"),
+                None => "granted".to_owned(),
+            };
+
+            let _ = table.add_row(vec![
+                outcome.member.to_string(),
+                format!("{:?}", outcome.capability),
+                result,
+            ]);
+        }
+
+        println!("{table}");
+
+        let succeeded = self
+            .outcomes
+            .iter()
+            .filter(|outcome| outcome.error.is_none())
+            .count();
+
+        println!("{succeeded} of {} grant(s) succeeded", self.outcomes.len());
+    }
+}
+
+/// Resolves context aliases for a command, either the single `--context`
+/// argument or every line of an alias file.
+pub(super) async fn resolve_contexts(
+    multiaddr: &Multiaddr,
+    config: &ConfigFile,
+    context: Alias<ContextId>,
+    alias_file: Option<&Utf8PathBuf>,
+) -> EyreResult<Vec<ContextId>> {
+    let Some(path) = alias_file else {
+        let context_id = resolve_alias(multiaddr, &config.identity, context, None)
+            .await?
+            .value()
+            .cloned()
+            .ok_or_eyre("unable to resolve context")?;
+
+        return Ok(vec![context_id]);
+    };
+
+    let contents = read_to_string(path)?;
+
+    let mut contexts = Vec::new();
+
+    for line in contents.lines() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let alias: Alias<ContextId> = line
+            .parse()
+            .map_err(|err| eyre!("invalid context alias `{line}`: {err}"))?;
+
+        let context_id = resolve_alias(multiaddr, &config.identity, alias, None)
+            .await?
+            .value()
+            .cloned()
+            .ok_or_else(|| eyre!("unable to resolve context `{line}`"))?;
+
+        contexts.push(context_id);
+    }
+
+    Ok(contexts)
+}
+
+impl GrantCommand {
+    pub async fn run(self, environment: &Environment) -> EyreResult<()> {
+        let config = load_config(&environment.args.home, &environment.args.node_name).await?;
+
+        let multiaddr = fetch_multiaddr(&config)?;
+
+        if !self.no_precheck {
+            ensure_reachable(multiaddr).await?;
+        }
+
+        let context = self
+            .context
+            .unwrap_or_else(|| "default".parse().expect("'default' is a valid alias name"));
+
+        if self.explain {
+            match &self.context_from_alias_file {
+                Some(path) => environment
+                    .output
+                    .write(&InfoLine(&format!("explain: resolving contexts from {path}"))),
+                None => environment
+                    .output
+                    .write(&InfoLine(&format!("explain: resolving context alias `{context}`"))),
+            }
+        }
+
+        let contexts = resolve_contexts(
+            multiaddr,
+            &config,
+            context,
+            self.context_from_alias_file.as_ref(),
+        )
+        .await?;
+
+        if self.explain {
+            for context_id in &contexts {
+                environment
+                    .output
+                    .write(&InfoLine(&format!("explain: context -> {context_id}")));
+            }
+        }
+
+        // Falling back to the `default` alias silently is how grants end
+        // up in the wrong context; say what it resolved to.
+        if self.context.is_none() && self.context_from_alias_file.is_none() && !self.quiet {
+            if let [context_id] = contexts[..] {
+                environment.output.write(&InfoLine(&format!(
+                    "no --context given; granting in context {context_id} (alias `default`)"
+                )));
+            }
+        }
+
+        let endpoint = ApiEndpoint::resolve(multiaddr)?;
+
+        let mut changed = false;
+
+        let mut progress = Progress::new("granting", contexts.len(), self.quiet);
+
+        for context_id in contexts {
+            changed |= self
+                .grant_in(environment, &config, multiaddr, &endpoint, context_id)
+                .await?;
+
+            progress.tick();
+        }
+
+        progress.finish();
+
+        if !changed && !self.json {
+            environment.mark_no_op();
+        }
+
+        Ok(())
+    }
+
+    /// Grants in one context, returning whether anything actually changed.
+    async fn grant_in(
+        &self,
+        environment: &Environment,
+        config: &ConfigFile,
+        multiaddr: &Multiaddr,
+        endpoint: &ApiEndpoint,
+        context_id: ContextId,
+    ) -> EyreResult<bool> {
+        let granter = self
+            .granter
+            .unwrap_or_else(|| "default".parse().expect("'default' is a valid alias name"));
+
+        if self.explain {
+            environment.output.write(&InfoLine(&format!(
+                "explain: resolving granter alias `{granter}` in context {context_id}"
+            )));
+        }
+
+        let granter_id = resolve_alias(multiaddr, &config.identity, granter, Some(context_id))
+            .await?
+            .value()
+            .cloned()
+            .ok_or_eyre("unable to resolve granter")?;
+
+        if self.granter.is_none() && !self.quiet {
+            environment.output.write(&InfoLine(&format!(
+                "no --as given; granting as {granter_id} (alias `default`)"
+            )));
+        }
+
+        if let [MemberSelector::Holding(filter)] = self.grantees[..] {
+            return self
+                .grant_to_holders(environment, config, endpoint, context_id, granter_id, filter)
+                .await;
+        }
+
+        if self
+            .grantees
+            .iter()
+            .any(|grantee| matches!(grantee, MemberSelector::Holding(_)))
+        {
+            bail!("`@holding:<CAPABILITY>` targets a set of members and must be the only grantee");
+        }
+
+        if self.explain {
+            environment.output.write(&InfoLine(&format!(
+                "explain: granter -> {granter_id}"
+            )));
+        }
+
+        // Resolve every grantee once, up front, so a typo in the last
+        // one doesn't leave the first half of the batch already applied.
+        let mut grantee_ids = Vec::with_capacity(self.grantees.len());
+
+        for &grantee in &self.grantees {
+            if self.explain {
+                environment
+                    .output
+                    .write(&InfoLine(&format!("explain: resolving grantee `{grantee}`")));
+            }
+
+            let grantee_id = resolve_member(
+                multiaddr,
+                config,
+                endpoint,
+                grantee,
+                context_id,
+                self.by_index,
+            )
+            .await?;
+
+            if matches!(grantee, MemberSelector::Index(_)) {
+                environment
+                    .output
+                    .write(&InfoLine(&format!("`{grantee}` resolved to {grantee_id}")));
+            }
+
+            if self.explain {
+                environment
+                    .output
+                    .write(&InfoLine(&format!("explain: grantee -> {grantee_id}")));
+            }
+
+            grantee_ids.push((grantee, grantee_id));
+        }
+
+        // Detect "already granted" up front so a re-run exits with the
+        // no-op code instead of silently repeating the mutation.
+        let held: GetCapabilitiesResponse = do_request(
+            &client(),
+            endpoint.url(&format!("admin-api/dev/contexts/{context_id}/capabilities")),
+            None::<()>,
+            &config.identity,
+            RequestType::Get,
+        )
+        .await?;
+
+        let mut pairs = Vec::new();
+
+        for &(grantee, grantee_id) in &grantee_ids {
+            for &capability in &self.capabilities {
+                let already_granted = held
+                    .data
+                    .capabilities
+                    .iter()
+                    .find(|(member, _)| *member == grantee_id)
+                    .is_some_and(|(_, capabilities)| capabilities.contains(&capability));
+
+                if already_granted {
+                    environment.output.write(&InfoLine(&format!(
+                        "`{grantee}` already holds {capability:?} in context {context_id}"
+                    )));
+
+                    continue;
+                }
+
+                pairs.push((grantee_id, capability));
+            }
+        }
+
+        if pairs.is_empty() {
+            return Ok(false);
+        }
+
+        // Proxy on its own is rarely what's wanted - proposal management
+        // still requires ManageApplication.
+        if self.capabilities.contains(&Capability::Proxy)
+            && !self.capabilities.contains(&Capability::ManageApplication)
+        {
+            environment.output.write(&WarnLine(
+                "granting Proxy without ManageApplication; the member can use the proxy \
+                 but cannot manage the application it fronts",
+            ));
+        }
+
+        self.send_grants(environment, config, endpoint, context_id, granter_id, pairs)
+            .await
+    }
+
+    /// Sends one grant request per (member, capability) pair, collecting
+    /// failures instead of aborting the batch on the first one. The
+    /// summary lists every outcome; the command still fails if any pair
+    /// did.
+    async fn send_grants(
+        &self,
+        environment: &Environment,
+        config: &ConfigFile,
+        endpoint: &ApiEndpoint,
+        context_id: ContextId,
+        granter_id: PublicKey,
+        pairs: Vec<(PublicKey, Capability)>,
+    ) -> EyreResult<bool> {
+        if self.explain || self.json {
+            let request = GrantPermissionRequest {
+                capabilities: pairs.clone(),
+                signer_id: granter_id,
+                reason: self.reason.clone(),
+                expiry: self.until,
+            };
+
+            if self.explain {
+                environment.output.write(&InfoLine(&format!(
+                    "explain: request -> POST admin-api/dev/contexts/{context_id}/capabilities/grant {}",
+                    serde_json::to_string(&request)?
+                )));
+            }
+
+            if self.json {
+                println!("{}", serde_json::to_string_pretty(&request)?);
+
+                return Ok(false);
+            }
+        }
+
+        let mut outcomes = Vec::with_capacity(pairs.len());
+
+        for (member, capability) in pairs {
+            let request = GrantPermissionRequest {
+                capabilities: vec![(member, capability)],
+                signer_id: granter_id,
+                reason: self.reason.clone(),
+                expiry: self.until,
+            };
+
+            let result: EyreResult<GrantPermissionResponse> = do_request(
+                &client(),
+                endpoint.url(&format!(
+                    "admin-api/dev/contexts/{context_id}/capabilities/grant"
+                )),
+                Some(request),
+                &config.identity,
+                RequestType::Post,
+            )
+            .await;
+
+            outcomes.push(GrantOutcome {
+                member,
+                capability,
+                error: result.err().map(|err| err.to_string()),
+            });
+        }
+
+        let summary = BatchGrantSummary { outcomes };
+
+        environment.output.write(&summary);
+
+        let failed = summary
+            .outcomes
+            .iter()
+            .filter(|outcome| outcome.error.is_some())
+            .count();
+
+        if failed > 0 {
+            bail!(
+                "{failed} of {} grant(s) failed in context {context_id}",
+                summary.outcomes.len()
+            );
+        }
+
+        Ok(true)
+    }
+
+    /// Grants to every member of the context who already holds `filter`,
+    /// explicitly or by inheritance - "proxy rights follow
+    /// app-management rights" as one command. Every member is reported
+    /// as matched or skipped for each requested capability.
+    async fn grant_to_holders(
+        &self,
+        environment: &Environment,
+        config: &ConfigFile,
+        endpoint: &ApiEndpoint,
+        context_id: ContextId,
+        granter_id: PublicKey,
+        filter: Capability,
+    ) -> EyreResult<bool> {
+        let held: GetCapabilitiesResponse = do_request(
+            &client(),
+            endpoint.url(&format!("admin-api/dev/contexts/{context_id}/capabilities")),
+            None::<()>,
+            &config.identity,
+            RequestType::Get,
+        )
+        .await?;
+
+        let mut pairs = Vec::new();
+
+        for (member, capabilities) in &held.data.capabilities {
+            let holds = |wanted| {
+                with_inherited(capabilities)
+                    .iter()
+                    .any(|&(capability, _)| capability == wanted)
+            };
+
+            if !holds(filter) {
+                environment.output.write(&InfoLine(&format!(
+                    "skipping {member}: does not hold {filter:?}"
+                )));
+
+                continue;
+            }
+
+            for &capability in &self.capabilities {
+                if holds(capability) {
+                    environment.output.write(&InfoLine(&format!(
+                        "skipping {member}: already holds {capability:?}"
+                    )));
+
+                    continue;
+                }
+
+                environment.output.write(&InfoLine(&format!(
+                    "granting {capability:?} to {member} (holds {filter:?})"
+                )));
+
+                pairs.push((*member, capability));
+            }
+        }
+
+        if pairs.is_empty() {
+            environment.output.write(&InfoLine(&format!(
+                "no member of context {context_id} needs {:?}; nothing to grant",
+                self.capabilities
+            )));
+
+            return Ok(false);
+        }
+
+        self.send_grants(environment, config, endpoint, context_id, granter_id, pairs)
+            .await
+    }
+}
@@ -10,7 +10,7 @@ use reqwest::Client;
 
 use crate::cli::Environment;
 use crate::common::{
-    create_alias, delete_alias, fetch_multiaddr, load_config, lookup_alias, make_request,
+    client, create_alias, delete_alias, fetch_multiaddr, load_config, lookup_alias, make_request,
     multiaddr_to_url, resolve_alias, RequestType,
 };
 use crate::output::ErrorLine;
@@ -55,7 +55,7 @@ impl ContextIdentityCommand {
     pub async fn run(self, environment: &Environment) -> EyreResult<()> {
         let config = load_config(&environment.args.home, &environment.args.node_name).await?;
         let multiaddr = fetch_multiaddr(&config)?;
-        let client = Client::new();
+        let client = client();
 
         match self.command {
             ContextIdentitySubcommand::List { context, owned } => {
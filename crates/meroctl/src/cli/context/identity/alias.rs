@@ -3,17 +3,18 @@ use calimero_primitives::context::ContextId;
 use calimero_primitives::identity::PublicKey;
 use calimero_server_primitives::admin::GetContextIdentitiesResponse;
 use clap::Parser;
+use comfy_table::{Cell, Color, Table};
 use eyre::{OptionExt, Result as EyreResult, WrapErr};
 use libp2p::identity::Keypair;
 use libp2p::Multiaddr;
-use reqwest::Client;
+use serde::Serialize;
 
 use crate::cli::Environment;
 use crate::common::{
-    create_alias, delete_alias, fetch_multiaddr, load_config, lookup_alias, multiaddr_to_url,
-    resolve_alias,
+    client, create_alias, delete_alias, fetch_multiaddr, load_config, lookup_alias,
+    multiaddr_to_url, resolve_alias, resolve_alias_any_context,
 };
-use crate::output::ErrorLine;
+use crate::output::{themed, ErrorLine, Report};
 
 // Helper function needed by the Add subcommand implementation
 async fn identity_exists_in_context(
@@ -31,7 +32,7 @@ async fn identity_exists_in_context(
     let endpoint = format!("admin-api/dev/contexts/{}/identities", context_id);
     let url = multiaddr_to_url(multiaddr, &endpoint)?;
 
-    let response: GetContextIdentitiesResponse = Client::new()
+    let response: GetContextIdentitiesResponse = client()
         .get(url)
         .send()
         .await?
@@ -84,10 +85,46 @@ pub enum ContextIdentityAliasSubcommand {
 
         #[arg(help = "The context that the identity is a member of ")]
         #[arg(long, short)]
-        context: Alias<ContextId>,
+        #[arg(required_unless_present = "any_context", conflicts_with = "any_context")]
+        context: Option<Alias<ContextId>>,
+
+        #[arg(
+            long,
+            help = "Try every context the node knows and list where the alias resolves"
+        )]
+        any_context: bool,
     },
 }
 
+/// Where an identity alias resolves, across every context on the node.
+#[derive(Debug, Serialize)]
+struct AliasMatches {
+    alias: Alias<PublicKey>,
+    matches: Vec<(ContextId, PublicKey)>,
+}
+
+impl Report for AliasMatches {
+    fn report(&self) {
+        if self.matches.is_empty() {
+            println!("`{}` does not resolve in any context", self.alias);
+
+            return;
+        }
+
+        let mut table = Table::new();
+        let _ = table.set_header(vec![
+            Cell::new("Context").fg(themed(Color::Blue)),
+            Cell::new("Identity").fg(themed(Color::Blue)),
+        ]);
+
+        for (context_id, identity) in &self.matches {
+            let _ = table.add_row(vec![context_id.to_string(), identity.to_string()]);
+        }
+
+        println!("{table}");
+    }
+}
+
 impl ContextIdentityAliasCommand {
     pub async fn run(self, environment: &Environment) -> EyreResult<()> {
         let config = load_config(&environment.args.home, &environment.args.node_name).await?;
@@ -171,7 +208,25 @@ impl ContextIdentityAliasCommand {
 
                 environment.output.write(&res);
             }
-            ContextIdentityAliasSubcommand::Get { identity, context } => {
+            ContextIdentityAliasSubcommand::Get {
+                identity,
+                context,
+                any_context,
+            } => {
+                if any_context {
+                    let matches =
+                        resolve_alias_any_context(multiaddr, &config.identity, identity).await?;
+
+                    environment.output.write(&AliasMatches {
+                        alias: identity,
+                        matches,
+                    });
+
+                    return Ok(());
+                }
+
+                let context = context.expect("clap requires --context without --any-context");
+
                 let resolve_response =
                     resolve_alias(multiaddr, &config.identity, context, None).await?;
 
@@ -5,7 +5,7 @@ use comfy_table::{Cell, Color, Table};
 use eyre::Result as EyreResult;
 
 use crate::cli::Environment;
-use crate::output::Report;
+use crate::output::{themed, Report};
 
 #[derive(Debug, Parser)]
 #[command(about = "Generate public/private key pair used for context identity")]
@@ -14,7 +14,7 @@ pub struct GenerateCommand;
 impl Report for GenerateContextIdentityResponse {
     fn report(&self) {
         let mut table = Table::new();
-        let _ = table.set_header(vec![Cell::new("Generated Identity").fg(Color::Blue)]);
+        let _ = table.set_header(vec![Cell::new("Generated Identity").fg(themed(Color::Blue))]);
         let _ = table.add_row(vec![format!("Public Key: {}", self.data.public_key)]);
         let _ = table.add_row(vec![format!("Private Key: {}", self.data.private_key)]);
         println!("{table}");
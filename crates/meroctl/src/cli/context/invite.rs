@@ -1,18 +1,26 @@
+use std::str::FromStr;
+
+use calimero_config::ConfigFile;
 use calimero_primitives::alias::Alias;
 use calimero_primitives::context::{ContextId, ContextInvitationPayload};
 use calimero_primitives::identity::PublicKey;
 use calimero_server_primitives::admin::{InviteToContextRequest, InviteToContextResponse};
+use camino::Utf8PathBuf;
 use clap::Parser;
 use comfy_table::{Cell, Color, Table};
-use eyre::{OptionExt, Result as EyreResult};
-use reqwest::Client;
+use eyre::{bail, eyre, OptionExt, Result as EyreResult};
+use libp2p::Multiaddr;
+use serde::Serialize;
+use tokio::fs::{read_to_string, write};
 
+use crate::cli::context::capability::Capability;
+use crate::cli::context::member::MemberAddRequest;
 use crate::cli::Environment;
 use crate::common::{
-    create_alias, do_request, fetch_multiaddr, load_config, multiaddr_to_url, resolve_alias,
-    RequestType,
+    client, create_alias, do_request, ensure_reachable, fetch_multiaddr, load_config,
+    multiaddr_to_url, resolve_alias, RequestType,
 };
-use crate::output::Report;
+use crate::output::{themed, InfoLine, Progress, Report};
 
 #[derive(Debug, Parser)]
 #[command(about = "Create invitation to a context")]
@@ -20,34 +28,86 @@ pub struct InviteCommand {
     #[clap(long, short)]
     #[clap(
         value_name = "CONTEXT",
-        help = "The context for which invitation is created",
-        default_value = "default"
+        help = "The context for which invitation is created; defaults to `default`"
     )]
-    pub context: Alias<ContextId>,
+    pub context: Option<Alias<ContextId>>,
 
     #[clap(
         long = "as",
         value_name = "INVITER",
-        help = "The identifier of the inviter",
-        default_value = "default"
+        help = "The identifier of the inviter; defaults to `default`"
     )]
-    pub inviter: Alias<PublicKey>,
+    pub inviter: Option<Alias<PublicKey>>,
 
-    #[clap(value_name = "INVITEE", help = "The identifier of the invitee")]
-    pub invitee_id: PublicKey,
+    #[clap(
+        value_name = "INVITEE",
+        help = "The invitee, as a public key or an alias known in the context"
+    )]
+    #[clap(required_unless_present = "from_csv")]
+    pub invitee_id: Option<Invitee>,
 
     #[clap(value_name = "ALIAS", help = "The alias for the invitee")]
     pub name: Option<Alias<PublicKey>>,
+
+    /// Invite every key listed in this CSV instead of one invitee. Rows
+    /// are `<public key>[,<capability>;<capability>...]`; a header row is
+    /// skipped, malformed rows are reported without stopping the batch
+    #[clap(long, value_name = "FILE")]
+    #[clap(conflicts_with_all = ["invitee_id", "name"])]
+    pub from_csv: Option<Utf8PathBuf>,
+
+    /// Where to write the per-row results CSV; defaults to the input
+    /// path with `.results.csv` appended
+    #[clap(long, value_name = "FILE", requires = "from_csv")]
+    pub results: Option<Utf8PathBuf>,
+
+    /// Seconds until the invitation lapses and can no longer be
+    /// accepted; left unset, it never does
+    #[clap(long, value_name = "SECONDS")]
+    pub expires_in_secs: Option<u64>,
+
+    /// Skip the upfront reachability check against the node
+    #[clap(long)]
+    pub no_precheck: bool,
+
+    /// Don't announce which context and identity the `default` aliases
+    /// resolved to
+    #[clap(long, short)]
+    pub quiet: bool,
+}
+
+/// The invitee argument: a literal public key, or an alias to resolve in
+/// the invited context.
+#[derive(Clone, Copy, Debug)]
+pub enum Invitee {
+    Key(PublicKey),
+    Alias(Alias<PublicKey>),
+}
+
+impl FromStr for Invitee {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(key) = s.parse() {
+            return Ok(Self::Key(key));
+        }
+
+        s.parse().map(Self::Alias).map_err(|err| err.to_string())
+    }
 }
 
 impl Report for InviteToContextResponse {
     fn report(&self) {
         let mut table = Table::new();
         let _ = table.add_row(vec![
-            Cell::new("Invitation Details").fg(Color::Blue),
-            Cell::new("").fg(Color::Blue),
+            Cell::new("Invitation Details").fg(themed(Color::Blue)),
+            Cell::new("").fg(themed(Color::Blue)),
         ]);
 
+        if let Some(context_name) = &self.context_name {
+            let _ = table.add_row(vec!["Context", context_name]);
+        }
+
         match &self.data {
             Some(payload) => {
                 let payload_str = payload.to_string();
@@ -63,12 +123,54 @@ impl Report for InviteToContextResponse {
             }
         }
 
+        if !self.granted.is_empty() {
+            let set = self
+                .granted
+                .iter()
+                .map(|capability| format!("{capability:?}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            let _ = table.add_row(vec!["Pre-granted", &set]);
+        }
+
         println!("{table}");
     }
 }
 
+/// One processed CSV row, echoed into the results file.
+#[derive(Debug, Serialize)]
+pub struct CsvInviteRow {
+    pub line: usize,
+    pub invitee: String,
+    pub status: String,
+    pub invitation: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CsvInviteSummary {
+    pub invited: usize,
+    pub failed: usize,
+    pub results: Utf8PathBuf,
+}
+
+impl Report for CsvInviteSummary {
+    fn report(&self) {
+        println!(
+            "invited {} of {} rows; per-row results written to {}",
+            self.invited,
+            self.invited + self.failed,
+            self.results
+        );
+    }
+}
+
 impl InviteCommand {
     pub async fn run(self, environment: &Environment) -> EyreResult<()> {
+        if self.from_csv.is_some() {
+            return self.invite_from_csv(environment).await;
+        }
+
         let _ignored = self.invite(environment).await?;
         Ok(())
     }
@@ -78,25 +180,69 @@ impl InviteCommand {
 
         let multiaddr = fetch_multiaddr(&config)?;
 
-        let context_id = resolve_alias(multiaddr, &config.identity, self.context, None)
+        if !self.no_precheck {
+            ensure_reachable(multiaddr).await?;
+        }
+
+        let context = self
+            .context
+            .unwrap_or_else(|| "default".parse().expect("'default' is a valid alias name"));
+
+        let context_id = resolve_alias(multiaddr, &config.identity, context, None)
             .await?
             .value()
             .cloned()
             .ok_or_eyre("unable to resolve")?;
 
-        let inviter_id = resolve_alias(multiaddr, &config.identity, self.inviter, Some(context_id))
+        // Falling back to the `default` alias silently is how invitations
+        // go out for the wrong context; say what it resolved to.
+        if self.context.is_none() && !self.quiet {
+            environment.output.write(&InfoLine(&format!(
+                "no --context given; inviting to context {context_id} (alias `default`)"
+            )));
+        }
+
+        let inviter = self
+            .inviter
+            .unwrap_or_else(|| "default".parse().expect("'default' is a valid alias name"));
+
+        let inviter_id = resolve_alias(multiaddr, &config.identity, inviter, Some(context_id))
             .await?
             .value()
             .cloned()
             .ok_or_eyre("unable to resolve")?;
 
+        if self.inviter.is_none() && !self.quiet {
+            environment.output.write(&InfoLine(&format!(
+                "no --as given; inviting as {inviter_id} (alias `default`)"
+            )));
+        }
+
+        let Some(invitee) = self.invitee_id else {
+            unreachable!("clap requires INVITEE without --from-csv")
+        };
+
+        let invitee_id = match invitee {
+            Invitee::Key(key) => key,
+            Invitee::Alias(alias) => {
+                resolve_alias(multiaddr, &config.identity, alias, Some(context_id))
+                    .await?
+                    .value()
+                    .cloned()
+                    .ok_or_eyre("unable to resolve invitee")?
+            }
+        };
+
         let response: InviteToContextResponse = do_request(
-            &Client::new(),
+            &client(),
             multiaddr_to_url(multiaddr, "admin-api/dev/contexts/invite")?,
             Some(InviteToContextRequest {
                 context_id,
                 inviter_id,
-                invitee_id: self.invitee_id,
+                invitee_id,
+                capabilities: vec![],
+                idempotency_key: None,
+                expires_in_secs: self.expires_in_secs,
             }),
             &config.identity,
             RequestType::Post,
@@ -115,7 +261,7 @@ impl InviteCommand {
                 &config.identity,
                 name,
                 Some(context_id),
-                self.invitee_id,
+                invitee_id,
             )
             .await?;
             environment.output.write(&res);
@@ -123,4 +269,197 @@ impl InviteCommand {
 
         Ok(invitation_payload)
     }
+
+    /// Invites every key listed in the CSV, collecting a per-row status
+    /// instead of stopping at the first malformed or rejected row.
+    async fn invite_from_csv(&self, environment: &Environment) -> EyreResult<()> {
+        let path = self.from_csv.as_ref().expect("only called with --from-csv");
+
+        let config = load_config(&environment.args.home, &environment.args.node_name).await?;
+
+        let multiaddr = fetch_multiaddr(&config)?;
+
+        if !self.no_precheck {
+            ensure_reachable(multiaddr).await?;
+        }
+
+        let context = self
+            .context
+            .unwrap_or_else(|| "default".parse().expect("'default' is a valid alias name"));
+
+        let context_id = resolve_alias(multiaddr, &config.identity, context, None)
+            .await?
+            .value()
+            .cloned()
+            .ok_or_eyre("unable to resolve context")?;
+
+        if self.context.is_none() && !self.quiet {
+            environment.output.write(&InfoLine(&format!(
+                "no --context given; inviting to context {context_id} (alias `default`)"
+            )));
+        }
+
+        let inviter = self
+            .inviter
+            .unwrap_or_else(|| "default".parse().expect("'default' is a valid alias name"));
+
+        let inviter_id = resolve_alias(multiaddr, &config.identity, inviter, Some(context_id))
+            .await?
+            .value()
+            .cloned()
+            .ok_or_eyre("unable to resolve inviter")?;
+
+        if self.inviter.is_none() && !self.quiet {
+            environment.output.write(&InfoLine(&format!(
+                "no --as given; inviting as {inviter_id} (alias `default`)"
+            )));
+        }
+
+        let raw = read_to_string(path)
+            .await
+            .map_err(|_| eyre!("unable to read invitees from {path:?}"))?;
+
+        let mut lines: Vec<(usize, &str)> = raw
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .collect();
+
+        // Spreadsheet exports usually lead with a header row; a first
+        // field that isn't a public key is taken as one and skipped.
+        if let Some(&(_, first)) = lines.first() {
+            let field = first.split(',').next().unwrap_or("").trim();
+
+            if field.parse::<PublicKey>().is_err() {
+                let _ignored = lines.remove(0);
+            }
+        }
+
+        if lines.is_empty() {
+            bail!("{path:?} holds no invitee rows");
+        }
+
+        let total = lines.len();
+
+        let mut rows = Vec::with_capacity(total);
+        let mut invited = 0_usize;
+        let mut progress = Progress::new("inviting", total, self.quiet);
+
+        for (line, row) in lines {
+            let invitee = row.split(',').next().unwrap_or("").trim().to_owned();
+
+            match self
+                .invite_row(&config, multiaddr, context_id, inviter_id, row)
+                .await
+            {
+                Ok(payload) => {
+                    invited += 1;
+
+                    rows.push(CsvInviteRow {
+                        line,
+                        invitee,
+                        status: "invited".to_owned(),
+                        invitation: Some(payload),
+                    });
+                }
+                Err(err) => rows.push(CsvInviteRow {
+                    line,
+                    invitee,
+                    status: format!("error: {err}"),
+                    invitation: None,
+                }),
+            }
+
+            progress.tick();
+        }
+
+        progress.finish();
+
+        let results = self.results.clone().unwrap_or_else(|| {
+            Utf8PathBuf::from(format!("{path}.results.csv"))
+        });
+
+        let mut out = String::from("line,invitee,status,invitation\n");
+
+        for row in &rows {
+            out.push_str(&format!(
+                "{},{},\"{}\",{}\n",
+                row.line,
+                row.invitee,
+                row.status.replace('"', "\"\""),
+                row.invitation.as_deref().unwrap_or("")
+            ));
+        }
+
+        write(&results, out).await?;
+
+        environment.output.write(&CsvInviteSummary {
+            invited,
+            failed: total - invited,
+            results,
+        });
+
+        if invited == 0 {
+            environment.mark_no_op();
+        }
+
+        Ok(())
+    }
+
+    /// Parses and invites one `<public key>[,<capability>;...]` row,
+    /// returning the minted invitation payload.
+    async fn invite_row(
+        &self,
+        config: &ConfigFile,
+        multiaddr: &Multiaddr,
+        context_id: ContextId,
+        inviter_id: PublicKey,
+        row: &str,
+    ) -> EyreResult<String> {
+        let mut fields = row.split(',');
+
+        let key_field = fields.next().unwrap_or("").trim();
+
+        let invitee_id: PublicKey = key_field
+            .parse()
+            .map_err(|_| eyre!("`{key_field}` is not a public key"))?;
+
+        let capabilities = match fields.next().map(str::trim).filter(|field| !field.is_empty()) {
+            Some(field) => {
+                let mut capabilities = Vec::new();
+
+                for name in field.split(';') {
+                    capabilities.push(
+                        name.trim()
+                            .parse::<Capability>()
+                            .map_err(|err| eyre!("{err}"))?,
+                    );
+                }
+
+                capabilities
+            }
+            None => vec![],
+        };
+
+        let response: InviteToContextResponse = do_request(
+            &client(),
+            multiaddr_to_url(multiaddr, "admin-api/dev/contexts/invite")?,
+            Some(MemberAddRequest {
+                context_id,
+                inviter_id,
+                invitee_id,
+                capabilities,
+            }),
+            &config.identity,
+            RequestType::Post,
+        )
+        .await?;
+
+        let payload = response
+            .data
+            .ok_or_eyre("the node returned no invitation payload")?;
+
+        Ok(payload.to_string())
+    }
 }
@@ -5,13 +5,13 @@ use calimero_server_primitives::admin::{JoinContextRequest, JoinContextResponse}
 use clap::Parser;
 use comfy_table::{Cell, Color, Table};
 use eyre::Result as EyreResult;
-use reqwest::Client;
 
 use crate::cli::Environment;
 use crate::common::{
-    create_alias, do_request, fetch_multiaddr, load_config, multiaddr_to_url, RequestType,
+    client, create_alias, do_request, fetch_multiaddr, load_config, multiaddr_to_url,
+    RequestType,
 };
-use crate::output::Report;
+use crate::output::{themed, Report};
 
 #[derive(Debug, Parser)]
 #[command(about = "Join an application context")]
@@ -35,7 +35,7 @@ pub struct JoinCommand {
 impl Report for JoinContextResponse {
     fn report(&self) {
         let mut table = Table::new();
-        let _ = table.set_header(vec![Cell::new("Join Context Response").fg(Color::Blue)]);
+        let _ = table.set_header(vec![Cell::new("Join Context Response").fg(themed(Color::Blue))]);
 
         if let Some(payload) = &self.data {
             let _ = table.add_row(vec![format!("Context ID: {}", payload.context_id)]);
@@ -56,7 +56,7 @@ impl JoinCommand {
         let multiaddr = fetch_multiaddr(&config)?;
 
         let response: JoinContextResponse = do_request(
-            &Client::new(),
+            &client(),
             multiaddr_to_url(multiaddr, "admin-api/dev/contexts/join")?,
             Some(JoinContextRequest::new(
                 self.private_key,
@@ -2,11 +2,12 @@ use calimero_server_primitives::admin::GetContextsResponse;
 use clap::Parser;
 use comfy_table::{Cell, Color, Table};
 use eyre::Result as EyreResult;
-use reqwest::Client;
 
 use crate::cli::Environment;
-use crate::common::{do_request, fetch_multiaddr, load_config, multiaddr_to_url, RequestType};
-use crate::output::Report;
+use crate::common::{
+    client, do_request, fetch_multiaddr, load_config, multiaddr_to_url, RequestType,
+};
+use crate::output::{themed, Report};
 
 #[derive(Debug, Parser)]
 #[command(about = "List all contexts")]
@@ -16,9 +17,9 @@ impl Report for GetContextsResponse {
     fn report(&self) {
         let mut table = Table::new();
         let _ = table.set_header(vec![
-            Cell::new("Contexts").fg(Color::Blue),
-            Cell::new("ID").fg(Color::Blue),
-            Cell::new("Application ID").fg(Color::Blue),
+            Cell::new("Contexts").fg(themed(Color::Blue)),
+            Cell::new("ID").fg(themed(Color::Blue)),
+            Cell::new("Application ID").fg(themed(Color::Blue)),
         ]);
 
         for context in &self.data.contexts {
@@ -36,7 +37,7 @@ impl ListCommand {
         let config = load_config(&environment.args.home, &environment.args.node_name).await?;
 
         let response: GetContextsResponse = do_request(
-            &Client::new(),
+            &client(),
             multiaddr_to_url(fetch_multiaddr(&config)?, "admin-api/dev/contexts")?,
             None::<()>,
             &config.identity,
@@ -0,0 +1,136 @@
+use calimero_primitives::alias::Alias;
+use calimero_primitives::context::ContextId;
+use calimero_primitives::identity::PublicKey;
+use calimero_server_primitives::admin::InviteToContextResponse;
+use clap::{Parser, Subcommand};
+use eyre::{OptionExt, Result as EyreResult};
+use serde::Serialize;
+
+use crate::cli::context::capability::Capability;
+use crate::cli::Environment;
+use crate::common::{
+    client, create_alias, do_request, ensure_reachable, fetch_multiaddr, load_config,
+    multiaddr_to_url, resolve_alias, RequestType,
+};
+
+#[derive(Debug, Parser)]
+#[command(about = "Higher-level member management for a context")]
+pub struct MemberCommand {
+    #[command(subcommand)]
+    pub subcommand: MemberSubCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum MemberSubCommands {
+    Add(AddCommand),
+}
+
+/// Invites a member and pre-grants the intended capability set in one
+/// step, collapsing the usual invite-wait-grant onboarding dance.
+#[derive(Debug, Parser)]
+#[command(about = "Invite a member and pre-grant capabilities in one step")]
+pub struct AddCommand {
+    #[clap(long, short)]
+    #[clap(
+        value_name = "CONTEXT",
+        help = "The context to add the member to",
+        default_value = "default"
+    )]
+    pub context: Alias<ContextId>,
+
+    #[clap(
+        long = "as",
+        value_name = "INVITER",
+        help = "The identity inviting the member",
+        default_value = "default"
+    )]
+    pub inviter: Alias<PublicKey>,
+
+    #[clap(value_name = "INVITEE", help = "The identifier of the invitee")]
+    pub invitee_id: PublicKey,
+
+    #[clap(value_name = "ALIAS", help = "The alias for the invitee")]
+    pub name: Option<Alias<PublicKey>>,
+
+    /// Capability the member receives on acceptance; repeat for several
+    #[clap(long, value_name = "CAPABILITY")]
+    pub capability: Vec<Capability>,
+
+    /// Skip the upfront reachability check against the node
+    #[clap(long)]
+    pub no_precheck: bool,
+}
+
+/// Wire-compatible with the admin API's `InviteToContextRequest`; local
+/// so the capability list can use the CLI's own [`Capability`] enum.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemberAddRequest {
+    pub context_id: ContextId,
+    pub inviter_id: PublicKey,
+    pub invitee_id: PublicKey,
+    pub capabilities: Vec<Capability>,
+}
+
+impl MemberCommand {
+    pub async fn run(self, environment: &Environment) -> EyreResult<()> {
+        match self.subcommand {
+            MemberSubCommands::Add(add) => add.run(environment).await,
+        }
+    }
+}
+
+impl AddCommand {
+    pub async fn run(self, environment: &Environment) -> EyreResult<()> {
+        let config = load_config(&environment.args.home, &environment.args.node_name).await?;
+
+        let multiaddr = fetch_multiaddr(&config)?;
+
+        if !self.no_precheck {
+            ensure_reachable(multiaddr).await?;
+        }
+
+        let context_id = resolve_alias(multiaddr, &config.identity, self.context, None)
+            .await?
+            .value()
+            .cloned()
+            .ok_or_eyre("unable to resolve context")?;
+
+        let inviter_id = resolve_alias(multiaddr, &config.identity, self.inviter, Some(context_id))
+            .await?
+            .value()
+            .cloned()
+            .ok_or_eyre("unable to resolve inviter")?;
+
+        let response: InviteToContextResponse = do_request(
+            &client(),
+            multiaddr_to_url(multiaddr, "admin-api/dev/contexts/invite")?,
+            Some(MemberAddRequest {
+                context_id,
+                inviter_id,
+                invitee_id: self.invitee_id,
+                capabilities: self.capability.clone(),
+            }),
+            &config.identity,
+            RequestType::Post,
+        )
+        .await?;
+
+        environment.output.write(&response);
+
+        if let Some(name) = self.name {
+            let res = create_alias(
+                multiaddr,
+                &config.identity,
+                name,
+                Some(context_id),
+                self.invitee_id,
+            )
+            .await?;
+
+            environment.output.write(&res);
+        }
+
+        Ok(())
+    }
+}
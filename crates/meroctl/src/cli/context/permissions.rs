@@ -0,0 +1,118 @@
+use calimero_primitives::alias::Alias;
+use calimero_primitives::context::ContextId;
+use calimero_primitives::identity::PublicKey;
+use clap::Parser;
+use comfy_table::{Cell, Color, Table};
+use eyre::{OptionExt, Result as EyreResult};
+use serde::{Deserialize, Serialize};
+
+use crate::cli::context::capability::{sort_for_display, Capability};
+use crate::cli::Environment;
+use crate::common::{
+    client, fetch_multiaddr, load_config, make_request, multiaddr_to_url, resolve_alias,
+    RequestType,
+};
+use crate::output::{themed, Report};
+
+#[derive(Debug, Parser)]
+#[command(about = "List which capabilities each member holds in a context")]
+pub struct PermissionsCommand {
+    #[arg(help = "The context to inspect")]
+    #[arg(default_value = "default")]
+    pub context: Alias<ContextId>,
+
+    /// Scope the listing to one member; left unset, every member that
+    /// holds at least one capability is listed
+    #[arg(long = "for", value_name = "MEMBER")]
+    pub member: Option<Alias<PublicKey>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListPermissionsRequest {
+    pub context_id: ContextId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_id: Option<PublicKey>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ListPermissionsResponseData {
+    pub permissions: Vec<(PublicKey, Vec<Capability>)>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ListPermissionsResponse {
+    pub data: ListPermissionsResponseData,
+}
+
+impl Report for ListPermissionsResponse {
+    fn report(&self) {
+        if self.data.permissions.is_empty() {
+            println!("no member holds any capability");
+
+            return;
+        }
+
+        let mut table = Table::new();
+        let _ = table.set_header(vec![
+            Cell::new("Member").fg(themed(Color::Blue)),
+            Cell::new("Capabilities").fg(themed(Color::Blue)),
+        ]);
+
+        for (member, capabilities) in &self.data.permissions {
+            let mut capabilities = capabilities.clone();
+
+            sort_for_display(&mut capabilities);
+
+            let set = capabilities
+                .iter()
+                .map(|capability| format!("{capability:?}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            let _ = table.add_row(vec![member.to_string(), set]);
+        }
+
+        println!("{table}");
+    }
+}
+
+impl PermissionsCommand {
+    pub async fn run(self, environment: &Environment) -> EyreResult<()> {
+        let config = load_config(&environment.args.home, &environment.args.node_name).await?;
+
+        let multiaddr = fetch_multiaddr(&config)?;
+
+        let context_id = resolve_alias(multiaddr, &config.identity, self.context, None)
+            .await?
+            .value()
+            .cloned()
+            .ok_or_eyre("unable to resolve context")?;
+
+        let member_id = match self.member {
+            None => None,
+            Some(member) => Some(
+                resolve_alias(multiaddr, &config.identity, member, Some(context_id))
+                    .await?
+                    .value()
+                    .cloned()
+                    .ok_or_eyre("unable to resolve member")?,
+            ),
+        };
+
+        let url = multiaddr_to_url(multiaddr, "admin-api/dev/contexts/list-permissions")?;
+
+        make_request::<_, ListPermissionsResponse>(
+            environment,
+            &client(),
+            url,
+            Some(ListPermissionsRequest {
+                context_id,
+                member_id,
+            }),
+            &config.identity,
+            RequestType::Post,
+        )
+        .await
+    }
+}
@@ -0,0 +1,670 @@
+use std::str::FromStr;
+
+use calimero_config::ConfigFile;
+use calimero_primitives::alias::Alias;
+use calimero_primitives::context::ContextId;
+use calimero_primitives::identity::PublicKey;
+use calimero_server_primitives::admin::GetContextsResponse;
+use camino::Utf8PathBuf;
+use clap::Parser;
+use comfy_table::{Cell, Color, Table};
+use eyre::{bail, eyre, OptionExt, Result as EyreResult};
+use libp2p::Multiaddr;
+use serde::{Deserialize, Serialize};
+
+use crate::cli::context::capabilities::GetCapabilitiesResponse;
+use crate::cli::context::capability::{sort_for_display, Capability};
+use crate::cli::context::grant::{fetch_members, resolve_contexts, resolve_member, MemberSelector};
+use crate::cli::context::InvalidCapability;
+use crate::cli::Environment;
+use crate::common::{
+    client, do_request, ensure_reachable, fetch_multiaddr, load_config, resolve_alias,
+    ApiEndpoint, RequestType,
+};
+use crate::output::{themed, InfoLine, Progress, Report, WarnLine};
+
+#[derive(Debug, Parser)]
+#[command(about = "Revoke permissions from a member in a context")]
+pub struct RevokeCommand {
+    #[clap(long, short)]
+    #[clap(
+        value_name = "CONTEXT",
+        help = "The context to revoke permissions in; defaults to `default`"
+    )]
+    pub context: Option<Alias<ContextId>>,
+
+    #[clap(
+        long = "as",
+        value_name = "REVOKER",
+        help = "The identity revoking the permission; defaults to `default`"
+    )]
+    #[clap(env = "CALIMERO_IDENTITY", hide_env_values = true)]
+    pub revoker: Option<Alias<PublicKey>>,
+
+    #[clap(
+        value_name = "REVOKEE",
+        value_delimiter = ',',
+        num_args = 1,
+        help = "The members losing the permission, comma-separated; `@N` picks the N-th \
+                listed member"
+    )]
+    #[clap(required_unless_present_any = ["revokee_raw", "all_members"])]
+    pub revokees: Vec<MemberSelector>,
+
+    /// Revoke from every member of the context instead of one; combine
+    /// with --except to carve out the keys that keep the capability
+    #[clap(long, conflicts_with_all = ["revokees", "revokee_raw"])]
+    pub all_members: bool,
+
+    /// Members spared by --all-members; repeatable
+    #[clap(long, value_name = "MEMBER", requires = "all_members")]
+    pub except: Vec<Alias<PublicKey>>,
+
+    /// Use this literal public key as the revokee, skipping alias
+    /// resolution. Deliberately bypasses membership validation so stale
+    /// capability records of members who already left can be cleaned up.
+    #[clap(long, value_name = "PUBLIC_KEY", conflicts_with = "revokees")]
+    pub revokee_raw: Option<PublicKey>,
+
+    #[clap(
+        value_name = "CAPABILITY",
+        value_delimiter = ',',
+        num_args = 1,
+        help = "The capabilities or groups to revoke, comma-separated; omitted, every \
+                capability the member holds"
+    )]
+    pub capabilities: Vec<CapabilitySelector>,
+
+    /// Why this revocation is happening; recorded in the node's log for
+    /// audits
+    #[clap(long, value_name = "TEXT")]
+    pub reason: Option<String>,
+
+    /// Print the fully resolved request as JSON instead of sending it
+    #[clap(long)]
+    pub json: bool,
+
+    /// File with one context alias per line; the revocation is applied in each
+    #[clap(long, value_name = "PATH", conflicts_with = "context")]
+    pub context_from_alias_file: Option<Utf8PathBuf>,
+
+    /// Apply the revocation in every context on the node, for offboarding
+    /// a departing member in one sweep
+    #[clap(long, conflicts_with_all = ["context", "context_from_alias_file"])]
+    pub all_contexts: bool,
+
+    /// Skip the upfront reachability check against the node
+    #[clap(long)]
+    pub no_precheck: bool,
+
+    /// Don't announce which context and identity the `default` aliases
+    /// resolved to
+    #[clap(long, short)]
+    pub quiet: bool,
+
+    /// Allow the `@N` index selector; member order is whatever the node
+    /// returns, so indexes are for interactive use, not scripts
+    #[clap(long)]
+    pub by_index: bool,
+
+    /// Trace each resolution step - the alias inputs, the ids they
+    /// resolved to, and the final request - so a failing step is visible
+    #[clap(long)]
+    pub explain: bool,
+}
+
+/// Named capability groups; a group name given as the CAPABILITY argument
+/// expands to every capability it lists.
+pub const CAPABILITY_GROUPS: &[(&str, &[Capability])] = &[
+    ("application", &[Capability::ManageApplication]),
+    ("membership", &[Capability::ManageMembers]),
+    ("admin", &[Capability::ManageApplication, Capability::ManageMembers]),
+    (
+        "all",
+        &[
+            Capability::ManageApplication,
+            Capability::ManageMembers,
+            Capability::Proxy,
+        ],
+    ),
+];
+
+#[derive(Clone, Copy, Debug)]
+pub enum CapabilitySelector {
+    /// A single capability, e.g. `ManageMembers`.
+    One(Capability),
+    /// A named group expanded to its member capabilities.
+    Group(&'static str, &'static [Capability]),
+}
+
+impl FromStr for CapabilitySelector {
+    type Err = InvalidCapability;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.to_lowercase();
+
+        if let Some((name, members)) = CAPABILITY_GROUPS
+            .iter()
+            .find(|(name, _)| *name == normalized)
+        {
+            return Ok(Self::Group(name, members));
+        }
+
+        s.parse().map(Self::One)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct RevokePermissionRequest {
+    pub capabilities: Vec<(PublicKey, Capability)>,
+    pub signer_id: PublicKey,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RevokeSummary {
+    pub rows: Vec<(ContextId, usize)>,
+}
+
+impl Report for RevokeSummary {
+    fn report(&self) {
+        let mut table = Table::new();
+        let _ = table.set_header(vec![
+            Cell::new("Context").fg(themed(Color::Blue)),
+            Cell::new("Capabilities Revoked").fg(themed(Color::Blue)),
+        ]);
+
+        for (context_id, revoked) in &self.rows {
+            let _ = table.add_row(vec![context_id.to_string(), revoked.to_string()]);
+        }
+
+        println!("{table}");
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RevokePermissionResponseData {
+    pub capabilities: Vec<(PublicKey, Vec<Capability>)>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RevokePermissionResponse {
+    pub data: RevokePermissionResponseData,
+}
+
+impl Report for RevokePermissionResponse {
+    fn report(&self) {
+        let mut table = Table::new();
+        let _ = table.set_header(vec![
+            Cell::new("Member").fg(themed(Color::Green)),
+            Cell::new("Remaining Capabilities").fg(themed(Color::Green)),
+        ]);
+
+        for (identity, capabilities) in &self.data.capabilities {
+            let set = if capabilities.is_empty() {
+                "(none)".to_owned()
+            } else {
+                let mut capabilities = capabilities.clone();
+
+                sort_for_display(&mut capabilities);
+
+                capabilities
+                    .iter()
+                    .map(|capability| format!("{capability:?}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+
+            let _ = table.add_row(vec![identity.to_string(), set]);
+        }
+
+        println!("{table}");
+    }
+}
+
+/// The result of one (member, capability) revocation within a batch.
+#[derive(Debug, Serialize)]
+pub struct RevokeOutcome {
+    pub member: PublicKey,
+    pub capability: Capability,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Every outcome of a batched revocation, successes and failures alike,
+/// so a partially failed batch still reports what did go through.
+#[derive(Debug, Serialize)]
+pub struct BatchRevokeSummary {
+    pub outcomes: Vec<RevokeOutcome>,
+}
+
+impl Report for BatchRevokeSummary {
+    fn report(&self) {
+        let mut table = Table::new();
+        let _ = table.set_header(vec![
+            Cell::new("Member").fg(themed(Color::Green)),
+            Cell::new("Capability").fg(themed(Color::Green)),
+            Cell::new("Result").fg(themed(Color::Green)),
+        ]);
+
+        for outcome in &self.outcomes {
+            let result = match &outcome.error {
+                Some(error) => format!("failed: {error}"),
+                None => "revoked".to_owned(),
+            };
+
+            let _ = table.add_row(vec![
+                outcome.member.to_string(),
+                format!("{:?}", outcome.capability),
+                result,
+            ]);
+        }
+
+        println!("{table}");
+
+        let succeeded = self
+            .outcomes
+            .iter()
+            .filter(|outcome| outcome.error.is_none())
+            .count();
+
+        println!(
+            "{succeeded} of {} revocation(s) succeeded",
+            self.outcomes.len()
+        );
+    }
+}
+
+impl RevokeCommand {
+    pub async fn run(self, environment: &Environment) -> EyreResult<()> {
+        let config = load_config(&environment.args.home, &environment.args.node_name).await?;
+
+        let multiaddr = fetch_multiaddr(&config)?;
+
+        if !self.no_precheck {
+            ensure_reachable(multiaddr).await?;
+        }
+
+        let endpoint = ApiEndpoint::resolve(multiaddr)?;
+
+        let contexts = if self.all_contexts {
+            let response: GetContextsResponse = do_request(
+                &client(),
+                endpoint.url("admin-api/dev/contexts"),
+                None::<()>,
+                &config.identity,
+                RequestType::Get,
+            )
+            .await?;
+
+            response
+                .data
+                .contexts
+                .into_iter()
+                .map(|context| context.id)
+                .collect()
+        } else {
+            let context = self
+                .context
+                .unwrap_or_else(|| "default".parse().expect("'default' is a valid alias name"));
+
+            if self.explain {
+                match &self.context_from_alias_file {
+                    Some(path) => environment
+                        .output
+                        .write(&InfoLine(&format!("explain: resolving contexts from {path}"))),
+                    None => environment.output.write(&InfoLine(&format!(
+                        "explain: resolving context alias `{context}`"
+                    ))),
+                }
+            }
+
+            resolve_contexts(
+                multiaddr,
+                &config,
+                context,
+                self.context_from_alias_file.as_ref(),
+            )
+            .await?
+        };
+
+        if self.explain {
+            for context_id in &contexts {
+                environment
+                    .output
+                    .write(&InfoLine(&format!("explain: context -> {context_id}")));
+            }
+        }
+
+        // Falling back to the `default` alias silently is how revocations
+        // land in the wrong context; say what it resolved to.
+        if self.context.is_none()
+            && self.context_from_alias_file.is_none()
+            && !self.all_contexts
+            && !self.quiet
+        {
+            if let [context_id] = contexts[..] {
+                environment.output.write(&InfoLine(&format!(
+                    "no --context given; revoking in context {context_id} (alias `default`)"
+                )));
+            }
+        }
+
+        let mut summary = RevokeSummary { rows: vec![] };
+
+        let mut progress = Progress::new("revoking", contexts.len(), self.quiet);
+
+        for context_id in contexts {
+            let revoked = self
+                .revoke_in(environment, &config, multiaddr, &endpoint, context_id)
+                .await?;
+
+            summary.rows.push((context_id, revoked));
+
+            progress.tick();
+        }
+
+        progress.finish();
+
+        if summary.rows.len() > 1 {
+            environment.output.write(&summary);
+        }
+
+        // Nothing revoked anywhere means the command was a no-op; let the
+        // exit code say so.
+        if !self.json && summary.rows.iter().all(|&(_, revoked)| revoked == 0) {
+            environment.mark_no_op();
+        }
+
+        Ok(())
+    }
+
+    /// Revokes in one context, returning how many capabilities were taken
+    /// away.
+    async fn revoke_in(
+        &self,
+        environment: &Environment,
+        config: &ConfigFile,
+        multiaddr: &Multiaddr,
+        endpoint: &ApiEndpoint,
+        context_id: ContextId,
+    ) -> EyreResult<usize> {
+        let revoker = self
+            .revoker
+            .unwrap_or_else(|| "default".parse().expect("'default' is a valid alias name"));
+
+        if self.explain {
+            environment.output.write(&InfoLine(&format!(
+                "explain: resolving revoker alias `{revoker}` in context {context_id}"
+            )));
+        }
+
+        let revoker_id = resolve_alias(multiaddr, &config.identity, revoker, Some(context_id))
+            .await?
+            .value()
+            .cloned()
+            .ok_or_eyre("unable to resolve revoker")?;
+
+        if self.revoker.is_none() && !self.quiet {
+            environment.output.write(&InfoLine(&format!(
+                "no --as given; revoking as {revoker_id} (alias `default`)"
+            )));
+        }
+
+        if self.explain {
+            environment.output.write(&InfoLine(&format!(
+                "explain: revoker -> {revoker_id}"
+            )));
+        }
+
+        // Resolve every named revokee once, up front, so a typo in the
+        // last one doesn't leave the first half of the batch applied.
+        let mut named_targets: Vec<PublicKey> = Vec::new();
+
+        if let Some(revokee_id) = self.revokee_raw {
+            named_targets.push(revokee_id);
+        } else if !self.revokees.is_empty() {
+            for &revokee in &self.revokees {
+                if self.explain {
+                    environment
+                        .output
+                        .write(&InfoLine(&format!("explain: resolving revokee `{revokee}`")));
+                }
+
+                let revokee_id =
+                    resolve_member(multiaddr, config, endpoint, revokee, context_id, self.by_index)
+                        .await?;
+
+                if matches!(revokee, MemberSelector::Index(_)) {
+                    environment
+                        .output
+                        .write(&InfoLine(&format!("`{revokee}` resolved to {revokee_id}")));
+                }
+
+                if self.explain {
+                    environment
+                        .output
+                        .write(&InfoLine(&format!("explain: revokee -> {revokee_id}")));
+                }
+
+                named_targets.push(revokee_id);
+            }
+        } else if !self.all_members {
+            unreachable!("clap requires one of REVOKEE, --revokee-raw or --all-members")
+        }
+
+        // Check the revoker can actually revoke before sending the
+        // mutation; the server's rejection is an opaque 403.
+        let held: GetCapabilitiesResponse = do_request(
+            &client(),
+            endpoint.url(&format!("admin-api/dev/contexts/{context_id}/capabilities")),
+            None::<()>,
+            &config.identity,
+            RequestType::Get,
+        )
+        .await?;
+
+        let can_revoke = held
+            .data
+            .capabilities
+            .iter()
+            .find(|(member, _)| *member == revoker_id)
+            .is_some_and(|(_, capabilities)| {
+                capabilities
+                    .iter()
+                    .any(|capability| matches!(capability, Capability::ManageMembers))
+            });
+
+        if !can_revoke {
+            bail!(
+                "`{}` does not hold ManageMembers in context {} and cannot revoke",
+                revoker,
+                context_id
+            );
+        }
+
+        let targets: Vec<PublicKey> = if !self.all_members {
+            named_targets
+        } else {
+            let members = fetch_members(config, endpoint, context_id).await?;
+
+            let mut except = Vec::with_capacity(self.except.len());
+
+            for alias in &self.except {
+                let identity =
+                    resolve_alias(multiaddr, &config.identity, *alias, Some(context_id))
+                        .await?
+                        .value()
+                        .cloned()
+                        .ok_or_else(|| {
+                            eyre!("unable to resolve --except member `{alias}`")
+                        })?;
+
+                except.push(identity);
+            }
+
+            let targets: Vec<PublicKey> = members
+                .into_iter()
+                .filter(|member| !except.contains(member))
+                .collect();
+
+            if !self.quiet {
+                environment.output.write(&InfoLine(&format!(
+                    "revoking from {} of the context's members ({} excepted)",
+                    targets.len(),
+                    except.len()
+                )));
+            }
+
+            if targets.contains(&revoker_id) {
+                environment.output.write(&WarnLine(&format!(
+                    "the revoker {revoker_id} is not in --except; the revocation applies to them too"
+                )));
+            }
+
+            targets
+        };
+
+        let selected: Option<Vec<Capability>> = if self.capabilities.is_empty() {
+            None
+        } else {
+            let mut expanded: Vec<Capability> = Vec::new();
+
+            for selector in &self.capabilities {
+                match selector {
+                    CapabilitySelector::One(capability) => {
+                        if !expanded.contains(capability) {
+                            expanded.push(*capability);
+                        }
+                    }
+                    CapabilitySelector::Group(name, members) => {
+                        let listed = members
+                            .iter()
+                            .map(|capability| format!("{capability:?}"))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+
+                        environment
+                            .output
+                            .write(&InfoLine(&format!("group `{name}` expands to: {listed}")));
+
+                        for capability in *members {
+                            if !expanded.contains(capability) {
+                                expanded.push(*capability);
+                            }
+                        }
+                    }
+                }
+            }
+
+            Some(expanded)
+        };
+
+        let mut tuples: Vec<(PublicKey, Capability)> = vec![];
+
+        for target in targets {
+            // With no capability named, sweep away everything the member
+            // currently holds in this context.
+            let capabilities: Vec<Capability> = match &selected {
+                Some(capabilities) => capabilities.clone(),
+                None => held
+                    .data
+                    .capabilities
+                    .iter()
+                    .find(|(member, _)| *member == target)
+                    .map(|(_, capabilities)| capabilities.clone())
+                    .unwrap_or_default(),
+            };
+
+            if capabilities.is_empty() {
+                environment.output.write(&InfoLine(&format!(
+                    "`{target}` holds nothing in context {context_id}"
+                )));
+
+                continue;
+            }
+
+            tuples.extend(
+                capabilities
+                    .into_iter()
+                    .map(|capability| (target, capability)),
+            );
+        }
+
+        if tuples.is_empty() {
+            return Ok(0);
+        }
+
+        if self.explain || self.json {
+            let request = RevokePermissionRequest {
+                capabilities: tuples.clone(),
+                signer_id: revoker_id,
+                reason: self.reason.clone(),
+            };
+
+            if self.explain {
+                environment.output.write(&InfoLine(&format!(
+                    "explain: request -> POST admin-api/dev/contexts/{context_id}/capabilities/revoke {}",
+                    serde_json::to_string(&request)?
+                )));
+            }
+
+            if self.json {
+                println!("{}", serde_json::to_string_pretty(&request)?);
+
+                return Ok(0);
+            }
+        }
+
+        // One request per pair: a rejected revocation doesn't abort the
+        // rest of the batch, and the summary says which ones failed.
+        let mut outcomes = Vec::with_capacity(tuples.len());
+
+        for (member, capability) in tuples {
+            let request = RevokePermissionRequest {
+                capabilities: vec![(member, capability)],
+                signer_id: revoker_id,
+                reason: self.reason.clone(),
+            };
+
+            let result: EyreResult<RevokePermissionResponse> = do_request(
+                &client(),
+                endpoint.url(&format!(
+                    "admin-api/dev/contexts/{context_id}/capabilities/revoke"
+                )),
+                Some(request),
+                &config.identity,
+                RequestType::Post,
+            )
+            .await;
+
+            outcomes.push(RevokeOutcome {
+                member,
+                capability,
+                error: result.err().map(|err| err.to_string()),
+            });
+        }
+
+        let summary = BatchRevokeSummary { outcomes };
+
+        environment.output.write(&summary);
+
+        let revoked = summary
+            .outcomes
+            .iter()
+            .filter(|outcome| outcome.error.is_none())
+            .count();
+
+        let failed = summary.outcomes.len() - revoked;
+
+        if failed > 0 {
+            bail!(
+                "{failed} of {} revocation(s) failed in context {context_id}",
+                summary.outcomes.len()
+            );
+        }
+
+        Ok(revoked)
+    }
+}
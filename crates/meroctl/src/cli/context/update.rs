@@ -19,7 +19,8 @@ use tokio::sync::mpsc;
 
 use crate::cli::Environment;
 use crate::common::{
-    do_request, fetch_multiaddr, load_config, multiaddr_to_url, resolve_alias, RequestType,
+    client, do_request, fetch_multiaddr, load_config, multiaddr_to_url, resolve_alias,
+    RequestType,
 };
 use crate::output::{ErrorLine, InfoLine};
 
@@ -75,7 +76,7 @@ impl UpdateCommand {
     pub async fn run(self, environment: &Environment) -> EyreResult<()> {
         let config = load_config(&environment.args.home, &environment.args.node_name).await?;
         let multiaddr = fetch_multiaddr(&config)?;
-        let client = Client::new();
+        let client = client();
 
         let context_id = resolve_alias(multiaddr, &config.identity, self.context, None)
             .await?
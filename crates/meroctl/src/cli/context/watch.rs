@@ -11,7 +11,7 @@ use tokio_tungstenite::tungstenite::Message as WsMessage;
 
 use crate::cli::Environment;
 use crate::common::{fetch_multiaddr, load_config, multiaddr_to_url, resolve_alias};
-use crate::output::{ErrorLine, InfoLine, Report};
+use crate::output::{themed, ErrorLine, InfoLine, Report};
 
 pub const EXAMPLES: &str = r#"
   # Watch events from default context
@@ -51,7 +51,7 @@ pub struct WatchCommand {
 impl Report for Response {
     fn report(&self) {
         let mut table = Table::new();
-        let _ = table.set_header(vec![Cell::new("WebSocket Response").fg(Color::Blue)]);
+        let _ = table.set_header(vec![Cell::new("WebSocket Response").fg(themed(Color::Blue))]);
         let _ = table.add_row(vec![format!("ID: {:?}", self.id)]);
         let _ = table.add_row(vec![format!("Payload: {:#?}", self.body)]);
         println!("{table}");
@@ -0,0 +1,74 @@
+use calimero_primitives::alias::Alias;
+use calimero_primitives::context::ContextId;
+use calimero_primitives::identity::PublicKey;
+use clap::Parser;
+use comfy_table::{Cell, Color, Table};
+use eyre::{OptionExt, Result as EyreResult};
+use serde::Serialize;
+
+use crate::cli::Environment;
+use crate::common::{fetch_multiaddr, load_config, resolve_alias};
+use crate::output::{themed, Report};
+
+#[derive(Debug, Parser)]
+#[command(about = "Show the resolved default identity for a context")]
+pub struct WhoamiCommand {
+    #[clap(long, short)]
+    #[clap(
+        value_name = "CONTEXT",
+        help = "The context to resolve the identity in",
+        default_value = "default"
+    )]
+    pub context: Alias<ContextId>,
+
+    #[clap(
+        long = "as",
+        value_name = "IDENTITY",
+        help = "The identity alias to resolve",
+        default_value = "default"
+    )]
+    pub identity: Alias<PublicKey>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WhoamiResponse {
+    pub context_id: ContextId,
+    pub identity: PublicKey,
+}
+
+impl Report for WhoamiResponse {
+    fn report(&self) {
+        let mut table = Table::new();
+        let _ = table.set_header(vec![Cell::new("Identity Resolution").fg(themed(Color::Blue))]);
+        let _ = table.add_row(vec!["Context", &self.context_id.to_string()]);
+        let _ = table.add_row(vec!["Identity", &self.identity.to_string()]);
+        println!("{table}");
+    }
+}
+
+impl WhoamiCommand {
+    pub async fn run(self, environment: &Environment) -> EyreResult<()> {
+        let config = load_config(&environment.args.home, &environment.args.node_name).await?;
+
+        let multiaddr = fetch_multiaddr(&config)?;
+
+        let context_id = resolve_alias(multiaddr, &config.identity, self.context, None)
+            .await?
+            .value()
+            .cloned()
+            .ok_or_eyre("unable to resolve context")?;
+
+        let identity = resolve_alias(multiaddr, &config.identity, self.identity, Some(context_id))
+            .await?
+            .value()
+            .cloned()
+            .ok_or_eyre("unable to resolve identity")?;
+
+        environment.output.write(&WhoamiResponse {
+            context_id,
+            identity,
+        });
+
+        Ok(())
+    }
+}
@@ -3,11 +3,12 @@ use clap::Parser;
 use comfy_table::{Cell, Color, Table};
 use const_format::concatcp;
 use eyre::Result as EyreResult;
-use reqwest::Client;
 
 use crate::cli::Environment;
-use crate::common::{do_request, fetch_multiaddr, load_config, multiaddr_to_url, RequestType};
-use crate::output::Report;
+use crate::common::{
+    client, do_request, fetch_multiaddr, load_config, multiaddr_to_url, RequestType,
+};
+use crate::output::{themed, Report};
 
 pub const EXAMPLES: &str = r"
   #
@@ -25,7 +26,7 @@ pub struct PeersCommand;
 impl Report for GetPeersCountResponse {
     fn report(&self) {
         let mut table = Table::new();
-        let _ = table.set_header(vec![Cell::new("Connected Peers").fg(Color::Blue)]);
+        let _ = table.set_header(vec![Cell::new("Connected Peers").fg(themed(Color::Blue))]);
         let _ = table.add_row(vec![self.count.to_string()]);
         println!("{table}");
     }
@@ -36,7 +37,7 @@ impl PeersCommand {
         let config = load_config(&environment.args.home, &environment.args.node_name).await?;
 
         let response: GetPeersCountResponse = do_request(
-            &Client::new(),
+            &client(),
             multiaddr_to_url(fetch_multiaddr(&config)?, "admin-api/dev/peers")?,
             None::<()>,
             &config.identity,
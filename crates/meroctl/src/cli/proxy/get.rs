@@ -15,9 +15,10 @@ use serde_json::Value;
 
 use crate::cli::Environment;
 use crate::common::{
-    fetch_multiaddr, load_config, make_request, multiaddr_to_url, resolve_alias, RequestType,
+    client, fetch_multiaddr, load_config, make_request, multiaddr_to_url, resolve_alias,
+    RequestType,
 };
-use crate::output::Report;
+use crate::output::{themed, Report};
 
 #[derive(Parser, Debug)]
 #[command(about = "Fetch details about the proxy contract")]
@@ -56,7 +57,7 @@ pub enum GetRequest {
 impl Report for GetNumberOfActiveProposalsResponse {
     fn report(&self) {
         let mut table = Table::new();
-        let _ = table.set_header(vec![Cell::new("Active Proposals Count").fg(Color::Blue)]);
+        let _ = table.set_header(vec![Cell::new("Active Proposals Count").fg(themed(Color::Blue))]);
         let _ = table.add_row(vec![self.data.to_string()]);
         println!("{table}");
     }
@@ -65,7 +66,7 @@ impl Report for GetNumberOfActiveProposalsResponse {
 impl Report for GetNumberOfProposalApprovalsResponse {
     fn report(&self) {
         let mut table = Table::new();
-        let _ = table.set_header(vec![Cell::new("Proposal Approvals").fg(Color::Blue)]);
+        let _ = table.set_header(vec![Cell::new("Proposal Approvals").fg(themed(Color::Blue))]);
         let _ = table.add_row(vec![format!("Approvals: {:?}", self.data)]);
         println!("{table}");
     }
@@ -75,8 +76,8 @@ impl Report for GetProposalApproversResponse {
     fn report(&self) {
         let mut table = Table::new();
         let _ = table.set_header(vec![
-            Cell::new("Proposal Approvers").fg(Color::Blue),
-            Cell::new("Type").fg(Color::Blue),
+            Cell::new("Proposal Approvers").fg(themed(Color::Blue)),
+            Cell::new("Type").fg(themed(Color::Blue)),
         ]);
 
         for user in &self.data {
@@ -90,9 +91,9 @@ impl Report for GetProposalsResponse {
     fn report(&self) {
         let mut table = Table::new();
         let _ = table.set_header(vec![
-            Cell::new("Proposals").fg(Color::Blue),
-            Cell::new("ID").fg(Color::Blue),
-            Cell::new("Status").fg(Color::Blue),
+            Cell::new("Proposals").fg(themed(Color::Blue)),
+            Cell::new("ID").fg(themed(Color::Blue)),
+            Cell::new("Status").fg(themed(Color::Blue)),
         ]);
 
         for proposal in &self.data {
@@ -105,7 +106,7 @@ impl Report for GetProposalsResponse {
 impl Report for GetProposalResponse {
     fn report(&self) {
         let mut table = Table::new();
-        let _ = table.set_header(vec![Cell::new("Proposal Details").fg(Color::Blue)]);
+        let _ = table.set_header(vec![Cell::new("Proposal Details").fg(themed(Color::Blue))]);
         let _ = table.add_row(vec![format!("ID: {}", self.data.id)]);
         let _ = table.add_row(vec![format!("Status: {:?}", self.data)]);
         println!("{table}");
@@ -116,7 +117,7 @@ impl GetCommand {
     pub async fn run(&self, environment: &Environment) -> EyreResult<()> {
         let config = load_config(&environment.args.home, &environment.args.node_name).await?;
         let multiaddr = fetch_multiaddr(&config)?;
-        let client = Client::new();
+        let client = client();
 
         let context_id = resolve_alias(multiaddr, &config.identity, self.context, None)
             .await?
@@ -1,28 +1,156 @@
+use std::collections::HashMap;
 use std::fmt;
 use std::str::FromStr;
+use std::sync::{LazyLock, Mutex, OnceLock};
+use std::time::{Duration, SystemTime};
 
-use calimero_config::ConfigFile;
+use calimero_config::{ConfigFile, CONFIG_FILE};
 use calimero_primitives::alias::{Alias, ScopedAlias};
 use calimero_primitives::application::ApplicationId;
 use calimero_primitives::context::ContextId;
 use calimero_primitives::identity::PublicKey;
 use calimero_server_primitives::admin::{
     AliasKind, CreateAliasRequest, CreateAliasResponse, CreateApplicationIdAlias,
-    CreateContextIdAlias, CreateContextIdentityAlias, DeleteAliasResponse, LookupAliasResponse,
+    CreateContextIdAlias, CreateContextIdentityAlias, DeleteAliasResponse, GetContextsResponse,
+    LookupAliasResponse,
 };
-use camino::Utf8Path;
+use camino::{Utf8Path, Utf8PathBuf};
 use chrono::Utc;
+use clap::ValueEnum;
 use comfy_table::{Cell, Color, Table};
 use eyre::{bail, eyre, Result as EyreResult, WrapErr};
 use libp2p::identity::Keypair;
 use libp2p::multiaddr::Protocol;
 use libp2p::Multiaddr;
-use reqwest::{Client, Url};
+use reqwest::{Certificate, Client, Identity, Url};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
-use crate::cli::{ApiError, Environment};
-use crate::output::Report;
+use crate::cli::{ApiError, Environment, RootArgs};
+use crate::output::{themed, Report};
+
+/// The HTTP client shared by every admin command, so TLS material from
+/// the root flags is loaded once and connections are pooled.
+static CLIENT: OnceLock<Client> = OnceLock::new();
+
+/// Builds the shared HTTP client from the root TLS flags. Runs once, up
+/// front, so a bad certificate fails before any command does work.
+pub fn init_client(args: &RootArgs) -> EyreResult<()> {
+    let mut builder = Client::builder().timeout(Duration::from_secs(args.timeout));
+
+    if let Some(path) = &args.ca_cert {
+        let pem = std::fs::read(path).wrap_err_with(|| format!("Failed to read {path}"))?;
+
+        builder = builder.add_root_certificate(Certificate::from_pem(&pem)?);
+    }
+
+    if let (Some(cert), Some(key)) = (&args.client_cert, &args.client_key) {
+        let cert_pem = std::fs::read(cert).wrap_err_with(|| format!("Failed to read {cert}"))?;
+        let key_pem = std::fs::read(key).wrap_err_with(|| format!("Failed to read {key}"))?;
+
+        builder = builder.identity(Identity::from_pkcs8_pem(&cert_pem, &key_pem)?);
+    }
+
+    let _ignored = CLIENT.set(builder.build()?);
+
+    Ok(())
+}
+
+/// The shared HTTP client; clones hand out the same connection pool.
+pub fn client() -> Client {
+    CLIENT.get_or_init(Client::new).clone()
+}
+
+/// How requests prove who they are to the node's admin API.
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+pub enum AuthScheme {
+    /// No credentials at all; for local nodes that don't check any
+    Dev,
+    /// Sign each request's timestamp with the node identity; what the
+    /// node's admin API expects today
+    #[default]
+    Signed,
+    /// Send `--auth-token` as an `Authorization: Bearer` header
+    Bearer,
+}
+
+/// The scheme (and bearer token, if any) every request uses; set once at
+/// startup from the root flags.
+static AUTH: OnceLock<(AuthScheme, Option<String>)> = OnceLock::new();
+
+/// Fixes the auth scheme for the rest of the process, failing up front
+/// when `bearer` was asked for without a token.
+pub fn init_auth(args: &RootArgs) -> EyreResult<()> {
+    if matches!(args.auth, AuthScheme::Bearer) && args.auth_token.is_none() {
+        bail!("--auth bearer needs a token; pass --auth-token or set CALIMERO_AUTH_TOKEN");
+    }
+
+    let _ignored = AUTH.set((args.auth, args.auth_token.clone()));
+
+    Ok(())
+}
+
+/// How many times a failed request is retried; set once at startup from
+/// the root flags.
+static RETRIES: OnceLock<u32> = OnceLock::new();
+
+/// Fixes the retry budget for the rest of the process.
+pub fn init_retries(args: &RootArgs) {
+    let _ignored = RETRIES.set(args.retries);
+}
+
+fn retry_budget() -> u32 {
+    RETRIES.get().copied().unwrap_or(DEFAULT_RETRIES)
+}
+
+/// Retries on transient failures when `--retries` is not given.
+pub const DEFAULT_RETRIES: u32 = 2;
+
+/// Per-attempt timeout when `--timeout` is not given, in seconds.
+pub const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+/// Whether `--trace-http` was given; set once at startup.
+static TRACE_HTTP: OnceLock<bool> = OnceLock::new();
+
+/// Turns on wire tracing for the rest of the process; later calls are
+/// ignored.
+pub fn enable_http_trace() {
+    let _ignored = TRACE_HTTP.set(true);
+}
+
+fn http_trace_enabled() -> bool {
+    TRACE_HTTP.get().copied().unwrap_or(false)
+}
+
+/// Blanks out values under keys that obviously hold secrets, so traced
+/// request bodies are safe to paste into bug reports.
+fn redact(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                let lowered = key.to_lowercase();
+
+                if ["private", "secret", "token", "password", "signature"]
+                    .iter()
+                    .any(|marker| lowered.contains(marker))
+                {
+                    *value = serde_json::Value::String("<redacted>".to_owned());
+                } else {
+                    redact(value);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                redact(item);
+            }
+        }
+        serde_json::Value::Null
+        | serde_json::Value::Bool(_)
+        | serde_json::Value::Number(_)
+        | serde_json::Value::String(_) => {}
+    }
+}
 
 pub fn multiaddr_to_url(multiaddr: &Multiaddr, api_path: &str) -> EyreResult<Url> {
     #[expect(clippy::wildcard_enum_match_arm, reason = "Acceptable here")]
@@ -48,6 +176,47 @@ pub fn multiaddr_to_url(multiaddr: &Multiaddr, api_path: &str) -> EyreResult<Url
     Ok(url)
 }
 
+/// The node's admin API base, resolved once from the configured listen
+/// address so commands that issue several requests - batch grants, the
+/// capability matrix - don't re-parse the multiaddr per call.
+#[derive(Clone, Debug)]
+pub struct ApiEndpoint {
+    base: Url,
+}
+
+impl ApiEndpoint {
+    pub fn resolve(multiaddr: &Multiaddr) -> EyreResult<Self> {
+        let base = multiaddr_to_url(multiaddr, "")?;
+
+        Ok(Self { base })
+    }
+
+    /// A full URL with `api_path` appended to the resolved base.
+    #[must_use]
+    pub fn url(&self, api_path: &str) -> Url {
+        let mut url = self.base.clone();
+
+        url.set_path(api_path);
+
+        url
+    }
+}
+
+/// Whether a failed request is worth retrying: connection errors,
+/// timeouts and 5xx responses are transient; anything else - 4xx above
+/// all - reflects the request itself and is returned immediately.
+fn is_transient(err: &eyre::Report) -> bool {
+    if let Some(err) = err.downcast_ref::<reqwest::Error>() {
+        return err.is_connect() || err.is_timeout();
+    }
+
+    if let Some(err) = err.downcast_ref::<ApiError>() {
+        return err.status_code >= 500;
+    }
+
+    false
+}
+
 pub async fn do_request<I, O>(
     client: &Client,
     url: Url,
@@ -59,21 +228,137 @@ where
     I: Serialize,
     O: DeserializeOwned,
 {
-    let timestamp = Utc::now().timestamp().to_string();
-    let signature = keypair.sign(timestamp.as_bytes())?;
+    let budget = retry_budget();
+
+    let mut attempt: u32 = 0;
+
+    loop {
+        attempt += 1;
+
+        let err = match send_once(client, url.clone(), &body, keypair, req_type).await {
+            Ok(output) => return Ok(output),
+            Err(err) => err,
+        };
+
+        if !is_transient(&err) || attempt > budget {
+            if attempt > 1 {
+                // The caller sees how long the node was given before
+                // giving up, not just the last failure.
+                if let Some(api_err) = err.downcast_ref::<ApiError>() {
+                    bail!(ApiError {
+                        status_code: api_err.status_code,
+                        message: format!("{} (after {attempt} attempts)", api_err.message),
+                    });
+                }
+
+                return Err(err.wrap_err(format!("request failed after {attempt} attempts")));
+            }
+
+            return Err(err);
+        }
+
+        // Exponential backoff, capped so the total elapsed time stays
+        // bounded by attempts * (timeout + 2s).
+        let backoff = Duration::from_millis(
+            250_u64
+                .checked_shl(attempt - 1)
+                .map_or(2_000, |millis| millis.min(2_000)),
+        );
+
+        if http_trace_enabled() {
+            eprintln!(
+                "[http] transient failure (attempt {attempt}); retrying in {}ms",
+                backoff.as_millis()
+            );
+        }
+
+        tokio::time::sleep(backoff).await;
+    }
+}
+
+/// One request/response round trip; `do_request` wraps this in the retry
+/// loop.
+async fn send_once<I, O>(
+    client: &Client,
+    url: Url,
+    body: &Option<I>,
+    keypair: &Keypair,
+    req_type: RequestType,
+) -> EyreResult<O>
+where
+    I: Serialize,
+    O: DeserializeOwned,
+{
+    if http_trace_enabled() {
+        let method = match req_type {
+            RequestType::Get => "GET",
+            RequestType::Post => "POST",
+            RequestType::Delete => "DELETE",
+        };
+
+        eprintln!("[http] {method} {url}");
+
+        if let Some(body) = body {
+            if let Ok(mut json) = serde_json::to_value(body) {
+                redact(&mut json);
+
+                eprintln!("[http] request: {json}");
+            }
+        }
+    }
 
     let mut builder = match req_type {
         RequestType::Get => client.get(url),
-        RequestType::Post => client.post(url).json(&body),
+        RequestType::Post => client.post(url).json(body),
         RequestType::Delete => client.delete(url),
     };
 
-    builder = builder
-        .header("X-Signature", bs58::encode(signature).into_string())
-        .header("X-Timestamp", timestamp);
+    let (scheme, token) = AUTH
+        .get()
+        .map_or((AuthScheme::Signed, None), |(scheme, token)| {
+            (*scheme, token.as_deref())
+        });
+
+    builder = match scheme {
+        AuthScheme::Dev => builder,
+        AuthScheme::Signed => {
+            let timestamp = Utc::now().timestamp().to_string();
+            let signature = keypair.sign(timestamp.as_bytes())?;
+
+            builder
+                .header("X-Signature", bs58::encode(signature).into_string())
+                .header("X-Timestamp", timestamp)
+        }
+        AuthScheme::Bearer => {
+            // `init_auth` refuses bearer without a token, so this only
+            // trips if a request is made before initialization.
+            let token = token
+                .ok_or_else(|| eyre!("--auth bearer needs a token; pass --auth-token"))?;
+
+            builder.bearer_auth(token)
+        }
+    };
 
     let response = builder.send().await?;
 
+    // Tracing has to buffer the body as text, so it takes its own path
+    // through deserialization.
+    if http_trace_enabled() {
+        let status = response.status();
+        let text = response.text().await?;
+
+        eprintln!("[http] response: {status} {text}");
+
+        if !status.is_success() {
+            bail!(ApiError {
+                status_code: status.as_u16(),
+                message: text,
+            });
+        }
+
+        return Ok(serde_json::from_str(&text)?);
+    }
+
     if !response.status().is_success() {
         bail!(ApiError {
             status_code: response.status().as_u16(),
@@ -135,27 +420,145 @@ where
 // }
 
 pub async fn load_config(home: &Utf8Path, node_name: &str) -> EyreResult<ConfigFile> {
+    let discovered;
+
+    let node_name = if node_name.is_empty() {
+        discovered = discover_node(home)?;
+
+        &discovered
+    } else {
+        node_name
+    };
+
     let path = home.join(node_name);
 
     if !ConfigFile::exists(&path) {
         bail!("Config file does not exist");
     }
 
+    // Commands load the config once per invocation, but batch tooling
+    // drives many commands in one process; serve repeats from memory
+    // until the file changes on disk.
+    let modified = std::fs::metadata(path.join(CONFIG_FILE))
+        .and_then(|metadata| metadata.modified())
+        .ok();
+
+    if let Some(modified) = modified {
+        let cache = CONFIG_CACHE.lock().expect("config cache poisoned");
+
+        if let Some((cached_at, config)) = cache.get(&path) {
+            if *cached_at == modified {
+                return Ok(config.clone());
+            }
+        }
+    }
+
     let config = ConfigFile::load(&path)
         .await
         .wrap_err("Failed to load config file")?;
 
+    if let Some(modified) = modified {
+        let _ignored = CONFIG_CACHE
+            .lock()
+            .expect("config cache poisoned")
+            .insert(path, (modified, config.clone()));
+    }
+
     Ok(config)
 }
 
+static CONFIG_CACHE: LazyLock<Mutex<HashMap<Utf8PathBuf, (SystemTime, ConfigFile)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Finds the one initialized node under `home` when `--node-name` was
+/// omitted; with several candidates, lists them and asks for the flag.
+fn discover_node(home: &Utf8Path) -> EyreResult<String> {
+    let mut nodes = Vec::new();
+
+    for entry in home
+        .read_dir_utf8()
+        .wrap_err_with(|| format!("Failed to read {home}"))?
+    {
+        let entry = entry?;
+
+        if ConfigFile::exists(entry.path()) {
+            nodes.push(entry.file_name().to_owned());
+        }
+    }
+
+    nodes.sort();
+
+    match &*nodes {
+        [] => bail!("No initialized node found in {home}; pass --node-name"),
+        [node] => Ok(node.clone()),
+        _ => bail!(
+            "Multiple nodes found in {home}: {}; pass --node-name",
+            nodes.join(", ")
+        ),
+    }
+}
+
+/// Fails fast when the node's admin API isn't answering, so mutating
+/// commands error upfront instead of deep inside a request.
+pub async fn ensure_reachable(multiaddr: &Multiaddr) -> EyreResult<()> {
+    let url = multiaddr_to_url(multiaddr, "admin-api/health")?;
+
+    let reachable = client()
+        .get(url.clone())
+        .send()
+        .await
+        .map(|response| response.status().is_success())
+        .unwrap_or(false);
+
+    if !reachable {
+        bail!("node at {url} is not reachable");
+    }
+
+    Ok(())
+}
+
 pub fn fetch_multiaddr(config: &ConfigFile) -> EyreResult<&Multiaddr> {
     let Some(multiaddr) = config.network.server.listen.first() else {
         bail!("No address.")
     };
 
+    validate_multiaddr(multiaddr)?;
+
     Ok(multiaddr)
 }
 
+/// Checks the configured listen address carries an IP + TCP stack the
+/// admin client can speak, naming the unusable segment otherwise instead
+/// of failing cryptically once a request is sent.
+fn validate_multiaddr(multiaddr: &Multiaddr) -> EyreResult<()> {
+    let mut has_ip = false;
+    let mut has_tcp = false;
+
+    for protocol in multiaddr.iter() {
+        #[expect(clippy::wildcard_enum_match_arm, reason = "Acceptable here")]
+        match protocol {
+            Protocol::Ip4(_) => has_ip = true,
+            Protocol::Tcp(_) => has_tcp = true,
+            Protocol::Http | Protocol::Https => {}
+            other => bail!(
+                "the configured listen address `{multiaddr}` contains `{other}`, which the \
+                 admin client cannot use; `server.listen` must be an /ip4/../tcp/.. address"
+            ),
+        }
+    }
+
+    if !has_ip {
+        bail!("the configured listen address `{multiaddr}` has no /ip4 segment");
+    }
+
+    if !has_tcp {
+        bail!("the configured listen address `{multiaddr}` has no /tcp segment");
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Copy)]
 pub enum RequestType {
     Get,
     Post,
@@ -234,7 +637,7 @@ impl UrlFragment for ApplicationId {
 impl Report for CreateAliasResponse {
     fn report(&self) {
         let mut table = Table::new();
-        let _ = table.set_header(vec![Cell::new("Alias Created").fg(Color::Green)]);
+        let _ = table.set_header(vec![Cell::new("Alias Created").fg(themed(Color::Green))]);
         let _ = table.add_row(vec!["Successfully created alias"]);
         println!("{table}");
     }
@@ -264,7 +667,7 @@ where
     };
 
     let response: CreateAliasResponse = do_request(
-        &Client::new(),
+        &client(),
         multiaddr_to_url(multiaddr, &format!("{prefix}/{kind}{scope}"))?,
         Some(body),
         keypair,
@@ -278,7 +681,7 @@ where
 impl Report for DeleteAliasResponse {
     fn report(&self) {
         let mut table = Table::new();
-        let _ = table.set_header(vec![Cell::new("Alias Deleted").fg(Color::Green)]);
+        let _ = table.set_header(vec![Cell::new("Alias Deleted").fg(themed(Color::Green))]);
         let _ = table.add_row(vec!["Successfully deleted alias"]);
         println!("{table}");
     }
@@ -301,7 +704,7 @@ where
         T::scoped(scope.as_ref()).map_or_else(Default::default, |scope| format!("{}/", scope));
 
     let response: DeleteAliasResponse = do_request(
-        &Client::new(),
+        &client(),
         multiaddr_to_url(multiaddr, &format!("{prefix}/{kind}/{scope}{alias}"))?,
         None::<()>,
         keypair,
@@ -329,7 +732,7 @@ where
         T::scoped(scope.as_ref()).map_or_else(Default::default, |scope| format!("{}/", scope));
 
     let response = do_request(
-        &Client::new(),
+        &client(),
         multiaddr_to_url(multiaddr, &format!("{prefix}/{kind}/{scope}{alias}"))?,
         None::<()>,
         keypair,
@@ -343,7 +746,7 @@ where
 impl<T: fmt::Display> Report for LookupAliasResponse<T> {
     fn report(&self) {
         let mut table = Table::new();
-        let _ = table.set_header(vec![Cell::new("Alias Lookup").fg(Color::Blue)]);
+        let _ = table.set_header(vec![Cell::new("Alias Lookup").fg(themed(Color::Blue))]);
 
         match &self.data.value {
             Some(value) => {
@@ -369,13 +772,16 @@ pub struct ResolveResponse<T> {
 enum ResolveResponseValue<T> {
     Lookup(LookupAliasResponse<T>),
     Parsed(T),
+    Cached(T),
 }
 
 impl<T> ResolveResponse<T> {
     pub fn value(&self) -> Option<&T> {
         match self.value.as_ref()? {
             ResolveResponseValue::Lookup(value) => value.data.value.as_ref(),
-            ResolveResponseValue::Parsed(value) => Some(value),
+            ResolveResponseValue::Parsed(value) | ResolveResponseValue::Cached(value) => {
+                Some(value)
+            }
         }
     }
 }
@@ -383,7 +789,7 @@ impl<T> ResolveResponse<T> {
 impl<T: fmt::Display> Report for ResolveResponse<T> {
     fn report(&self) {
         let mut table = Table::new();
-        let _ = table.set_header(vec![Cell::new("Alias Resolution").fg(Color::Blue)]);
+        let _ = table.set_header(vec![Cell::new("Alias Resolution").fg(themed(Color::Blue))]);
         let _ = table.add_row(vec!["Alias", self.alias.as_str()]);
 
         match &self.value {
@@ -395,6 +801,10 @@ impl<T: fmt::Display> Report for ResolveResponse<T> {
                 let _ = table.add_row(vec!["Type", "Direct"]);
                 let _ = table.add_row(vec!["Value", &value.to_string()]);
             }
+            Some(ResolveResponseValue::Cached(value)) => {
+                let _ = table.add_row(vec!["Type", "Cached"]);
+                let _ = table.add_row(vec!["Value", &value.to_string()]);
+            }
             None => {
                 let _ = table.add_row(vec!["Status", "Not Resolved"]);
             }
@@ -410,22 +820,116 @@ pub(crate) async fn resolve_alias<T>(
     scope: Option<T::Scope>,
 ) -> EyreResult<ResolveResponse<T>>
 where
-    T: ScopedAlias + UrlFragment + FromStr + DeserializeOwned,
+    T: ScopedAlias + UrlFragment + FromStr + DeserializeOwned + PartialEq + fmt::Display,
 {
-    let value = lookup_alias(multiaddr, keypair, alias, scope).await?;
+    // Successful resolutions within a single invocation, so commands that
+    // reference the same alias repeatedly only hit the node once.
+    static RESOLVED: LazyLock<Mutex<HashMap<(&str, Option<String>, String), String>>> =
+        LazyLock::new(|| Mutex::new(HashMap::new()));
+
+    let cache_key = (
+        T::KIND,
+        T::scoped(scope.as_ref()).map(ToOwned::to_owned),
+        alias.to_string(),
+    );
+
+    if let Some(cached) = RESOLVED
+        .lock()
+        .expect("alias cache poisoned")
+        .get(&cache_key)
+    {
+        let value = cached
+            .parse()
+            .map_err(|_| eyre!("cached alias value no longer parses"))?;
 
-    if value.data.value.is_some() {
         return Ok(ResolveResponse {
             alias,
-            value: Some(ResolveResponseValue::Lookup(value)),
+            value: Some(ResolveResponseValue::Cached(value)),
         });
     }
 
-    let value = alias
-        .as_str()
-        .parse()
-        .ok()
-        .map(ResolveResponseValue::Parsed);
+    let parsed = alias.as_str().parse::<T>().ok();
+
+    // A raw identifier is self-evident - if the alias lookup itself fails,
+    // fall back to it instead of aborting.
+    let looked_up = match lookup_alias(multiaddr, keypair, alias, scope).await {
+        Ok(looked_up) => looked_up,
+        Err(err) => {
+            let Some(parsed) = parsed else {
+                return Err(err);
+            };
+
+            return Ok(ResolveResponse {
+                alias,
+                value: Some(ResolveResponseValue::Parsed(parsed)),
+            });
+        }
+    };
+
+    match (&looked_up.data.value, parsed) {
+        // The name is both a registered alias and a valid raw value - don't
+        // silently pick one over the other.
+        (Some(value), Some(parsed)) if *value != parsed => bail!(
+            "`{alias}` is ambiguous: it is an alias for `{value}` but is also a valid \
+             identifier itself; delete the alias or pass the raw value explicitly"
+        ),
+        (Some(value), _) => {
+            let _ = RESOLVED
+                .lock()
+                .expect("alias cache poisoned")
+                .insert(cache_key, value.to_string());
+
+            Ok(ResolveResponse {
+                alias,
+                value: Some(ResolveResponseValue::Lookup(looked_up)),
+            })
+        }
+        (None, parsed) => {
+            if let Some(parsed) = &parsed {
+                let _ = RESOLVED
+                    .lock()
+                    .expect("alias cache poisoned")
+                    .insert(cache_key, parsed.to_string());
+            }
+
+            Ok(ResolveResponse {
+                alias,
+                value: parsed.map(ResolveResponseValue::Parsed),
+            })
+        }
+    }
+}
+
+/// Tries an identity alias in every context the node knows, returning
+/// each context where it resolves together with the identity it names
+/// there. For when the alias is known but the context it lives in is
+/// not; `resolve_alias` stays scoped once the context is settled.
+pub(crate) async fn resolve_alias_any_context(
+    multiaddr: &Multiaddr,
+    keypair: &Keypair,
+    alias: Alias<PublicKey>,
+) -> EyreResult<Vec<(ContextId, PublicKey)>> {
+    let contexts: GetContextsResponse = do_request(
+        &client(),
+        multiaddr_to_url(multiaddr, "admin-api/dev/contexts")?,
+        None::<()>,
+        keypair,
+        RequestType::Get,
+    )
+    .await?;
+
+    let mut matches = Vec::new();
+
+    for context in contexts.data.contexts {
+        let found = lookup_alias(multiaddr, keypair, alias, Some(context.id))
+            .await?
+            .data
+            .value;
+
+        if let Some(identity) = found {
+            matches.push((context.id, identity));
+        }
+    }
 
-    Ok(ResolveResponse { alias, value })
+    Ok(matches)
 }
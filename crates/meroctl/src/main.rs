@@ -25,7 +25,7 @@ async fn main() -> ExitCode {
 
     let command = RootCommand::parse();
     match command.run().await {
-        Ok(()) => ExitCode::SUCCESS,
+        Ok(code) => code,
         Err(err) => err.into(),
     }
 }
@@ -1,5 +1,11 @@
+use std::fs::OpenOptions;
+use std::io::{stderr, stdout, IsTerminal, Write as _};
+use std::sync::OnceLock;
+
+use camino::Utf8PathBuf;
 use clap::ValueEnum;
 use color_eyre::owo_colors::OwoColorize;
+use comfy_table::Color;
 use serde::Serialize;
 
 #[derive(Clone, Copy, Debug, Default, ValueEnum)]
@@ -9,9 +15,64 @@ pub enum Format {
     Human,
 }
 
+impl Format {
+    /// Human output for a person at a terminal, JSON when piped, so
+    /// `meroctl ... | jq` works without a flag.
+    pub fn detect() -> Self {
+        if stdout().is_terminal() {
+            Self::Human
+        } else {
+            Self::Json
+        }
+    }
+}
+
+/// Color theme for human-readable tables; `mono` drops color entirely,
+/// for accessibility and piping.
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+pub enum Theme {
+    #[default]
+    Default,
+    Mono,
+    Solarized,
+}
+
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+/// Fixes the theme for the rest of the process; later calls are ignored.
+pub fn set_theme(theme: Theme) {
+    let _ignored = THEME.set(theme);
+}
+
+/// Maps a table's hardcoded color through the active theme.
+pub fn themed(color: Color) -> Color {
+    match THEME.get().copied().unwrap_or_default() {
+        Theme::Default => color,
+        Theme::Mono => Color::Reset,
+        Theme::Solarized => {
+            #[expect(
+                clippy::wildcard_enum_match_arm,
+                reason = "only the colors the tables actually use get remapped"
+            )]
+            match color {
+                Color::Blue => Color::Cyan,
+                Color::Green => Color::DarkGreen,
+                Color::Yellow => Color::DarkYellow,
+                Color::Red => Color::DarkRed,
+                other => other,
+            }
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct Output {
     format: Format,
+    /// When set, every reported value is appended here as one JSON object
+    /// per line, regardless of the terminal format. More robust for
+    /// automation than shell redirection, which loses structure for
+    /// human-formatted output.
+    file: Option<Utf8PathBuf>,
 }
 
 pub trait Report {
@@ -19,15 +80,37 @@ pub trait Report {
 }
 
 impl Output {
-    pub const fn new(output_type: Format) -> Self {
+    pub const fn new(output_type: Format, file: Option<Utf8PathBuf>) -> Self {
         Self {
             format: output_type,
+            file,
         }
     }
 
     pub fn write<T: Serialize + Report>(&self, value: &T) {
+        let json = serde_json::to_string(&value);
+
+        if let Some(path) = &self.file {
+            match &json {
+                Ok(json) => {
+                    let written = OpenOptions::new()
+                        .create(true)
+                        .append(true)
+                        .open(path)
+                        .and_then(|mut file| writeln!(file, "{json}"));
+
+                    if let Err(err) = written {
+                        eprintln!("Failed to write output to {path}: {err}");
+                    }
+                }
+                Err(err) => eprintln!("Failed to serialize to JSON: {err}"),
+            }
+        }
+
+        // The file is in addition to the terminal output, as the help
+        // promises, so the terminal renders either way.
         match self.format {
-            Format::Json => match serde_json::to_string(&value) {
+            Format::Json => match json {
                 Ok(json) => println!("{json}"),
                 Err(err) => eprintln!("Failed to serialize to JSON: {err}"),
             },
@@ -36,6 +119,48 @@ impl Output {
     }
 }
 
+/// A single-line `done/total` counter for batch operations, drawn on
+/// stderr so it never mixes with reported output. It renders only when
+/// stderr is a terminal and more than one step is ahead, so piped and
+/// scripted runs stay clean.
+#[derive(Debug)]
+pub struct Progress {
+    label: &'static str,
+    total: usize,
+    done: usize,
+    enabled: bool,
+}
+
+impl Progress {
+    #[must_use]
+    pub fn new(label: &'static str, total: usize, quiet: bool) -> Self {
+        Self {
+            label,
+            total,
+            done: 0,
+            enabled: !quiet && total > 1 && stderr().is_terminal(),
+        }
+    }
+
+    /// Marks one step done and redraws the counter.
+    pub fn tick(&mut self) {
+        self.done += 1;
+
+        if self.enabled {
+            eprint!("\r{} {}/{}", self.label, self.done, self.total);
+
+            let _ignored = stderr().flush();
+        }
+    }
+
+    /// Ends the counter's line, so whatever prints next starts clean.
+    pub fn finish(self) {
+        if self.enabled && self.done > 0 {
+            eprintln!();
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct InfoLine<'a>(pub &'a str);
 
@@ -1,10 +1,11 @@
 use std::sync::LazyLock;
 
 use eyre::Result as EyreResult;
-use reqwest::Client;
 use semver::Version;
 use serde::Deserialize;
 
+use crate::common::client;
+
 pub static CURRENT_VERSION: LazyLock<Version> =
     LazyLock::new(|| Version::parse(env!("CARGO_PKG_VERSION")).expect("Invalid cargo version"));
 
@@ -15,7 +16,7 @@ struct Release {
 
 pub async fn check_for_update() -> EyreResult<()> {
     let url = "https://api.github.com/repos/calimero-network/core/releases/latest";
-    let client = Client::new();
+    let client = client();
 
     let response = client
         .get(url)
@@ -15,6 +15,9 @@ use init::InitCommand;
 use relay::RelayCommand;
 use run::RunCommand;
 
+/// PID file a running node maintains in its home directory.
+pub const PID_FILE: &str = "merod.pid";
+
 pub const EXAMPLES: &str = r"
   # Initialize node
   $ merod --node-name node1 init --server-port 2428 --swarm-port 2528
@@ -1,96 +1,3508 @@
 #![allow(unused_results, reason = "Occurs in macro")]
 
-use std::env::temp_dir;
+use core::time::Duration;
+use std::env::var;
+use std::fs::{remove_file, OpenOptions};
+use std::io::{stdin, stdout, ErrorKind, Read as _, Write as _};
+use std::process::{exit, id, Command};
 use std::str::FromStr;
 
-use calimero_config::{ConfigFile, CONFIG_FILE};
-use camino::Utf8PathBuf;
-use clap::Parser;
+use calimero_config::{
+    BlobStoreConfig, ConfigFile, DataStoreConfig, NetworkConfig, ServerConfig, SyncConfig,
+    CONFIG_FILE,
+};
+use calimero_context::config::ContextConfig;
+use calimero_network::config::{
+    AutonatConfig, BootstrapConfig, BootstrapNodes, DiscoveryConfig, RelayConfig, RendezvousConfig,
+    SwarmConfig,
+};
+use calimero_server::admin::service::AdminConfig;
+use calimero_server::jsonrpc::JsonRpcConfig;
+use calimero_server::ws::WsConfig;
+use camino::{Utf8Path, Utf8PathBuf};
+use chrono::{DateTime, Utc};
+use clap::{Parser, Subcommand, ValueEnum};
 use eyre::{bail, eyre, Result as EyreResult};
-use tokio::fs::{read_to_string, write};
-use toml_edit::{Item, Value};
-use tracing::info;
+use nix::sys::signal::{kill, Signal};
+use nix::unistd::Pid;
+use notify::event::ModifyKind;
+use notify::{EventKind, RecursiveMode, Watcher};
+use serde::{Deserialize, Serialize};
+use similar::{ChangeTag, TextDiff};
+use tokio::fs::{metadata, read_to_string, write};
+use tokio::runtime::Handle;
+use tokio::sync::mpsc;
+use toml_edit::{Item, Table, Value};
+use tracing::{info, warn};
 
 use crate::cli;
+use crate::cli::init::default_client_config;
+use crate::cli::PID_FILE;
+use crate::defaults;
+
+pub mod journal;
+pub mod schema;
+
+use journal::JournalEntry;
+use schema::{SchemaNode, SchemaType, CONFIG_SCHEMA};
 
 /// Configure the node
 #[derive(Debug, Parser)]
+#[clap(args_conflicts_with_subcommands = true)]
 pub struct ConfigCommand {
-    /// Key-value pairs to be added or updated in the TOML file
+    #[command(subcommand)]
+    subcommand: Option<ConfigSubcommand>,
+
+    /// Key-value pairs to be added or updated in the TOML file, or `<KEY>?`
+    /// to describe a key; `<KEY>+=<VALUE>` appends to an array,
+    /// `<KEY>-=<VALUE>` removes a matching element, and `<KEY>:<TYPE>=<VALUE>`
+    /// forces the TOML type instead of inferring it
     #[clap(value_name = "ARGS")]
-    args: Vec<KeyValuePair>,
+    args: Vec<ConfigArg>,
+
+    /// Print all settable keys from the schema, one per line, and exit.
+    /// Consumed by shell completion scripts.
+    #[clap(long, hide = true, exclusive = true)]
+    complete_keys: bool,
+
+    /// Verify the built-in schema for definition bugs and exit; nonzero
+    /// when problems are found
+    #[clap(long, exclusive = true)]
+    self_check: bool,
+
+    /// Print the current config instead of editing it; bare keys scope
+    /// the output to those subtrees
+    #[clap(long, value_name = "FORMAT")]
+    print: Option<PrintFormat>,
+
+    /// Sort keys lexicographically within each table when printing, for
+    /// stable diffs; the on-disk file keeps its order
+    #[clap(long, requires = "print")]
+    sort: bool,
+
+    /// Print the config the node would actually load, with defaulted
+    /// keys filled in, instead of only what the file contains
+    #[clap(long, requires = "print")]
+    effective: bool,
+
+    /// Print secret values as-is instead of masking them; printed output
+    /// is otherwise safe to paste into bug reports
+    #[clap(long, requires = "print")]
+    show_secrets: bool,
+
+    /// Print hints as JSON, with descriptions emitted as `$comment` fields
+    #[clap(long, conflicts_with = "format")]
+    json: bool,
+
+    /// Rendering for hint output: the default human tree, JSON, or CSV
+    /// rows of `key,type,required,description`
+    #[clap(long, value_name = "FORMAT")]
+    format: Option<HintFormat>,
+
+    /// Schema levels a hint expands below the named key; 0 expands the
+    /// whole subtree
+    #[clap(long, value_name = "N", default_value_t = 0)]
+    depth: usize,
+
+    /// Operate on this TOML file instead of the node's config
+    #[clap(long, value_name = "PATH")]
+    file: Option<Utf8PathBuf>,
+
+    /// Layer `config.<PROFILE>.toml` from the same directory over the
+    /// base config before printing or editing
+    #[clap(long, value_name = "PROFILE")]
+    profile: Option<String>,
+
+    /// Re-validate the config whenever it changes on disk, printing the
+    /// verdict each time; never writes
+    #[clap(long)]
+    watch: bool,
+
+    /// Overwrite the config even if it changed on disk while editing
+    #[clap(long)]
+    force: bool,
+
+    /// Skip the confirmation summary when changing several keys at once
+    #[clap(long, short = 'y')]
+    yes: bool,
+
+    /// Show the whole file in the pre-save diff instead of changed hunks
+    #[clap(long)]
+    full_diff: bool,
+
+    /// Report which edits change the loaded config semantically, then
+    /// exit without saving
+    #[clap(long)]
+    semantic_diff: bool,
+
+    /// Apply edits one at a time, validating after each; the first edit
+    /// that breaks the config is named and dropped along with the rest
+    /// of the batch, keeping the edits before it
+    #[clap(long)]
+    stepwise: bool,
+
+    /// Validate after every edit and roll the whole batch back on the
+    /// first failure, reporting what each edit would have changed; either
+    /// all edits land or none do
+    #[clap(long, conflicts_with_all = ["stepwise", "allow_partial"])]
+    transactional: bool,
+
+    /// Validate after every edit, skip the ones that break the config,
+    /// and commit the rest; the skipped edits still fail the command
+    #[clap(long, conflicts_with = "stepwise")]
+    allow_partial: bool,
+
+    /// Move the value at OLD to NEW and delete OLD, for migrating across
+    /// key renames; NEW must be unset unless --force is given
+    #[clap(long, value_name = "OLD=NEW")]
+    rename: Vec<RenameArg>,
+
+    /// Restore the config from a backup - the most recent one, or the
+    /// named backup file; shows the diff and asks before writing, and
+    /// the restore is itself backed up so rollbacks are reversible
+    #[clap(long, value_name = "BACKUP", num_args = 0..=1)]
+    rollback: Option<Option<String>>,
+
+    /// Remove this leaf key from the file so it falls back to its
+    /// default; removing an absent key is a no-op
+    #[clap(long, value_name = "KEY")]
+    unset: Vec<String>,
+
+    /// Remove this whole section and everything under it; sections the
+    /// schema marks as holding required keys need --force
+    #[clap(long, value_name = "PATH")]
+    unset_section: Vec<String>,
+
+    /// Apply an RFC 6902 JSON Patch file (add/remove/replace operations
+    /// with JSON pointer paths) to the config, for standard
+    /// config-management tooling
+    #[clap(long, value_name = "FILE")]
+    json_patch: Option<Utf8PathBuf>,
+
+    /// Deep-merge a partial TOML or JSON document from this file into
+    /// the config, touching only the keys it names; `-` reads standard
+    /// input. Inline KEY=VALUE args apply afterwards, so they win on
+    /// conflict
+    #[clap(long, value_name = "FILE")]
+    from_file: Option<Utf8PathBuf>,
+
+    /// Write the pending changes as structured JSON - dotted key, old
+    /// and new value, set/unset operation - to this file instead of the
+    /// rendered diff; `-` writes to stdout, and no changes yields an
+    /// empty array
+    #[clap(long, value_name = "FILE")]
+    output_diff: Option<Utf8PathBuf>,
+
+    /// Report the pending changes without writing the config, failing
+    /// when any change is detected, so CI can gate on config drift
+    #[clap(long)]
+    check: bool,
+
+    /// Apply each edit only where the key is currently absent, so
+    /// provisioning scripts fill in defaults without overriding an
+    /// operator's customizations
+    #[clap(long)]
+    if_unset: bool,
+
+    /// Print nothing and exit 0 when the edits change nothing; exit 2
+    /// after saving when they do. For idempotency-checking scripts.
+    #[clap(long)]
+    quiet_if_noop: bool,
+
+    /// Don't warn about top-level sections the schema has no entry for
+    #[clap(long)]
+    no_warn_unknown: bool,
+
+    /// After saving, send SIGHUP to the running node (found via its PID
+    /// file) so it picks up hot-reloadable keys without a restart; under
+    /// systemd, `systemctl reload` does the same
+    #[clap(long)]
+    reload: bool,
+}
+
+/// Unchanged lines shown around each changed hunk in the pre-save diff.
+const DIFF_CONTEXT: usize = 2;
+
+/// Advisory lock file guarding the read-modify-validate-write cycle
+/// against concurrent invocations.
+const CONFIG_LOCK_FILE: &str = ".config.lock";
+
+/// How many timestamped config backups to keep around for `--rollback`.
+const BACKUP_KEEP: usize = 10;
+
+#[derive(Debug, Subcommand)]
+enum ConfigSubcommand {
+    /// Open the config in $EDITOR and validate it on exit
+    Edit,
+    /// Write a fresh default config where none exists yet
+    Init {
+        /// Walk the schema prompting for every documented key, with the
+        /// default pre-filled; optional sections can be skipped
+        #[clap(long)]
+        interactive: bool,
+    },
+    /// Print one key's value bare, for use in scripts and pipelines
+    Get {
+        /// Dotted path of the key to read
+        key: String,
+
+        /// Fail unless the value is of this type, so a pipeline can rely
+        /// on what it reads; without it the schema's type is checked
+        #[clap(long = "as", value_name = "TYPE")]
+        ty: Option<GetType>,
+    },
+    /// Show the config change journal, oldest edit first
+    History {
+        /// Only show edits at or after this moment (RFC 3339, e.g.
+        /// `2024-05-01T00:00:00Z`)
+        #[clap(long, value_name = "DATETIME")]
+        since: Option<DateTime<Utc>>,
+
+        /// Only show edits at or before this moment (RFC 3339)
+        #[clap(long, value_name = "DATETIME")]
+        until: Option<DateTime<Utc>>,
+    },
+    /// Rewrite the config in canonical form: keys sorted within each
+    /// table, standard spacing, comments kept
+    Fmt {
+        /// Write the canonical form back instead of showing the diff
+        #[clap(long)]
+        save: bool,
+
+        /// Print nothing; exit non-zero when the file isn't already
+        /// canonical, for CI
+        #[clap(long, conflicts_with = "save")]
+        check: bool,
+    },
+    /// List every settable key as a flat dotted path, one per line
+    Keys {
+        /// Append the type each key expects, tab-separated
+        #[clap(long)]
+        with_types: bool,
+    },
+    /// Dump the config schema as JSON, for external tooling
+    Schema,
+    /// Diff the config against what `merod init` writes by default
+    DiffDefaults,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum HintFormat {
+    /// The indented human-readable tree
+    Table,
+    /// JSON with descriptions as `$comment` fields
+    Json,
+    /// `key,type,required,description` rows, one per leaf
+    Csv,
+}
+
+/// Types `config get --as` can assert.
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum GetType {
+    Bool,
+    U64,
+    I64,
+    F64,
+    String,
+    Datetime,
+    Array,
+}
+
+impl GetType {
+    /// Whether `value` is of this type.
+    fn matches(self, value: &Value) -> bool {
+        match self {
+            Self::Bool => value.is_bool(),
+            Self::U64 => value.as_integer().is_some_and(|value| value >= 0),
+            Self::I64 => value.is_integer(),
+            Self::F64 => value.is_float() || value.is_integer(),
+            Self::String => value.is_str(),
+            Self::Datetime => value.is_datetime(),
+            Self::Array => value.is_array(),
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Bool => "bool",
+            Self::U64 => "u64",
+            Self::I64 => "i64",
+            Self::F64 => "f64",
+            Self::String => "string",
+            Self::Datetime => "datetime",
+            Self::Array => "array",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum PrintFormat {
+    /// Indented `key = value` lines, one per leaf
+    Human,
+    /// The subtree as TOML, as it appears on disk
+    Toml,
+    /// `SECTION_SUBKEY=value` lines, for seeding an environment
+    Env,
+    /// A one-line overview of the schema-flagged key values, for logs
+    /// and health output
+    Summary,
+}
+
+#[derive(Clone, Debug)]
+enum ConfigArg {
+    /// `<KEY>?` - describe the key from the schema.
+    Hint(String),
+    /// `<KEY>??` - print only the key's one-line description, for
+    /// tooltips and scripts.
+    Describe(String),
+    /// `<KEY>=<VALUE>` - set the key.
+    Set(KeyValuePair),
+    /// `<KEY>` - scope `--print` output to this subtree.
+    Key(String),
 }
 
 #[derive(Clone, Debug)]
 struct KeyValuePair {
     key: String,
     value: Value,
+    op: EditOp,
+    /// A trailing `# comment` from the argument, written into the file as
+    /// decor on the value so the reason for a change survives next to it.
+    comment: Option<String>,
+}
+
+/// How an edit combines with the value already in the slot.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum EditOp {
+    /// `=` - replace the slot wholesale.
+    Set,
+    /// `+=` - push an element onto an array.
+    Append,
+    /// `-=` - remove matching elements from an array.
+    Remove,
+}
+
+impl FromStr for ConfigArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(key) = s.strip_suffix("??") {
+            if assignment_index(key).is_some() {
+                return Err("Hints take a key, not an assignment".to_owned());
+            }
+
+            return Ok(Self::Describe(key.to_owned()));
+        }
+
+        if let Some(key) = s.strip_suffix('?') {
+            if assignment_index(key).is_some() {
+                return Err("Hints take a key, not an assignment".to_owned());
+            }
+
+            return Ok(Self::Hint(key.to_owned()));
+        }
+
+        if assignment_index(s).is_none() {
+            return Ok(Self::Key(s.to_owned()));
+        }
+
+        s.parse().map(Self::Set)
+    }
 }
 
 impl FromStr for KeyValuePair {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parts = s.splitn(2, '=');
-        let key = parts.next().ok_or("Missing key")?.to_owned();
+        // Split on the assignment `=`, not one inside a `[field=value]`
+        // filter.
+        let split = assignment_index(s).ok_or("Missing value")?;
+        let (key, value) = (&s[..split], &s[split + 1..]);
+
+        let (key, op) = if let Some(key) = key.strip_suffix('+') {
+            (key, EditOp::Append)
+        } else if let Some(key) = key.strip_suffix('-') {
+            (key, EditOp::Remove)
+        } else {
+            (key, EditOp::Set)
+        };
+
+        let (key, tag) = split_type_tag(key);
+
+        let (value, comment) = split_comment(value);
+
+        let value = match tag {
+            Some(tag) => coerce_tagged(tag, value)?,
+            None => Value::from_str(value).map_err(|e| e.to_string())?,
+        };
+
+        Ok(Self {
+            key: key.to_owned(),
+            value,
+            op,
+            comment,
+        })
+    }
+}
+
+/// One RFC 6902 operation. Only `add`, `remove` and `replace` are
+/// supported - the subset config-management tooling emits for scalar
+/// documents; `move`, `copy` and `test` are rejected at parse time.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+enum PatchOp {
+    Add {
+        path: String,
+        value: serde_json::Value,
+    },
+    Remove {
+        path: String,
+    },
+    Replace {
+        path: String,
+        value: serde_json::Value,
+    },
+}
+
+/// One pending change in the `--output-diff` report, borrowed from the
+/// journal entry the edit-tracking loop already produced.
+#[derive(Debug, Serialize)]
+struct DiffEntry<'a> {
+    key: &'a str,
+    old: Option<&'a str>,
+    new: &'a str,
+    op: &'static str,
+}
+
+impl<'a> From<&'a JournalEntry> for DiffEntry<'a> {
+    fn from(entry: &'a JournalEntry) -> Self {
+        // Removals journal with these markers instead of a value.
+        let op = if matches!(entry.new.as_str(), "(removed)" | "(section removed)") {
+            "unset"
+        } else {
+            "set"
+        };
+
+        Self {
+            key: &entry.key,
+            old: entry.old.as_deref(),
+            new: &entry.new,
+            op,
+        }
+    }
+}
+
+/// An `OLD=NEW` key rename.
+#[derive(Clone, Debug)]
+struct RenameArg {
+    old: String,
+    new: String,
+}
+
+impl FromStr for RenameArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (old, new) = s
+            .split_once('=')
+            .ok_or_else(|| "renames take the form OLD=NEW".to_owned())?;
+
+        if old.is_empty() || new.is_empty() {
+            return Err("renames take the form OLD=NEW".to_owned());
+        }
+
+        Ok(Self {
+            old: old.to_owned(),
+            new: new.to_owned(),
+        })
+    }
+}
+
+/// Holds [`CONFIG_LOCK_FILE`] in the node directory for the lifetime of
+/// one config operation; the file is removed again on drop.
+#[derive(Debug)]
+struct ConfigLock {
+    path: Utf8PathBuf,
+}
+
+impl ConfigLock {
+    /// Takes the advisory lock, failing fast when another invocation
+    /// holds it. The file records the holder's PID for diagnosis.
+    fn acquire(dir: &Utf8Path) -> EyreResult<Self> {
+        let path = dir.join(CONFIG_LOCK_FILE);
+
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(mut file) => {
+                let _ignored = writeln!(file, "{}", id());
+
+                Ok(Self { path })
+            }
+            Err(err) if err.kind() == ErrorKind::AlreadyExists => bail!(
+                "another config operation is in progress (lock {:?}); remove the file if it is stale",
+                path
+            ),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+impl Drop for ConfigLock {
+    fn drop(&mut self) {
+        let _ignored = remove_file(&self.path);
+    }
+}
+
+/// What a batch of edits does when one of them breaks validation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ApplyMode {
+    /// Apply everything and validate once at the end.
+    Batch,
+    /// Validate after each edit; the first failure keeps the good prefix
+    /// and drops the rest.
+    Stepwise,
+    /// Validate after each edit; any failure rolls the whole batch back.
+    Transactional,
+    /// Validate after each edit; failing edits are skipped, the rest land.
+    Partial,
+}
+
+/// What a batch of edits did to the document.
+#[derive(Debug)]
+struct ApplyOutcome {
+    /// Journal entries, one per applied edit.
+    entries: Vec<JournalEntry>,
+    /// Whether any edit changed a value, as opposed to rewriting it
+    /// verbatim.
+    changed: bool,
+    /// Why the batch was cut short, when stepwise or partial mode dropped
+    /// an edit; reported after the surviving edits are saved.
+    stepwise_failure: Option<String>,
+}
+
+/// Byte index of the first `=` outside a `[field=value]` filter - the
+/// assignment operator, as opposed to an `=` inside a selector.
+fn assignment_index(s: &str) -> Option<usize> {
+    let mut depth = 0_usize;
+
+    for (index, c) in s.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth = depth.saturating_sub(1),
+            '=' if depth == 0 => return Some(index),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Splits an optional `:type` tag off a key, as in `key:bool`. Only the
+/// recognized tag names count, so a stray `:` elsewhere in a key is left
+/// alone.
+fn split_type_tag(key: &str) -> (&str, Option<&str>) {
+    if let Some((key, tag)) = key.rsplit_once(':') {
+        if matches!(tag, "bool" | "int" | "float" | "string" | "datetime") {
+            return (key, Some(tag));
+        }
+    }
+
+    (key, None)
+}
+
+/// Parses `raw` as the explicitly requested TOML type instead of letting
+/// inference guess, so `key:string=5000` stays the string "5000" and
+/// `key:bool=true` refuses anything that isn't a boolean.
+fn coerce_tagged(tag: &str, raw: &str) -> Result<Value, String> {
+    let trimmed = raw.trim();
+
+    // Quoted input is accepted for every tag, so `key:bool='true'` works.
+    let unquoted = if trimmed.len() >= 2
+        && (trimmed.starts_with('"') && trimmed.ends_with('"')
+            || trimmed.starts_with('\'') && trimmed.ends_with('\''))
+    {
+        &trimmed[1..trimmed.len() - 1]
+    } else {
+        trimmed
+    };
+
+    match tag {
+        "bool" => unquoted
+            .parse::<bool>()
+            .map(Value::from)
+            .map_err(|_| format!("`{trimmed}` is not a bool")),
+        "int" => unquoted
+            .parse::<i64>()
+            .map(Value::from)
+            .map_err(|_| format!("`{trimmed}` is not an integer")),
+        "float" => unquoted
+            .parse::<f64>()
+            .map(Value::from)
+            .map_err(|_| format!("`{trimmed}` is not a float")),
+        "string" => Ok(Value::from(unquoted)),
+        "datetime" => unquoted
+            .parse::<toml_edit::Datetime>()
+            .map(Value::from)
+            .map_err(|_| format!("`{trimmed}` is not an RFC 3339 datetime")),
+        _ => Err(format!(
+            "unknown type tag `{tag}`; expected bool, int, float, string or datetime"
+        )),
+    }
+}
 
-        let value = parts.next().ok_or("Missing value")?;
-        let value = Value::from_str(value).map_err(|e| e.to_string())?;
+/// Splits an optional trailing `# comment` off a raw value, ignoring `#`
+/// inside quoted strings.
+fn split_comment(s: &str) -> (&str, Option<String>) {
+    let mut in_basic = false;
+    let mut in_literal = false;
+    let mut escaped = false;
 
-        Ok(Self { key, value })
+    for (index, c) in s.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+
+        match c {
+            '\\' if in_basic => escaped = true,
+            '"' if !in_literal => in_basic = !in_basic,
+            '\'' if !in_basic => in_literal = !in_literal,
+            '#' if !in_basic && !in_literal => {
+                let comment = s[index + 1..].trim();
+
+                return (&s[..index], (!comment.is_empty()).then(|| comment.to_owned()));
+            }
+            _ => {}
+        }
     }
+
+    (s, None)
+}
+
+/// The comment lines of a decor prefix, re-anchored at column zero, one
+/// trailing newline each. Blank lines and indentation are dropped.
+fn comment_lines(prefix: Option<&toml_edit::RawString>) -> String {
+    prefix
+        .and_then(toml_edit::RawString::as_str)
+        .unwrap_or("")
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with('#'))
+        .map(|line| format!("{line}\n"))
+        .collect()
 }
 
 impl ConfigCommand {
     pub async fn run(self, root_args: &cli::RootArgs) -> EyreResult<()> {
-        let path = root_args.home.join(&root_args.node_name);
+        if self.complete_keys {
+            for key in CONFIG_SCHEMA.flat_keys() {
+                println!("{key}");
+            }
+
+            return Ok(());
+        }
+
+        if self.self_check {
+            let problems = CONFIG_SCHEMA.self_check();
+
+            for problem in &problems {
+                println!("{problem}");
+            }
+
+            if !problems.is_empty() {
+                bail!("schema self-check found {} problem(s)", problems.len());
+            }
+
+            println!("schema OK: {} settable keys", CONFIG_SCHEMA.flat_keys().len());
+
+            return Ok(());
+        }
+
+        // The schema is static; it doesn't need an initialized node.
+        if let Some(ConfigSubcommand::Schema) = self.subcommand {
+            println!("{}", serde_json::to_string_pretty(&CONFIG_SCHEMA.to_json())?);
+
+            return Ok(());
+        }
+
+        if let Some(ConfigSubcommand::Keys { with_types }) = self.subcommand {
+            for (key, ty) in CONFIG_SCHEMA.flat_leaves() {
+                if with_types {
+                    println!("{key}\t{ty}");
+                } else {
+                    println!("{key}");
+                }
+            }
 
-        if !ConfigFile::exists(&path) {
-            bail!("Node is not initialized in {:?}", path);
+            return Ok(());
         }
 
-        let path = path.join(CONFIG_FILE);
+        // Init writes a fresh config, so it runs before the checks that
+        // expect one to exist.
+        if let Some(ConfigSubcommand::Init { interactive }) = self.subcommand {
+            let path = self.file.clone().unwrap_or_else(|| {
+                root_args
+                    .home
+                    .join(&root_args.node_name)
+                    .join(CONFIG_FILE)
+            });
+
+            return Self::init(&path, interactive).await;
+        }
+
+        // `--file` points the command at any TOML file - a template, a
+        // staged copy - sidestepping the node-directory convention. The
+        // file still has to load as a [`ConfigFile`] to be saved.
+        let (dir, path) = if let Some(file) = &self.file {
+            let dir = file
+                .parent()
+                .map_or_else(Utf8PathBuf::new, Utf8Path::to_path_buf);
+
+            if !file.is_file() {
+                bail!("{:?} does not exist", file);
+            }
+
+            (dir, file.clone())
+        } else {
+            let dir = root_args.home.join(&root_args.node_name);
+
+            if !ConfigFile::exists(&dir) {
+                bail!("Node is not initialized in {:?}", dir);
+            }
+
+            let path = dir.join(CONFIG_FILE);
+
+            (dir, path)
+        };
+
+        match self.subcommand {
+            Some(ConfigSubcommand::Edit) => return self.edit(&path).await,
+            Some(ConfigSubcommand::History { since, until }) => {
+                return Self::history(&dir, since, until).await
+            }
+            Some(ConfigSubcommand::DiffDefaults) => return Self::diff_defaults(&path).await,
+            Some(ConfigSubcommand::Fmt { save, check }) => {
+                return Self::fmt(&path, save, check).await
+            }
+            // Schema, Keys and Init returned above; Get runs below, once
+            // the document (and any profile overlay) is loaded.
+            Some(
+                ConfigSubcommand::Schema
+                | ConfigSubcommand::Keys { .. }
+                | ConfigSubcommand::Init { .. }
+                | ConfigSubcommand::Get { .. },
+            )
+            | None => {}
+        }
+
+        if self.watch {
+            return self.watch(&path).await;
+        }
+
+        // One config operation at a time; concurrent automation fails
+        // fast instead of interleaving reads and writes.
+        let lock = ConfigLock::acquire(&dir)?;
+
+        if let Some(backup) = &self.rollback {
+            let result = self.rollback_config(&dir, &path, backup.as_deref()).await;
+
+            drop(lock);
+
+            return result;
+        }
 
         // Load the existing TOML file
         let toml_str = read_to_string(&path)
             .await
             .map_err(|_| eyre!("Node is not initialized in {:?}", path))?;
 
+        let read_at = metadata(&path).await?.modified()?;
+
         let mut doc = toml_str.parse::<toml_edit::DocumentMut>()?;
 
-        // Update the TOML document
-        for kv in self.args.iter() {
-            let key_parts: Vec<&str> = kv.key.split('.').collect();
+        // Profile overlays layer environment-specific overrides over the
+        // base, twelve-factor style: config.prod.toml on top of config.toml.
+        if let Some(profile) = &self.profile {
+            let overlay_path = dir.join(format!("config.{profile}.toml"));
+
+            let overlay = read_to_string(&overlay_path)
+                .await
+                .map_err(|_| eyre!("profile overlay {overlay_path:?} not found"))?;
+
+            let mut overlay = overlay.parse::<toml_edit::DocumentMut>()?;
 
-            let mut current = doc.as_item_mut();
+            Self::merge_item(doc.as_item_mut(), std::mem::take(overlay.as_item_mut()));
+        }
+
+        // Sections the schema doesn't know are skipped silently
+        // everywhere else; leftovers from removed features and
+        // section-level typos should be named once per run.
+        if !self.no_warn_unknown {
+            let unknown = Self::unknown_sections(&doc);
 
-            for key in &key_parts[..key_parts.len() - 1] {
-                current = &mut current[key];
+            if !unknown.is_empty() {
+                warn!(
+                    "config has top-level sections the schema doesn't know: {}",
+                    unknown.join(", ")
+                );
             }
+        }
+
+        if let Some(ConfigSubcommand::Get { key, ty }) = &self.subcommand {
+            println!("{}", Self::get_value(&doc, key, *ty)?);
 
-            current[key_parts[key_parts.len() - 1]] = Item::Value(kv.value.clone());
+            return Ok(());
         }
 
-        self.validate_toml(&doc).await?;
+        if let Some(format) = self.print {
+            let filters: Vec<&str> = self
+                .args
+                .iter()
+                .filter_map(|arg| match arg {
+                    ConfigArg::Key(key) => Some(key.as_str()),
+                    _ => None,
+                })
+                .collect();
 
-        // Save the updated TOML back to the file
-        write(&path, doc.to_string()).await?;
+            // The config equivalent of "show computed styles": round-trip
+            // through [`ConfigFile`] so serde fills in every defaulted
+            // key, then render like any other document.
+            let mut doc = if self.effective {
+                toml::to_string_pretty(&Self::load_snapshot(&doc.to_string())?)?
+                    .parse::<toml_edit::DocumentMut>()?
+            } else {
+                doc
+            };
 
-        info!("Node configuration has been updated");
+            if !self.show_secrets {
+                Self::mask_secrets("", doc.as_item_mut());
+            }
 
-        Ok(())
-    }
+            return Self::print_config(&doc, format, &filters, self.sort);
+        }
 
-    pub async fn validate_toml(self, doc: &toml_edit::DocumentMut) -> EyreResult<()> {
-        let tmp_dir = temp_dir();
-        let tmp_path = tmp_dir.join(CONFIG_FILE);
+        let mut hinted = false;
 
-        write(&tmp_path, doc.to_string()).await?;
+        let mut pending = Vec::new();
 
-        let tmp_path_utf8 = Utf8PathBuf::try_from(tmp_dir)?;
+        // Hints print immediately; assignments are collected and applied
+        // as one batch below.
+        for arg in &self.args {
+            match arg {
+                ConfigArg::Hint(key) => {
+                    let format = if self.json {
+                        HintFormat::Json
+                    } else {
+                        self.format.unwrap_or(HintFormat::Table)
+                    };
 
-        drop(ConfigFile::load(&tmp_path_utf8).await?);
+                    match (CONFIG_SCHEMA.lookup(key), format) {
+                        (Some(node), HintFormat::Table) => {
+                            node.print_human_depth(key, 0, (self.depth > 0).then_some(self.depth))
+                        }
+                        (Some(node), HintFormat::Json) => {
+                            println!("{}", serde_json::to_string_pretty(&node.to_json())?)
+                        }
+                        (Some(node), HintFormat::Csv) => {
+                            for row in node.to_csv(key) {
+                                println!("{row}");
+                            }
+                        }
+                        (None, _) => println!("{key}: no schema found"),
+                    }
 
-        Ok(())
+                    hinted = true;
+                }
+                ConfigArg::Describe(key) => {
+                    match CONFIG_SCHEMA.lookup(key) {
+                        Some(node) => println!("{}", node.description()),
+                        None => println!("{key}: no schema found"),
+                    }
+
+                    hinted = true;
+                }
+                ConfigArg::Set(kv) => pending.push(kv.clone()),
+                ConfigArg::Key(key) => {
+                    bail!("bare key `{key}` needs `--print`, or `{key}?` for a hint")
+                }
+            }
+        }
+
+        // Keys that already hold a value are left alone under --if-unset;
+        // the skip is announced so provisioning logs show what happened.
+        if self.if_unset {
+            pending.retain(|kv| {
+                let set = kv
+                    .key
+                    .split('.')
+                    .try_fold(doc.as_item(), |item, part| item.get(part))
+                    .is_some();
+
+                if set {
+                    println!("`{}` is already set; skipped (--if-unset)", kv.key);
+                }
+
+                !set
+            });
+        }
+
+        // Renames go first, so a rename and an edit of the new key can
+        // travel in one invocation.
+        let mut edits = Vec::new();
+
+        // A partial document can layer in whole from a file, or stdin
+        // as `-`; it lands before the inline KEY=VALUE args, so on
+        // conflict the command line deterministically wins.
+        if let Some(overlay_path) = &self.from_file {
+            let raw = if overlay_path.as_str() == "-" {
+                let mut raw = String::new();
+
+                let _ = stdin().read_to_string(&mut raw)?;
+
+                raw
+            } else {
+                read_to_string(overlay_path)
+                    .await
+                    .map_err(|_| eyre!("unable to read the overlay from {overlay_path:?}"))?
+            };
+
+            let overlay = Self::parse_overlay(&raw, overlay_path)?;
+
+            let mut merges = Vec::new();
+
+            Self::overlay_edits("", overlay.as_item(), &mut merges)?;
+
+            for kv in &merges {
+                edits.push(Self::apply_edit(&mut doc, kv)?);
+            }
+        }
+
+        if let Some(patch_path) = &self.json_patch {
+            let raw = read_to_string(patch_path)
+                .await
+                .map_err(|_| eyre!("unable to read the patch from {patch_path:?}"))?;
+
+            let ops: Vec<PatchOp> = serde_json::from_str(&raw)
+                .map_err(|err| eyre!("{patch_path:?} is not an RFC 6902 patch: {err}"))?;
+
+            for op in &ops {
+                edits.push(Self::apply_patch_op(&mut doc, op)?);
+            }
+        }
+
+        for rename in &self.rename {
+            edits.extend(Self::apply_rename(&mut doc, rename, self.force)?);
+        }
+
+        for key in &self.unset {
+            match Self::apply_unset_key(&mut doc, key)? {
+                Some(entry) => edits.push(entry),
+                None => println!("`{key}` is not set; no changes were made"),
+            }
+        }
+
+        for path in &self.unset_section {
+            edits.push(Self::apply_unset_section(&mut doc, path, self.force)?);
+        }
+
+        let mode = if self.transactional {
+            ApplyMode::Transactional
+        } else if self.allow_partial {
+            ApplyMode::Partial
+        } else if self.stepwise {
+            ApplyMode::Stepwise
+        } else {
+            ApplyMode::Batch
+        };
+
+        let outcome = Self::apply_edits(&mut doc, &pending, mode)?;
+
+        // Renames, section removals and patch ops always count as changes.
+        let changed = outcome.changed || !edits.is_empty();
+
+        edits.extend(outcome.entries);
+
+        let stepwise_failure = outcome.stepwise_failure;
+
+        if hinted {
+            return Ok(());
+        }
+
+        if self.quiet_if_noop && !changed {
+            return Ok(());
+        }
+
+        // Point at required keys the file still lacks, so a failing
+        // validation comes with the commands that fix it.
+        for (key, ty) in CONFIG_SCHEMA.missing_required(&doc) {
+            println!("missing required key `{key}` ({ty}) - set it with `merod config {key}=<value>`");
+        }
+
+        Self::validate_toml_attributed(&doc, &edits)?;
+
+        // Diff what the node would actually load, not the text: quoting
+        // or formatting changes drop out, defaulted keys show up.
+        if self.semantic_diff {
+            let old = serde_json::to_value(&Self::load_snapshot(&toml_str)?)?;
+            let new = serde_json::to_value(&Self::load_snapshot(&doc.to_string())?)?;
+
+            let mut changes = Vec::new();
+
+            Self::semantic_changes("", &old, &new, &mut changes);
+
+            if changes.is_empty() {
+                println!("no semantic changes");
+            }
+
+            for change in changes {
+                println!("{change}");
+            }
+
+            return Ok(());
+        }
+
+        // CI wants the change set as data it can assert on, not a
+        // rendered diff; the journal entries already carry exactly that,
+        // so the report is valid JSON - an empty array - even when
+        // there is nothing to do.
+        if self.output_diff.is_some() || self.check {
+            let entries: Vec<DiffEntry<'_>> = edits.iter().map(DiffEntry::from).collect();
+
+            let report = serde_json::to_string_pretty(&entries)?;
+
+            match &self.output_diff {
+                Some(path) if path.as_str() != "-" => write(path, format!("{report}\n")).await?,
+                _ => println!("{report}"),
+            }
+        }
+
+        // Gating mode: report, never write, and signal drift through
+        // the exit code.
+        if self.check {
+            if changed {
+                bail!(
+                    "config drift: {} pending change(s); nothing was written",
+                    edits.len()
+                );
+            }
+
+            return Ok(());
+        }
+
+        if !edits.is_empty() && self.output_diff.is_none() {
+            Self::print_diff(&toml_str, &doc.to_string(), self.full_diff);
+        }
+
+        // A large batch is easy to fat-finger; show what's about to
+        // change and ask once before touching the file.
+        if edits.len() > 1 && !self.yes {
+            println!("About to change {} keys:", edits.len());
+
+            for entry in &edits {
+                println!(
+                    "  {}: {} -> {}",
+                    entry.key,
+                    entry.old.as_deref().unwrap_or("(unset)"),
+                    entry.new
+                );
+            }
+
+            print!("Proceed? [y/N] ");
+
+            stdout().flush()?;
+
+            let mut answer = String::new();
+
+            let _ = stdin().read_line(&mut answer)?;
+
+            if !matches!(answer.trim().to_lowercase().as_str(), "y" | "yes") {
+                bail!("aborted; no changes written");
+            }
+        }
+
+        // Refuse to clobber edits that landed while we held the document.
+        if !self.force && metadata(&path).await?.modified()? != read_at {
+            bail!(
+                "{:?} changed on disk while editing; re-run the command, or pass --force to overwrite",
+                path
+            );
+        }
+
+        // Config changes are not hot-reloaded; flag likely divergence.
+        if !self.reload && dir.join(PID_FILE).exists() {
+            warn!(
+                "The node appears to be running; changes take effect on restart, or re-run with --reload"
+            );
+        }
+
+        // Keep a way back: the old file survives as a timestamped backup
+        // for --rollback, even once the new one is written.
+        Self::backup_config(&dir, &path).await?;
+
+        // Save the updated TOML back to the file
+        write(&path, doc.to_string()).await?;
+
+        journal::append(&dir, &edits).await?;
+
+        info!("Node configuration has been updated");
+
+        if self.reload {
+            Self::reload_node(&dir).await?;
+        }
+
+        // The good prefix is already on disk; the broken edit still has
+        // to fail the command so scripts notice.
+        if let Some(failure) = stepwise_failure {
+            bail!(failure);
+        }
+
+        // A distinct code tells scripts the config was not already in
+        // the requested state. `exit` skips destructors, so release the
+        // lock by hand first.
+        if self.quiet_if_noop {
+            drop(lock);
+
+            exit(2);
+        }
+
+        Ok(())
+    }
+
+    /// Prints a line diff of the pending edit. By default only changed
+    /// hunks appear, with [`DIFF_CONTEXT`] lines around each; `full`
+    /// restores whole-file output.
+    fn print_diff(old: &str, new: &str, full: bool) {
+        let diff = TextDiff::from_lines(old, new);
+
+        let sign = |tag| match tag {
+            ChangeTag::Delete => '-',
+            ChangeTag::Insert => '+',
+            ChangeTag::Equal => ' ',
+        };
+
+        if full {
+            for change in diff.iter_all_changes() {
+                print!("{}{change}", sign(change.tag()));
+            }
+
+            return;
+        }
+
+        for (index, group) in diff.grouped_ops(DIFF_CONTEXT).iter().enumerate() {
+            if index > 0 {
+                println!("...");
+            }
+
+            for op in group {
+                for change in diff.iter_changes(op) {
+                    print!("{}{change}", sign(change.tag()));
+                }
+            }
+        }
+    }
+
+    /// Applies a batch of edits in order, validating between edits as the
+    /// mode demands. Stepwise keeps the good prefix and stops; partial
+    /// skips the broken edits and keeps going; in both the failure lands
+    /// in the outcome rather than as an error, so the surviving edits
+    /// still get saved. Transactional restores the document untouched and
+    /// errors, naming what each edit would have changed.
+    fn apply_edits(
+        doc: &mut toml_edit::DocumentMut,
+        edits: &[KeyValuePair],
+        mode: ApplyMode,
+    ) -> EyreResult<ApplyOutcome> {
+        let mut entries: Vec<JournalEntry> = Vec::new();
+
+        // Pristine copy for transactional rollback, last document that
+        // passed validation for stepwise and partial.
+        let pristine = (mode == ApplyMode::Transactional).then(|| doc.clone());
+        let mut last_good = doc.clone();
+        let mut stepwise_failure = None;
+        let mut skipped = Vec::new();
+
+        for kv in edits {
+            entries.push(Self::apply_edit(doc, kv)?);
+
+            if mode == ApplyMode::Batch {
+                continue;
+            }
+
+            if let Err(err) = Self::validate_toml(doc) {
+                *doc = last_good;
+
+                drop(entries.pop());
+
+                let edit = format!("{}={}", kv.key, kv.value.to_string().trim());
+
+                match mode {
+                    ApplyMode::Batch => unreachable!("batch mode never validates mid-batch"),
+                    ApplyMode::Stepwise => {
+                        stepwise_failure = Some(format!(
+                            "edit `{edit}` breaks the config: {err}; kept the {} edits before it",
+                            entries.len()
+                        ));
+
+                        break;
+                    }
+                    ApplyMode::Transactional => {
+                        *doc = pristine.expect("pristine copy kept for transactional mode");
+
+                        for entry in &entries {
+                            println!(
+                                "would have changed {}: {} -> {}",
+                                entry.key,
+                                entry.old.as_deref().unwrap_or("(unset)"),
+                                entry.new
+                            );
+                        }
+
+                        bail!(
+                            "edit `{edit}` breaks the config: {err}; transaction rolled back, nothing changed"
+                        );
+                    }
+                    ApplyMode::Partial => {
+                        println!("skipping `{edit}`: {err}");
+
+                        skipped.push(edit);
+
+                        continue;
+                    }
+                }
+            }
+
+            last_good = doc.clone();
+        }
+
+        if !skipped.is_empty() {
+            stepwise_failure = Some(format!(
+                "skipped {} of {} edits: {}",
+                skipped.len(),
+                edits.len(),
+                skipped.join(", ")
+            ));
+        }
+
+        let changed = entries
+            .iter()
+            .any(|entry| entry.old.as_deref() != Some(entry.new.as_str()));
+
+        Ok(ApplyOutcome {
+            entries,
+            changed,
+            stepwise_failure,
+        })
+    }
+
+    /// Applies one `<KEY>=<VALUE>` edit to the document: checks the value
+    /// against the schema, walks the dotted path, replaces the slot, and
+    /// returns the journal entry recording the change. Pure document
+    /// manipulation - no I/O - so the edit pipeline is testable on its own.
+    fn apply_edit(doc: &mut toml_edit::DocumentMut, kv: &KeyValuePair) -> EyreResult<JournalEntry> {
+        Self::reject_non_finite(&kv.key, &kv.value)?;
+
+        if let Some(node) = CONFIG_SCHEMA.lookup(&kv.key) {
+            match (node, kv.op) {
+                (SchemaNode::Object { .. }, EditOp::Set) => {
+                    // A whole subtable can be replaced in one go with an
+                    // inline table, e.g. `relay={ enabled = true }`.
+                    if !kv.value.is_inline_table() {
+                        bail!(
+                            "`{}` is a table; assign it an inline table or set its keys individually",
+                            kv.key
+                        )
+                    }
+                }
+                (SchemaNode::Leaf { ty, .. }, EditOp::Set) => {
+                    if !ty.matches(&kv.value) {
+                        bail!("`{}` expects a {}, got `{}`", kv.key, ty, kv.value)
+                    }
+                }
+                (SchemaNode::Leaf { ty: SchemaType::Array, .. }, EditOp::Append | EditOp::Remove) => {}
+                (_, EditOp::Append | EditOp::Remove) => {
+                    bail!("`{}` is not an array; `+=` and `-=` only apply to arrays", kv.key)
+                }
+            }
+        }
+
+        let key_parts: Vec<&str> = kv.key.split('.').collect();
+
+        let mut current = doc.as_item_mut();
+
+        for key in &key_parts[..key_parts.len() - 1] {
+            current = Self::descend(current, key, &kv.key)?;
+        }
+
+        let last = key_parts[key_parts.len() - 1];
+
+        let slot = if last.parse::<usize>().is_ok() || last.contains('[') {
+            Self::descend(current, last, &kv.key)?
+        } else {
+            &mut current[last]
+        };
+
+        let old = match &*slot {
+            Item::None => None,
+            item => Some(item.to_string().trim().to_owned()),
+        };
+
+        match kv.op {
+            EditOp::Set => *slot = Item::Value(kv.value.clone()),
+            EditOp::Append => {
+                // `+=` on an unset key starts a fresh array.
+                if slot.is_none() {
+                    *slot = Item::Value(Value::Array(toml_edit::Array::new()));
+                }
+
+                let Item::Value(Value::Array(array)) = &mut *slot else {
+                    bail!("`{}` is not an array; `+=` only appends to arrays", kv.key)
+                };
+
+                array.push(kv.value.clone());
+            }
+            EditOp::Remove => {
+                let Item::Value(Value::Array(array)) = &mut *slot else {
+                    bail!("`{}` is not an array; `-=` only removes from arrays", kv.key)
+                };
+
+                let wanted = kv.value.to_string().trim().to_owned();
+                let before = array.len();
+
+                array.retain(|item| item.to_string().trim() != wanted);
+
+                if array.len() == before {
+                    bail!("`{}` has no element `{wanted}` to remove", kv.key);
+                }
+            }
+        }
+
+        let new = slot.to_string().trim().to_owned();
+
+        if let (Some(comment), Item::Value(value)) = (&kv.comment, &mut *slot) {
+            value.decor_mut().set_suffix(format!(" # {comment}"));
+        }
+
+        Ok(JournalEntry::new(&kv.key, old, new))
+    }
+
+    /// Moves the value at `OLD` to `NEW` and deletes `OLD`, preserving the
+    /// value across a key rename. The target has to be unset unless
+    /// `force` allows overwriting it. Pure document manipulation, like
+    /// [`Self::apply_edit`]; validation happens on the batch as a whole.
+    fn apply_rename(
+        doc: &mut toml_edit::DocumentMut,
+        rename: &RenameArg,
+        force: bool,
+    ) -> EyreResult<Vec<JournalEntry>> {
+        let existing = rename
+            .new
+            .split('.')
+            .try_fold(doc.as_item(), |item, part| item.get(part))
+            .map(|item| item.to_string().trim().to_owned());
+
+        if existing.is_some() && !force {
+            bail!("`{}` is already set; pass --force to overwrite it", rename.new);
+        }
+
+        // Detach the value from the old key.
+        let old_parts: Vec<&str> = rename.old.split('.').collect();
+
+        let mut current = doc.as_item_mut();
+
+        for key in &old_parts[..old_parts.len() - 1] {
+            current = Self::descend(current, key, &rename.old)?;
+        }
+
+        let Some(parent) = current.as_table_like_mut() else {
+            bail!("`{}` does not name a key in a table", rename.old);
+        };
+
+        let Some(value) = parent.remove(old_parts[old_parts.len() - 1]) else {
+            bail!("`{}` is not set; nothing to rename", rename.old);
+        };
+
+        let value_str = value.to_string().trim().to_owned();
+
+        // Attach it at the new key.
+        if CONFIG_SCHEMA.lookup(&rename.new).is_none() {
+            warn!("`{}` is not a known config key", rename.new);
+        }
+
+        let new_parts: Vec<&str> = rename.new.split('.').collect();
+
+        let mut current = doc.as_item_mut();
+
+        for key in &new_parts[..new_parts.len() - 1] {
+            current = Self::descend(current, key, &rename.new)?;
+        }
+
+        current[new_parts[new_parts.len() - 1]] = value;
+
+        Ok(vec![
+            JournalEntry::new(
+                &rename.old,
+                Some(value_str.clone()),
+                format!("(moved to `{}`)", rename.new),
+            ),
+            JournalEntry::new(&rename.new, existing, value_str),
+        ])
+    }
+
+    /// Applies one RFC 6902 operation to the document. `add` and
+    /// `replace` funnel through [`Self::apply_edit`], so schema type
+    /// checks apply to patches exactly as they do to command-line edits.
+    fn apply_patch_op(doc: &mut toml_edit::DocumentMut, op: &PatchOp) -> EyreResult<JournalEntry> {
+        match op {
+            PatchOp::Add { path, value } | PatchOp::Replace { path, value } => {
+                let key = Self::pointer_to_key(path)?;
+
+                let exists = key
+                    .split('.')
+                    .try_fold(doc.as_item(), |item, part| item.get(part))
+                    .is_some();
+
+                if matches!(op, PatchOp::Replace { .. }) && !exists {
+                    bail!("`replace` target `{key}` is not set; use `add`");
+                }
+
+                Self::apply_edit(
+                    doc,
+                    &KeyValuePair {
+                        key,
+                        value: Self::json_to_toml_value(value)?,
+                        op: EditOp::Set,
+                        comment: None,
+                    },
+                )
+            }
+            PatchOp::Remove { path } => {
+                let key = Self::pointer_to_key(path)?;
+                let parts: Vec<&str> = key.split('.').collect();
+
+                let last = parts[parts.len() - 1];
+
+                if last.parse::<usize>().is_ok() {
+                    bail!("removing array elements by index is not supported; use `{}-=VALUE`",
+                        parts[..parts.len() - 1].join("."));
+                }
+
+                let mut current = doc.as_item_mut();
+
+                for part in &parts[..parts.len() - 1] {
+                    current = Self::descend(current, part, &key)?;
+                }
+
+                let removed = current
+                    .as_table_like_mut()
+                    .and_then(|table| table.remove(last))
+                    .ok_or_else(|| eyre!("`{key}` is not set; nothing to remove"))?;
+
+                Ok(JournalEntry::new(
+                    &key,
+                    Some(removed.to_string().trim().to_owned()),
+                    "(removed)".to_owned(),
+                ))
+            }
+        }
+    }
+
+    /// Translates an RFC 6901 JSON pointer into the dotted key the edit
+    /// machinery speaks.
+    fn pointer_to_key(pointer: &str) -> EyreResult<String> {
+        let Some(rest) = pointer.strip_prefix('/') else {
+            bail!("JSON pointer `{pointer}` must start with `/`");
+        };
+
+        // `~1` escapes `/` and `~0` escapes `~`, in that order.
+        Ok(rest
+            .split('/')
+            .map(|part| part.replace("~1", "/").replace("~0", "~"))
+            .collect::<Vec<_>>()
+            .join("."))
+    }
+
+    /// Converts a JSON value into its TOML equivalent; `null` has no TOML
+    /// counterpart and is rejected.
+    fn json_to_toml_value(value: &serde_json::Value) -> EyreResult<Value> {
+        Ok(match value {
+            serde_json::Value::Null => {
+                bail!("TOML has no null; use a `remove` operation instead")
+            }
+            serde_json::Value::Bool(flag) => Value::from(*flag),
+            serde_json::Value::Number(number) => number.as_i64().map_or_else(
+                || {
+                    number
+                        .as_f64()
+                        .map(Value::from)
+                        .ok_or_else(|| eyre!("`{number}` does not fit a TOML number"))
+                },
+                |integer| Ok(Value::from(integer)),
+            )?,
+            serde_json::Value::String(text) => Value::from(text.clone()),
+            serde_json::Value::Array(items) => {
+                let mut array = toml_edit::Array::new();
+
+                for item in items {
+                    array.push(Self::json_to_toml_value(item)?);
+                }
+
+                Value::Array(array)
+            }
+            serde_json::Value::Object(map) => {
+                let mut table = toml_edit::InlineTable::new();
+
+                for (key, item) in map {
+                    let _ignored = table.insert(key, Self::json_to_toml_value(item)?);
+                }
+
+                Value::InlineTable(table)
+            }
+        })
+    }
+
+    /// Parses `--from-file` input as TOML first and JSON second - the
+    /// two shapes provisioning tooling emits. Text that is neither gets
+    /// both parse failures named, so the right one is easy to spot.
+    fn parse_overlay(raw: &str, source: &Utf8Path) -> EyreResult<toml_edit::DocumentMut> {
+        let toml_err = match raw.parse::<toml_edit::DocumentMut>() {
+            Ok(overlay) => return Ok(overlay),
+            Err(err) => err,
+        };
+
+        let value: serde_json::Value = serde_json::from_str(raw).map_err(|json_err| {
+            eyre!("{source:?} is neither TOML nor JSON - as TOML: {toml_err}; as JSON: {json_err}")
+        })?;
+
+        if !value.is_object() {
+            bail!("{source:?} must hold an object of config keys at the top level");
+        }
+
+        // Round-trip through the TOML serializer so JSON objects become
+        // real tables, which [`Self::overlay_edits`] merges key by key.
+        let rendered = toml::to_string_pretty(&value)
+            .map_err(|err| eyre!("{source:?} holds a value TOML cannot represent: {err}"))?;
+
+        Ok(rendered.parse()?)
+    }
+
+    /// Flattens an overlay document into per-leaf edits: nested tables
+    /// become dotted keys, so merging a partial document touches only
+    /// the keys it names instead of replacing whole sections. Each leaf
+    /// then travels the same path as an inline `KEY=VALUE` arg.
+    fn overlay_edits(prefix: &str, item: &Item, edits: &mut Vec<KeyValuePair>) -> EyreResult<()> {
+        if let Item::Table(table) = item {
+            for (key, value) in table.iter() {
+                let key = if prefix.is_empty() {
+                    key.to_owned()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+
+                Self::overlay_edits(&key, value, edits)?;
+            }
+
+            return Ok(());
+        }
+
+        let value = item
+            .clone()
+            .into_value()
+            .map_err(|_| eyre!("`{prefix}` holds nothing assignable"))?;
+
+        edits.push(KeyValuePair {
+            key: prefix.to_owned(),
+            value,
+            op: EditOp::Set,
+            comment: None,
+        });
+
+        Ok(())
+    }
+
+    /// Removes the leaf at `key` so the node falls back to its default,
+    /// returning `None` when the key wasn't set - an absent key is
+    /// already in the requested state. The document re-validates after
+    /// the batch, so unsetting a required key is rejected before disk.
+    /// Pure document manipulation, like [`Self::apply_edit`].
+    fn apply_unset_key(
+        doc: &mut toml_edit::DocumentMut,
+        key: &str,
+    ) -> EyreResult<Option<JournalEntry>> {
+        let parts: Vec<&str> = key.split('.').collect();
+
+        let mut current = doc.as_item();
+
+        // Walk read-only first: a missing parent means the key is unset,
+        // which is a no-op, not an error like it is for edits.
+        for part in &parts {
+            match current.get(part) {
+                Some(item) if !item.is_none() => current = item,
+                _ => return Ok(None),
+            }
+        }
+
+        if current.is_table() || current.as_value().is_some_and(Value::is_inline_table) {
+            bail!("`{key}` is a section, not a single key; use --unset-section");
+        }
+
+        let mut current = doc.as_item_mut();
+
+        for part in &parts[..parts.len() - 1] {
+            current = Self::descend(current, part, key)?;
+        }
+
+        let removed = current
+            .as_table_like_mut()
+            .and_then(|table| table.remove(parts[parts.len() - 1]))
+            .ok_or_else(|| eyre!("`{key}` is not a table entry; nothing to remove"))?;
+
+        Ok(Some(JournalEntry::new(
+            key,
+            Some(removed.to_string().trim().to_owned()),
+            "(removed)".to_owned(),
+        )))
+    }
+
+    /// Removes the table at `path` and all its descendants. Sections the
+    /// schema marks as holding required keys only go with `force`; the
+    /// document still re-validates after the batch, so a removal that
+    /// breaks loading never reaches disk. Pure document manipulation,
+    /// like [`Self::apply_edit`].
+    fn apply_unset_section(
+        doc: &mut toml_edit::DocumentMut,
+        path: &str,
+        force: bool,
+    ) -> EyreResult<JournalEntry> {
+        if let Some(node) = CONFIG_SCHEMA.lookup(path) {
+            if node.has_required() && !force {
+                bail!("`{path}` holds required keys; pass --force to remove it anyway");
+            }
+        }
+
+        let parts: Vec<&str> = path.split('.').collect();
+
+        let mut current = doc.as_item_mut();
+
+        for key in &parts[..parts.len() - 1] {
+            current = Self::descend(current, key, path)?;
+        }
+
+        let last = parts[parts.len() - 1];
+
+        match current.get(last) {
+            None | Some(Item::None) => bail!("`{path}` is not set; nothing to remove"),
+            Some(Item::Value(value)) if !value.is_inline_table() => {
+                bail!("`{path}` is a single key, not a section")
+            }
+            Some(_) => {}
+        }
+
+        let removed = current
+            .as_table_like_mut()
+            .and_then(|table| table.remove(last))
+            .ok_or_else(|| eyre!("`{path}` is not set; nothing to remove"))?;
+
+        Ok(JournalEntry::new(
+            path,
+            Some(removed.to_string().trim().to_owned()),
+            "(section removed)".to_owned(),
+        ))
+    }
+
+    /// Rejects `nan` and `inf`, which TOML accepts as float literals but
+    /// no config key wants, looking inside arrays and inline tables too.
+    fn reject_non_finite(key: &str, value: &Value) -> EyreResult<()> {
+        match value {
+            Value::Float(float) if !float.value().is_finite() => {
+                bail!(
+                    "`{key}` must be a finite number, got `{}`",
+                    value.to_string().trim()
+                )
+            }
+            Value::Array(array) => {
+                for item in array {
+                    Self::reject_non_finite(key, item)?;
+                }
+            }
+            Value::InlineTable(table) => {
+                for (_, item) in table {
+                    Self::reject_non_finite(key, item)?;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Deep-merges `overlay` into `base`: tables merge key by key, while
+    /// any other item - values, inline tables, arrays, arrays of tables -
+    /// replaces its counterpart wholesale.
+    fn merge_item(base: &mut Item, overlay: Item) {
+        match (base, overlay) {
+            (Item::Table(base), Item::Table(overlay)) => {
+                for (key, value) in overlay {
+                    match base.entry(&key) {
+                        toml_edit::Entry::Occupied(mut entry) => {
+                            Self::merge_item(entry.get_mut(), value);
+                        }
+                        toml_edit::Entry::Vacant(entry) => {
+                            let _ = entry.insert(value);
+                        }
+                    }
+                }
+            }
+            (base, overlay) => *base = overlay,
+        }
+    }
+
+    /// Walks one dotted-path segment; numeric segments index into arrays
+    /// and arrays-of-tables, and `key[field=value]` segments select one
+    /// array element by a field match, which stays stable when the array
+    /// is reordered.
+    fn descend<'a>(item: &'a mut Item, segment: &str, full_key: &str) -> EyreResult<&'a mut Item> {
+        if let Some((key, filter)) = segment.split_once('[') {
+            let filter = filter
+                .strip_suffix(']')
+                .ok_or_else(|| eyre!("`{full_key}`: `{segment}` is missing the closing `]`"))?;
+
+            let (field, wanted) = filter
+                .split_once('=')
+                .ok_or_else(|| eyre!("`{full_key}`: filters take the form `[FIELD=VALUE]`"))?;
+
+            return Self::select_by_field(&mut item[key], field, wanted, full_key);
+        }
+
+        let Ok(index) = segment.parse::<usize>() else {
+            return Ok(&mut item[segment]);
+        };
+
+        let len = match &*item {
+            Item::ArrayOfTables(tables) => tables.len(),
+            Item::Value(Value::Array(array)) => array.len(),
+            _ => bail!("`{full_key}`: `{segment}` indexes into something that isn't an array"),
+        };
+
+        if index >= len {
+            bail!("`{full_key}`: index {index} is out of range (array has {len} elements)");
+        }
+
+        Ok(&mut item[index])
+    }
+
+    /// Finds the single array element whose `field` matches `wanted`;
+    /// zero or several matches are errors, so a filtered edit can never
+    /// touch the wrong element silently.
+    fn select_by_field<'a>(
+        item: &'a mut Item,
+        field: &str,
+        wanted: &str,
+        full_key: &str,
+    ) -> EyreResult<&'a mut Item> {
+        let field_matches =
+            |element: Option<&Value>| element.is_some_and(|value| Self::matches_filter(value, wanted));
+
+        let indices: Vec<usize> = match &*item {
+            Item::ArrayOfTables(tables) => tables
+                .iter()
+                .enumerate()
+                .filter(|(_, table)| {
+                    field_matches(table.get(field).and_then(Item::as_value))
+                })
+                .map(|(index, _)| index)
+                .collect(),
+            Item::Value(Value::Array(array)) => array
+                .iter()
+                .enumerate()
+                .filter(|(_, element)| {
+                    element
+                        .as_inline_table()
+                        .is_some_and(|table| field_matches(table.get(field)))
+                })
+                .map(|(index, _)| index)
+                .collect(),
+            _ => bail!("`{full_key}`: `[{field}={wanted}]` filters an array of tables"),
+        };
+
+        match indices[..] {
+            [index] => Ok(&mut item[index]),
+            [] => bail!("`{full_key}`: no element has `{field}` = `{wanted}`"),
+            _ => bail!(
+                "`{full_key}`: {} elements have `{field}` = `{wanted}`; the filter must match exactly one",
+                indices.len()
+            ),
+        }
+    }
+
+    /// Whether a field's value matches the filter text: strings compare
+    /// unquoted, everything else by its TOML rendering.
+    fn matches_filter(value: &Value, wanted: &str) -> bool {
+        value
+            .as_str()
+            .map_or_else(|| value.to_string().trim() == wanted, |s| s == wanted)
+    }
+
+    /// Top-level keys in `doc` that [`CONFIG_SCHEMA`] has no entry for.
+    fn unknown_sections(doc: &toml_edit::DocumentMut) -> Vec<String> {
+        let SchemaNode::Object { children, .. } = &*CONFIG_SCHEMA else {
+            return vec![];
+        };
+
+        doc.as_table()
+            .iter()
+            .filter(|(key, _)| !children.contains_key(key))
+            .map(|(key, _)| key.to_owned())
+            .collect()
+    }
+
+    /// Renders the value at `key` for `get`, after checking it against
+    /// `--as` or, with no `--as` given, against the schema's type.
+    fn get_value(
+        doc: &toml_edit::DocumentMut,
+        key: &str,
+        ty: Option<GetType>,
+    ) -> EyreResult<String> {
+        let item = key
+            .split('.')
+            .try_fold(doc.as_item(), |item, part| item.get(part))
+            .ok_or_else(|| eyre!("`{key}` is not set"))?;
+
+        let Some(value) = item.as_value() else {
+            bail!("`{key}` is a table; `get` reads a single value");
+        };
+
+        match ty {
+            Some(ty) => {
+                if !ty.matches(value) {
+                    bail!(
+                        "`{key}` is not a {}: {}",
+                        ty.name(),
+                        value.to_string().trim()
+                    );
+                }
+            }
+            // With no --as the schema's type is the contract.
+            None => {
+                if let Some(SchemaNode::Leaf { ty, .. }) = CONFIG_SCHEMA.lookup(key) {
+                    if !ty.matches(value) {
+                        bail!(
+                            "`{key}` is set to {} but the schema expects a {ty}",
+                            value.to_string().trim()
+                        );
+                    }
+                }
+            }
+        }
+
+        // Strings print unquoted so shells don't have to strip quotes.
+        Ok(value
+            .as_str()
+            .map_or_else(|| value.to_string().trim().to_owned(), ToOwned::to_owned))
+    }
+
+    /// Prints the document, or just the subtrees named by `filters`.
+    fn print_config(
+        doc: &toml_edit::DocumentMut,
+        format: PrintFormat,
+        filters: &[&str],
+        sort: bool,
+    ) -> EyreResult<()> {
+        let sorted;
+
+        let doc = if sort {
+            let mut copy = doc.clone();
+
+            Self::sort_item(copy.as_item_mut());
+
+            sorted = copy;
+
+            &sorted
+        } else {
+            doc
+        };
+
+        if matches!(format, PrintFormat::Summary) {
+            println!("{}", Self::summary_line(doc, filters));
+
+            return Ok(());
+        }
+
+        if filters.is_empty() {
+            match format {
+                // Summary returned above.
+                PrintFormat::Summary => unreachable!(),
+                PrintFormat::Toml => print!("{doc}"),
+                PrintFormat::Human | PrintFormat::Env => {
+                    let mut lines = Vec::new();
+
+                    if matches!(format, PrintFormat::Human) {
+                        Self::add_to_table("", doc.as_item(), &mut lines);
+                    } else {
+                        Self::add_to_env("", doc.as_item(), &mut lines);
+                    }
+
+                    for line in lines {
+                        println!("{line}");
+                    }
+                }
+            }
+
+            return Ok(());
+        }
+
+        for filter in filters {
+            if CONFIG_SCHEMA.lookup(filter).is_none() {
+                warn!("`{filter}` is not a known config key");
+            }
+
+            let mut current = doc.as_item();
+            let mut found = true;
+
+            for part in filter.split('.') {
+                match current.get(part) {
+                    Some(item) => current = item,
+                    None => {
+                        found = false;
+
+                        break;
+                    }
+                }
+            }
+
+            if !found {
+                println!("# `{filter}` is not set");
+
+                continue;
+            }
+
+            match format {
+                // Summary returned above.
+                PrintFormat::Summary => unreachable!(),
+                PrintFormat::Toml => println!("{}", current.to_string().trim()),
+                PrintFormat::Human | PrintFormat::Env => {
+                    let mut lines = Vec::new();
+
+                    if matches!(format, PrintFormat::Human) {
+                        Self::add_to_table(filter, current, &mut lines);
+                    } else {
+                        Self::add_to_env(filter, current, &mut lines);
+                    }
+
+                    for line in lines {
+                        println!("{line}");
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Builds the one-line overview from the schema's summary-flagged
+    /// keys, grouped by top-level section. Booleans render as `on`/`off`
+    /// to keep the line scannable.
+    fn summary_line(doc: &toml_edit::DocumentMut, filters: &[&str]) -> String {
+        let mut sections: Vec<(String, Vec<String>)> = Vec::new();
+
+        for key in CONFIG_SCHEMA.summary_keys() {
+            if !filters.is_empty()
+                && !filters
+                    .iter()
+                    .any(|filter| key == *filter || key.starts_with(&format!("{filter}.")))
+            {
+                continue;
+            }
+
+            let (section, rest) = key.split_once('.').unwrap_or((key.as_str(), key.as_str()));
+
+            let value = key
+                .split('.')
+                .try_fold(doc.as_item(), |item, part| item.get(part))
+                .map_or_else(
+                    || "unset".to_owned(),
+                    |item| match item.as_bool() {
+                        Some(true) => "on".to_owned(),
+                        Some(false) => "off".to_owned(),
+                        None => item.to_string().trim().to_owned(),
+                    },
+                );
+
+            let part = format!("{rest}={value}");
+
+            match sections.iter_mut().find(|(name, _)| name == section) {
+                Some((_, parts)) => parts.push(part),
+                None => sections.push((section.to_owned(), vec![part])),
+            }
+        }
+
+        let sections: Vec<String> = sections
+            .into_iter()
+            .map(|(name, parts)| format!("{name}: {}", parts.join(" ")))
+            .collect();
+
+        sections.join("  ")
+    }
+
+    /// Replaces values of schema-marked sensitive keys with `****`, so
+    /// printed output is safe to share in bug reports and screenshots.
+    fn mask_secrets(prefix: &str, item: &mut Item) {
+        let join = |key: &str| {
+            if prefix.is_empty() {
+                key.to_owned()
+            } else {
+                format!("{prefix}.{key}")
+            }
+        };
+
+        match item {
+            Item::Table(table) => {
+                for (key, value) in table.iter_mut() {
+                    let key = join(key.get());
+
+                    if CONFIG_SCHEMA.lookup(&key).is_some_and(SchemaNode::is_sensitive) {
+                        *value = Item::Value("****".into());
+                    } else {
+                        Self::mask_secrets(&key, value);
+                    }
+                }
+            }
+            Item::ArrayOfTables(tables) => {
+                // Indices don't shift the schema path; every element shares
+                // the array's node.
+                for table in tables.iter_mut() {
+                    for (key, value) in table.iter_mut() {
+                        let key = join(key.get());
+
+                        if CONFIG_SCHEMA.lookup(&key).is_some_and(SchemaNode::is_sensitive) {
+                            *value = Item::Value("****".into());
+                        } else {
+                            Self::mask_secrets(&key, value);
+                        }
+                    }
+                }
+            }
+            Item::Value(Value::InlineTable(table)) => {
+                for (key, value) in table.iter_mut() {
+                    if CONFIG_SCHEMA
+                        .lookup(&join(key.get()))
+                        .is_some_and(SchemaNode::is_sensitive)
+                    {
+                        *value = "****".into();
+                    }
+                }
+            }
+            Item::Value(_) | Item::None => {}
+        }
+    }
+
+    /// Sorts every table in `item` by key, recursively.
+    fn sort_item(item: &mut Item) {
+        match item {
+            Item::Table(table) => {
+                table.sort_values();
+
+                for (_, value) in table.iter_mut() {
+                    Self::sort_item(value);
+                }
+            }
+            Item::ArrayOfTables(tables) => {
+                for table in tables.iter_mut() {
+                    table.sort_values();
+
+                    for (_, value) in table.iter_mut() {
+                        Self::sort_item(value);
+                    }
+                }
+            }
+            Item::Value(Value::InlineTable(table)) => table.sort_values(),
+            Item::Value(_) | Item::None => {}
+        }
+    }
+
+    /// Rewrites the config into canonical form: keys sorted within each
+    /// table, one space around `=`, comments kept on their own lines.
+    /// Without `--save` only the diff is shown; `--check` prints nothing
+    /// and fails when the file isn't already canonical.
+    async fn fmt(path: &Utf8Path, save: bool, check: bool) -> EyreResult<()> {
+        let toml_str = read_to_string(path)
+            .await
+            .map_err(|_| eyre!("Node is not initialized in {:?}", path))?;
+
+        let doc = toml_str.parse::<toml_edit::DocumentMut>()?;
+
+        let canonical = Self::canonicalize(&doc);
+
+        if canonical == toml_str {
+            if !check {
+                println!("{path} is already canonical");
+            }
+
+            return Ok(());
+        }
+
+        if check {
+            bail!("{path} is not canonical; run `merod config fmt --save`");
+        }
+
+        Self::print_diff(&toml_str, &canonical, false);
+
+        if !save {
+            println!("re-run with --save to write the canonical form");
+
+            return Ok(());
+        }
+
+        // The rewrite is purely syntactic, but prove the result still
+        // parses and loads before replacing the file.
+        Self::validate_toml(&canonical.parse::<toml_edit::DocumentMut>()?)?;
+
+        write(path, &canonical).await?;
+
+        info!("Node configuration has been normalized");
+
+        Ok(())
+    }
+
+    /// The document rendered in canonical form: keys sorted within each
+    /// table, one blank line before each section header, `key = value`
+    /// spacing, comments kept at column zero.
+    fn canonicalize(doc: &toml_edit::DocumentMut) -> String {
+        let mut doc = doc.clone();
+
+        Self::sort_item(doc.as_item_mut());
+        Self::canonical_table(doc.as_table_mut());
+
+        // The first section header carries the blank-line prefix every
+        // header gets; the file shouldn't open with one.
+        let text = doc.to_string();
+
+        text.trim_start_matches('\n').to_owned()
+    }
+
+    /// Normalizes the decor of every entry in `table`, recursively.
+    fn canonical_table(table: &mut Table) {
+        for (mut key, item) in table.iter_mut() {
+            match item {
+                Item::Value(value) => {
+                    let comments = comment_lines(key.leaf_decor().prefix());
+
+                    key.leaf_decor_mut().set_prefix(comments);
+                    key.leaf_decor_mut().set_suffix(" ");
+
+                    let trailing = value
+                        .decor()
+                        .suffix()
+                        .and_then(toml_edit::RawString::as_str)
+                        .unwrap_or("")
+                        .trim();
+
+                    let trailing = if trailing.starts_with('#') {
+                        format!(" {trailing}")
+                    } else {
+                        String::new()
+                    };
+
+                    value.decor_mut().set_prefix(" ");
+                    value.decor_mut().set_suffix(trailing);
+                }
+                Item::Table(inner) => {
+                    let comments = comment_lines(inner.decor().prefix());
+
+                    inner.decor_mut().set_prefix(format!("\n{comments}"));
+
+                    Self::canonical_table(inner);
+                }
+                Item::ArrayOfTables(tables) => {
+                    for inner in tables.iter_mut() {
+                        let comments = comment_lines(inner.decor().prefix());
+
+                        inner.decor_mut().set_prefix(format!("\n{comments}"));
+
+                        Self::canonical_table(inner);
+                    }
+                }
+                Item::None => {}
+            }
+        }
+    }
+
+    /// Flattens `item` into `prefix.key = value` lines, one per leaf.
+    fn add_to_table(prefix: &str, item: &Item, lines: &mut Vec<String>) {
+        let join = |key: &str| {
+            if prefix.is_empty() {
+                key.to_owned()
+            } else {
+                format!("{prefix}.{key}")
+            }
+        };
+
+        match item {
+            Item::Table(table) => {
+                for (key, value) in table {
+                    Self::add_to_table(&join(key), value, lines);
+                }
+            }
+            Item::ArrayOfTables(tables) => {
+                for (index, table) in tables.iter().enumerate() {
+                    for (key, value) in table {
+                        Self::add_to_table(&format!("{prefix}[{index}].{key}"), value, lines);
+                    }
+                }
+            }
+            Item::Value(Value::InlineTable(table)) => {
+                for (key, value) in table {
+                    lines.push(format!("{} = {}", join(key), value.to_string().trim()));
+                }
+            }
+            Item::Value(Value::Array(array)) => {
+                // One row per element, indexed like arrays-of-tables, so
+                // listen addresses and bootstrap peers each get a line.
+                if array.is_empty() {
+                    lines.push(format!("{prefix} = []"));
+                }
+
+                for (index, element) in array.iter().enumerate() {
+                    match element {
+                        Value::InlineTable(table) => {
+                            for (key, value) in table {
+                                lines.push(format!(
+                                    "{prefix}[{index}].{key} = {}",
+                                    value.to_string().trim()
+                                ));
+                            }
+                        }
+                        element => {
+                            lines.push(format!(
+                                "{prefix}[{index}] = {}",
+                                element.to_string().trim()
+                            ));
+                        }
+                    }
+                }
+            }
+            Item::Value(Value::Datetime(value)) => {
+                // Datetimes are bare in TOML; render them without quotes.
+                lines.push(format!("{prefix} = {}", value.to_string().trim()));
+            }
+            Item::Value(value) => {
+                lines.push(format!("{prefix} = {}", value.to_string().trim()));
+            }
+            Item::None => {}
+        }
+    }
+
+    /// Flattens `item` into `SECTION_SUBKEY=value` lines, one per leaf.
+    fn add_to_env(prefix: &str, item: &Item, lines: &mut Vec<String>) {
+        let join = |key: &str| {
+            if prefix.is_empty() {
+                key.to_owned()
+            } else {
+                format!("{prefix}.{key}")
+            }
+        };
+
+        match item {
+            Item::Table(table) => {
+                for (key, value) in table {
+                    Self::add_to_env(&join(key), value, lines);
+                }
+            }
+            Item::ArrayOfTables(tables) => {
+                for (index, table) in tables.iter().enumerate() {
+                    for (key, value) in table {
+                        Self::add_to_env(&format!("{prefix}.{index}.{key}"), value, lines);
+                    }
+                }
+            }
+            Item::Value(Value::InlineTable(table)) => {
+                for (key, value) in table {
+                    lines.push(format!("{}={}", Self::env_key(&join(key)), Self::env_value(value)));
+                }
+            }
+            Item::Value(value) => {
+                lines.push(format!("{}={}", Self::env_key(prefix), Self::env_value(value)));
+            }
+            Item::None => {}
+        }
+    }
+
+    /// Uppercases a dotted key and joins its segments with underscores.
+    fn env_key(path: &str) -> String {
+        path.chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_uppercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    }
+
+    /// Renders a value as a shell-safe assignment right-hand side.
+    fn env_value(value: &Value) -> String {
+        match value {
+            Value::String(s) => format!("\"{}\"", s.value()),
+            // Env consumers get datetimes as quoted RFC 3339 strings.
+            Value::Datetime(value) => format!("\"{}\"", value.to_string().trim()),
+            Value::Array(array) => {
+                let items: Vec<String> = array
+                    .iter()
+                    .map(|item| match item {
+                        Value::String(s) => s.value().clone(),
+                        other => other.to_string().trim().to_owned(),
+                    })
+                    .collect();
+
+                format!("\"{}\"", items.join(","))
+            }
+            other => other.to_string().trim().to_owned(),
+        }
+    }
+
+    /// Diffs the live config against what `merod init` writes with default
+    /// arguments, showing exactly which values an operator has overridden.
+    /// Per-node generated values (the identity keypair and local signer
+    /// credentials) are carried over from the live config since they are
+    /// unique by construction and would drown the diff in noise.
+    async fn diff_defaults(path: &Utf8Path) -> EyreResult<()> {
+        let toml_str = read_to_string(path).await?;
+
+        let live: ConfigFile = toml::from_str(&toml_str)?;
+
+        // Re-serialize the live config so both sides share one formatting.
+        let live_str = toml::to_string_pretty(&live)?;
+
+        let mut default = Self::default_config(live.identity.clone())?;
+        default.context.client.signer.local = live.context.client.signer.local;
+
+        let default_str = toml::to_string_pretty(&default)?;
+
+        if default_str == live_str {
+            println!("no overrides; the config matches the defaults");
+
+            return Ok(());
+        }
+
+        Self::print_diff(&default_str, &live_str, false);
+
+        Ok(())
+    }
+
+    /// Builds the config `merod init` writes with default arguments,
+    /// around the caller-supplied node identity.
+    fn default_config(identity: libp2p::identity::Keypair) -> EyreResult<ConfigFile> {
+        let swarm_port = calimero_network::config::DEFAULT_PORT;
+        let server_port = calimero_server::config::DEFAULT_PORT;
+
+        let listen = vec![
+            format!("/ip4/0.0.0.0/tcp/{swarm_port}").parse()?,
+            format!("/ip4/0.0.0.0/udp/{swarm_port}/quic-v1").parse()?,
+            format!("/ip6/::/tcp/{swarm_port}").parse()?,
+            format!("/ip6/::/udp/{swarm_port}/quic-v1").parse()?,
+        ];
+
+        let server_listen = vec![
+            format!("/ip4/127.0.0.1/tcp/{server_port}").parse()?,
+            format!("/ip6/::1/tcp/{server_port}").parse()?,
+        ];
+
+        let client = default_client_config(defaults::default_relayer_url())?;
+
+        Ok(ConfigFile::new(
+            identity,
+            NetworkConfig::new(
+                SwarmConfig::new(listen),
+                BootstrapConfig::new(BootstrapNodes::calimero_dev()),
+                DiscoveryConfig::new(
+                    true,
+                    false,
+                    RendezvousConfig::new(3),
+                    RelayConfig::new(3),
+                    AutonatConfig::new(2),
+                ),
+                ServerConfig::new(
+                    server_listen,
+                    Some(AdminConfig::new(true)),
+                    Some(JsonRpcConfig::new(true)),
+                    Some(WsConfig::new(true)),
+                ),
+            ),
+            SyncConfig {
+                timeout: Duration::from_secs(30),
+                interval: Duration::from_secs(30),
+            },
+            DataStoreConfig::new("data".into()),
+            BlobStoreConfig::new("blobs".into()),
+            ContextConfig { client },
+        ))
+    }
+
+    /// Writes a fresh default config, walking the schema with one prompt
+    /// per documented key when `interactive` is set. Empty answers keep
+    /// the pre-filled default; optional sections can be skipped outright.
+    async fn init(path: &Utf8Path, interactive: bool) -> EyreResult<()> {
+        if path.exists() {
+            bail!("{:?} already exists; edit it with `merod config` instead", path);
+        }
+
+        let default = Self::default_config(libp2p::identity::Keypair::generate_ed25519())?;
+
+        let mut doc = toml::to_string_pretty(&default)?.parse::<toml_edit::DocumentMut>()?;
+
+        if interactive {
+            if let SchemaNode::Object { children, .. } = &*CONFIG_SCHEMA {
+                for (key, node) in children {
+                    Self::wizard_section(key, node, &mut doc)?;
+                }
+            }
+        }
+
+        Self::validate_toml(&doc)?;
+
+        write(path, doc.to_string()).await?;
+
+        info!("Wrote {}", path);
+
+        Ok(())
+    }
+
+    /// One wizard step: objects ask whether to enter them (unless a key
+    /// below is required), leaves prompt for a value with the current
+    /// default pre-filled.
+    fn wizard_section(
+        prefix: &str,
+        node: &SchemaNode,
+        doc: &mut toml_edit::DocumentMut,
+    ) -> EyreResult<()> {
+        match node {
+            SchemaNode::Object {
+                description,
+                children,
+            } => {
+                if Self::section_required(node) {
+                    println!("{prefix}: {description}");
+                } else {
+                    let answer = Self::prompt(&format!("configure {prefix}? ({description}) [y/N] "))?;
+
+                    if !matches!(answer.to_lowercase().as_str(), "y" | "yes") {
+                        return Ok(());
+                    }
+                }
+
+                for (key, child) in children {
+                    // `*` stands in for caller-chosen names, e.g. protocol
+                    // tables; the defaults already cover them.
+                    if *key == "*" {
+                        continue;
+                    }
+
+                    Self::wizard_section(&format!("{prefix}.{key}"), child, doc)?;
+                }
+            }
+            SchemaNode::Leaf {
+                description, ty, ..
+            } => loop {
+                let current = prefix
+                    .split('.')
+                    .try_fold(doc.as_item(), |item, part| item.get(part))
+                    .map_or_else(
+                        || "(unset)".to_owned(),
+                        |item| item.to_string().trim().to_owned(),
+                    );
+
+                let answer = Self::prompt(&format!("{prefix} ({ty}) - {description} [{current}]: "))?;
+
+                if answer.is_empty() {
+                    break;
+                }
+
+                // Accept bare strings without requiring TOML quoting.
+                let value = Value::from_str(&answer)
+                    .or_else(|_| Value::from_str(&format!("\"{answer}\"")));
+
+                match value {
+                    Ok(value) if ty.matches(&value) => {
+                        drop(Self::apply_edit(
+                            doc,
+                            &KeyValuePair {
+                                key: prefix.to_owned(),
+                                value,
+                                op: EditOp::Set,
+                                comment: None,
+                            },
+                        )?);
+
+                        break;
+                    }
+                    Ok(value) => println!("`{prefix}` expects a {ty}, got `{value}`"),
+                    Err(_) => println!("`{answer}` is not a valid TOML value"),
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Whether any leaf beneath `node` must be set for the config to load.
+    fn section_required(node: &SchemaNode) -> bool {
+        match node {
+            SchemaNode::Leaf { required, .. } => *required,
+            SchemaNode::Object { children, .. } => children.values().any(Self::section_required),
+        }
+    }
+
+    /// Prints `message` and reads one trimmed line from stdin.
+    fn prompt(message: &str) -> EyreResult<String> {
+        print!("{message}");
+
+        stdout().flush()?;
+
+        let mut answer = String::new();
+
+        let _ = stdin().read_line(&mut answer)?;
+
+        Ok(answer.trim().to_owned())
+    }
+
+    /// Prints the change journal, oldest edit first, optionally clipped
+    /// to a time window.
+    async fn history(
+        dir: &Utf8Path,
+        since: Option<DateTime<Utc>>,
+        until: Option<DateTime<Utc>>,
+    ) -> EyreResult<()> {
+        let entries: Vec<_> = journal::read(dir)
+            .await?
+            .into_iter()
+            .filter(|entry| since.map_or(true, |since| entry.at >= since))
+            .filter(|entry| until.map_or(true, |until| entry.at <= until))
+            .collect();
+
+        if entries.is_empty() {
+            if since.is_some() || until.is_some() {
+                println!("No config edits recorded in that window");
+            } else {
+                println!("No config edits recorded");
+            }
+
+            return Ok(());
+        }
+
+        for entry in entries {
+            let old = entry.old.as_deref().unwrap_or("(unset)");
+
+            println!("{} {}: {} -> {}", entry.at, entry.key, old, entry.new);
+        }
+
+        Ok(())
+    }
+
+    /// Sends SIGHUP to the node named by the PID file, so it re-reads
+    /// hot-reloadable keys. Keys read once at startup still need a
+    /// restart.
+    async fn reload_node(dir: &Utf8Path) -> EyreResult<()> {
+        let pid_path = dir.join(PID_FILE);
+
+        let raw = read_to_string(&pid_path).await.map_err(|_| {
+            eyre!("no running node detected ({pid_path:?} is missing); nothing to reload")
+        })?;
+
+        let pid: i32 = raw
+            .trim()
+            .parse()
+            .map_err(|_| eyre!("{pid_path:?} does not hold a PID"))?;
+
+        kill(Pid::from_raw(pid), Signal::SIGHUP)
+            .map_err(|err| eyre!("could not signal pid {pid} ({err}); is the node still running?"))?;
+
+        info!("sent SIGHUP to pid {pid}; the node reloads hot-reloadable keys");
+
+        Ok(())
+    }
+
+    /// Copies the current config to a timestamped `.bak` file beside it,
+    /// pruning all but the newest [`BACKUP_KEEP`] backups.
+    async fn backup_config(dir: &Utf8Path, path: &Utf8Path) -> EyreResult<()> {
+        let current = read_to_string(path).await?;
+
+        let backup = dir.join(format!("{CONFIG_FILE}.bak.{}", Utc::now().timestamp()));
+
+        write(&backup, current).await?;
+
+        let mut backups = Self::list_backups(dir)?;
+
+        while backups.len() > BACKUP_KEEP {
+            remove_file(backups.remove(0))?;
+        }
+
+        Ok(())
+    }
+
+    /// The config backups in `dir`, oldest first; the timestamped names
+    /// sort chronologically.
+    fn list_backups(dir: &Utf8Path) -> EyreResult<Vec<Utf8PathBuf>> {
+        let prefix = format!("{CONFIG_FILE}.bak.");
+
+        let mut backups = Vec::new();
+
+        for entry in dir.read_dir_utf8()? {
+            let entry = entry?;
+
+            if entry.file_name().starts_with(&prefix) {
+                backups.push(entry.into_path());
+            }
+        }
+
+        backups.sort();
+
+        Ok(backups)
+    }
+
+    /// Restores the config from `backup` (the most recent one when
+    /// unnamed), validating first and diffing against the current file.
+    /// The current file is backed up in turn, so a rollback can itself
+    /// be rolled back.
+    async fn rollback_config(
+        &self,
+        dir: &Utf8Path,
+        path: &Utf8Path,
+        backup: Option<&str>,
+    ) -> EyreResult<()> {
+        let backups = Self::list_backups(dir)?;
+
+        let source = match backup {
+            Some(name) => {
+                let candidate = dir.join(name);
+
+                if !backups.contains(&candidate) {
+                    bail!("`{name}` is not a config backup in {dir:?}");
+                }
+
+                candidate
+            }
+            None => backups
+                .last()
+                .cloned()
+                .ok_or_else(|| eyre!("no config backups in {dir:?}; one is kept per save"))?,
+        };
+
+        let restored = read_to_string(&source).await?;
+
+        // Prove the backup still loads before touching the live file.
+        Self::validate_toml(&restored.parse::<toml_edit::DocumentMut>()?)?;
+
+        let current = read_to_string(path).await?;
+
+        if current == restored {
+            println!("{source} matches the current config; nothing to restore");
+
+            return Ok(());
+        }
+
+        Self::print_diff(&current, &restored, self.full_diff);
+
+        if !self.yes {
+            let answer = Self::prompt(&format!("Restore {source}? [y/N] "))?;
+
+            if !matches!(answer.to_lowercase().as_str(), "y" | "yes") {
+                bail!("aborted; no changes written");
+            }
+        }
+
+        Self::backup_config(dir, path).await?;
+
+        write(path, &restored).await?;
+
+        journal::append(
+            dir,
+            &[JournalEntry::new(
+                "(rollback)",
+                None,
+                source.to_string(),
+            )],
+        )
+        .await?;
+
+        info!("Restored {}", source);
+
+        Ok(())
+    }
+
+    /// Hands the config over to `$EDITOR` and validates the result.
+    async fn edit(self, path: &Utf8Path) -> EyreResult<()> {
+        let editor = var("EDITOR").unwrap_or_else(|_| "vi".to_owned());
+
+        let status = Command::new(&editor).arg(path.as_str()).status()?;
+
+        if !status.success() {
+            bail!("`{editor}` exited with {status}");
+        }
+
+        let toml_str = read_to_string(path).await?;
+
+        let doc = toml_str.parse::<toml_edit::DocumentMut>()?;
+
+        Self::validate_toml(&doc)?;
+
+        info!("Node configuration has been updated");
+
+        Ok(())
+    }
+
+    /// Re-validates the config on every filesystem change, printing the
+    /// verdict each time. A read-only loop; nothing is ever written.
+    async fn watch(self, path: &Utf8Path) -> EyreResult<()> {
+        let (tx, mut rx) = mpsc::channel(1);
+        let handle = Handle::current();
+        let mut watcher = notify::recommended_watcher(move |evt| {
+            handle.block_on(async {
+                drop(tx.send(evt).await);
+            });
+        })?;
+
+        watcher.watch(path.as_std_path(), RecursiveMode::NonRecursive)?;
+
+        println!("watching {path}; validating on every change");
+
+        while let Some(event) = rx.recv().await {
+            let event = match event {
+                Ok(event) => event,
+                Err(err) => {
+                    warn!("watch error: {err:?}");
+                    continue;
+                }
+            };
+
+            match event.kind {
+                EventKind::Modify(ModifyKind::Data(_)) => {}
+                EventKind::Remove(_) => {
+                    warn!("{} removed; waiting for it to come back", path);
+                    continue;
+                }
+                EventKind::Any
+                | EventKind::Access(_)
+                | EventKind::Create(_)
+                | EventKind::Modify(_)
+                | EventKind::Other => continue,
+            }
+
+            let toml_str = match read_to_string(path).await {
+                Ok(toml_str) => toml_str,
+                Err(err) => {
+                    warn!("could not read {}: {}", path, err);
+                    continue;
+                }
+            };
+
+            match toml_str.parse::<toml_edit::DocumentMut>() {
+                Ok(doc) => match Self::validate_toml(&doc) {
+                    Ok(()) => println!("{path}: valid"),
+                    Err(err) => println!("{path}: invalid: {err}"),
+                },
+                Err(err) => println!("{path}: invalid: {err}"),
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn validate_toml(doc: &toml_edit::DocumentMut) -> EyreResult<()> {
+        drop(Self::load_snapshot(&doc.to_string())?);
+
+        Ok(())
+    }
+
+    /// Validates like [`Self::validate_toml`], but attributes a failure
+    /// to the key that caused it: the loader's error span is mapped back
+    /// to a dotted path and its line, the expected type comes from the
+    /// schema, and an edit from this batch is called out as such - so a
+    /// multi-edit batch names the offending key instead of failing
+    /// opaquely.
+    fn validate_toml_attributed(
+        doc: &toml_edit::DocumentMut,
+        edits: &[JournalEntry],
+    ) -> EyreResult<()> {
+        let Err(err) = Self::load_snapshot(&doc.to_string()) else {
+            return Ok(());
+        };
+
+        let Some(span) = err
+            .downcast_ref::<toml::de::Error>()
+            .and_then(toml::de::Error::span)
+        else {
+            return Err(err);
+        };
+
+        let rendered = doc.to_string();
+
+        let line = rendered[..span.start.min(rendered.len())]
+            .bytes()
+            .filter(|&byte| byte == b'\n')
+            .count()
+            + 1;
+
+        // Edits shed the spans the live document was parsed with; a
+        // fresh parse restores them, so the error offset can be walked
+        // back to the dotted key it points into.
+        let Some(key) = rendered
+            .parse::<toml_edit::DocumentMut>()
+            .ok()
+            .and_then(|spanned| Self::key_at_offset("", spanned.as_item(), span.start))
+        else {
+            bail!("Config validation failed at line {line}: {}", err);
+        };
+
+        let blame = if edits.iter().any(|entry| entry.key == key) {
+            format!("edit of `{key}`")
+        } else {
+            format!("`{key}`")
+        };
+
+        let got = key
+            .split('.')
+            .try_fold(doc.as_item(), |item, part| item.get(part))
+            .map(|item| item.to_string().trim().to_owned());
+
+        match (CONFIG_SCHEMA.lookup(&key), got) {
+            (Some(SchemaNode::Leaf { ty, .. }), Some(got)) => {
+                bail!("Config validation failed: {blame} expects a {ty}, got `{got}` (line {line})")
+            }
+            _ => bail!(
+                "Config validation failed: {blame} (line {line}): {}",
+                err.downcast_ref::<toml::de::Error>()
+                    .map_or("invalid value", toml::de::Error::message)
+            ),
+        }
+    }
+
+    /// Finds the dotted path of the value whose span covers `offset` in
+    /// a freshly parsed document.
+    fn key_at_offset(prefix: &str, item: &Item, offset: usize) -> Option<String> {
+        match item {
+            Item::Table(table) => Self::key_in_table(prefix, table, offset),
+            Item::ArrayOfTables(tables) => tables.iter().enumerate().find_map(|(index, table)| {
+                Self::key_in_table(&format!("{prefix}.{index}"), table, offset)
+            }),
+            Item::Value(value) => value
+                .span()
+                .is_some_and(|span| span.contains(&offset))
+                .then(|| prefix.to_owned()),
+            Item::None => None,
+        }
+    }
+
+    /// [`Self::key_at_offset`] over one table: entries are tried value
+    /// first, so the innermost match wins, with the key token itself as
+    /// the fallback when the error points at a name rather than a value.
+    fn key_in_table(prefix: &str, table: &Table, offset: usize) -> Option<String> {
+        for (key, value) in table.iter() {
+            let dotted = if prefix.is_empty() {
+                key.to_owned()
+            } else {
+                format!("{prefix}.{key}")
+            };
+
+            if let Some(found) = Self::key_at_offset(&dotted, value, offset) {
+                return Some(found);
+            }
+
+            if table
+                .key(key)
+                .and_then(toml_edit::Key::span)
+                .is_some_and(|span| span.contains(&offset))
+            {
+                return Some(dotted);
+            }
+        }
+
+        None
+    }
+
+    /// Round-trips `contents` through [`ConfigFile::from_toml_str`],
+    /// surfacing any validation error without touching the filesystem.
+    fn load_snapshot(contents: &str) -> EyreResult<ConfigFile> {
+        ConfigFile::from_toml_str(contents)
+    }
+
+    /// Collects dotted paths whose loaded values differ between two
+    /// config snapshots, ignoring changes that are purely textual.
+    fn semantic_changes(
+        prefix: &str,
+        old: &serde_json::Value,
+        new: &serde_json::Value,
+        out: &mut Vec<String>,
+    ) {
+        use serde_json::Value as Json;
+
+        let join = |key: &str| {
+            if prefix.is_empty() {
+                key.to_owned()
+            } else {
+                format!("{prefix}.{key}")
+            }
+        };
+
+        match (old, new) {
+            (Json::Object(old), Json::Object(new)) => {
+                for (key, old_value) in old {
+                    match new.get(key) {
+                        Some(new_value) => {
+                            Self::semantic_changes(&join(key), old_value, new_value, out)
+                        }
+                        None => out.push(format!("{}: {} -> (removed)", join(key), old_value)),
+                    }
+                }
+
+                for (key, new_value) in new {
+                    if !old.contains_key(key) {
+                        out.push(format!("{}: (unset) -> {}", join(key), new_value));
+                    }
+                }
+            }
+            _ if old == new => {}
+            _ => out.push(format!("{prefix}: {old} -> {new}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The smallest config that loads as a [`ConfigFile`]; the identity
+    /// keypair is generated on deserialization when absent.
+    const MINIMAL_CONFIG: &str = r#"
+[swarm]
+listen = []
+
+[server]
+listen = []
+
+[sync]
+timeout_ms = 30000
+interval_ms = 30000
+
+[datastore]
+path = "data"
+
+[blobstore]
+path = "blobs"
+
+[context.config.signer.relayer]
+url = "http://127.0.0.1:63529"
+
+[context.config.signer.self]
+"#;
+
+    /// Applies `edits` to a fresh minimal document and loads the result,
+    /// so tests can assert on the [`ConfigFile`] the node would see.
+    fn round_trip(edits: &[&str]) -> EyreResult<(Vec<JournalEntry>, ConfigFile)> {
+        let mut doc = MINIMAL_CONFIG
+            .parse::<toml_edit::DocumentMut>()
+            .expect("the minimal config is valid TOML");
+
+        let mut entries = Vec::new();
+
+        for edit in edits {
+            let kv: KeyValuePair = edit.parse().map_err(|err: String| eyre!(err))?;
+
+            entries.push(ConfigCommand::apply_edit(&mut doc, &kv)?);
+        }
+
+        Ok((entries, ConfigCommand::load_snapshot(&doc.to_string())?))
+    }
+
+    #[test]
+    fn edits_round_trip_into_config_fields() {
+        let (_, config) = round_trip(&["sync.timeout_ms=5000", "datastore.path='state'"])
+            .expect("well-typed edits must apply and validate");
+
+        assert_eq!(config.sync.timeout, Duration::from_secs(5));
+        assert_eq!(config.datastore.path, "state");
+    }
+
+    #[test]
+    fn edits_record_old_and_new_values() {
+        let (entries, _) =
+            round_trip(&["sync.interval_ms=60000"]).expect("a well-typed edit must apply");
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].old.as_deref(), Some("30000"));
+        assert_eq!(entries[0].new, "60000");
+    }
+
+    #[test]
+    fn mistyped_edits_are_rejected_by_the_schema() {
+        assert!(round_trip(&["sync.timeout_ms='soon'"]).is_err());
+    }
+
+    #[test]
+    fn non_finite_numbers_are_rejected() {
+        assert!(round_trip(&["discovery.rendezvous.discovery_rpm=nan"]).is_err());
+        assert!(round_trip(&["discovery.rendezvous.discovery_rpm=inf"]).is_err());
+    }
+
+    #[test]
+    fn out_of_range_integers_are_rejected() {
+        // Millisecond durations deserialize as u64; negatives must fail
+        // with a range error at edit time, not at load time.
+        assert!(round_trip(&["sync.timeout_ms=-1"]).is_err());
+    }
+
+    #[test]
+    fn renames_move_values_and_guard_the_target() {
+        let mut doc = MINIMAL_CONFIG
+            .parse::<toml_edit::DocumentMut>()
+            .expect("the minimal config is valid TOML");
+
+        let rename: RenameArg = "sync.timeout_ms=sync.round_timeout_ms"
+            .parse()
+            .map_err(|err: String| eyre!(err))
+            .expect("OLD=NEW parses");
+
+        let entries =
+            ConfigCommand::apply_rename(&mut doc, &rename, false).expect("the rename must apply");
+
+        assert_eq!(entries.len(), 2);
+        assert!(doc["sync"].get("timeout_ms").is_none());
+        assert_eq!(
+            doc["sync"]["round_timeout_ms"].as_integer(),
+            Some(30000)
+        );
+
+        // An occupied target is an error without --force.
+        let clobber: RenameArg = "datastore.path=blobstore.path".parse().expect("parses");
+
+        assert!(ConfigCommand::apply_rename(&mut doc, &clobber, false).is_err());
+        assert!(ConfigCommand::apply_rename(&mut doc, &clobber, true).is_ok());
+        assert_eq!(doc["blobstore"]["path"].as_str(), Some("data"));
+    }
+
+    #[test]
+    fn summary_line_groups_flagged_keys_by_section() {
+        let mut doc = MINIMAL_CONFIG
+            .parse::<toml_edit::DocumentMut>()
+            .expect("the minimal config is valid TOML");
+
+        let kv: KeyValuePair = "discovery.mdns=true".parse().expect("valid edit");
+
+        drop(ConfigCommand::apply_edit(&mut doc, &kv).expect("a bool edit must apply"));
+
+        let line = ConfigCommand::summary_line(&doc, &[]);
+
+        assert!(line.contains("sync: interval_ms=30000 timeout_ms=30000"), "{line}");
+        assert!(line.contains("mdns=on"), "{line}");
+        assert!(line.contains("relay.enabled=unset"), "{line}");
+
+        // Filters scope the line to the named sections.
+        let line = ConfigCommand::summary_line(&doc, &["sync"]);
+
+        assert!(!line.contains("mdns"), "{line}");
+    }
+
+    #[test]
+    fn array_edits_append_and_remove() {
+        let (_, config) = round_trip(&[
+            "swarm.listen+='/ip4/127.0.0.1/tcp/2428'",
+            "swarm.listen+='/ip6/::1/tcp/2428'",
+            "swarm.listen-='/ip4/127.0.0.1/tcp/2428'",
+        ])
+        .expect("array edits must apply and validate");
+
+        assert_eq!(config.network.swarm.listen.len(), 1);
+
+        // Removing something that isn't there is a mistake worth surfacing.
+        assert!(round_trip(&["swarm.listen-='/ip4/10.0.0.1/tcp/1'"]).is_err());
+
+        // Scalar keys don't grow array semantics.
+        assert!(round_trip(&["sync.timeout_ms+=5"]).is_err());
+    }
+
+    #[test]
+    fn diff_entries_classify_sets_and_unsets() {
+        let set = JournalEntry::new(
+            "sync.timeout_ms",
+            Some("30000".to_owned()),
+            "5000".to_owned(),
+        );
+
+        let unset = JournalEntry::new(
+            "discovery.mdns",
+            Some("true".to_owned()),
+            "(removed)".to_owned(),
+        );
+
+        let entries: Vec<DiffEntry<'_>> = [&set, &unset].into_iter().map(DiffEntry::from).collect();
+
+        assert_eq!(entries[0].op, "set");
+        assert_eq!(entries[1].op, "unset");
+
+        // The report is data for CI, so it must serialize cleanly.
+        let report = serde_json::to_string(&entries).expect("diff entries must serialize");
+
+        assert!(report.contains("\"op\":\"unset\""), "{report}");
+    }
+
+    #[test]
+    fn validation_failures_name_the_offending_key_and_line() {
+        let mut doc = MINIMAL_CONFIG
+            .parse::<toml_edit::DocumentMut>()
+            .expect("the minimal config is valid TOML");
+
+        // Bypass the schema check the way a hand-edited file would, so
+        // the loader is what rejects the value.
+        doc["sync"]["timeout_ms"] = Item::Value(Value::from("soon"));
+
+        let entry = JournalEntry::new(
+            "sync.timeout_ms",
+            Some("30000".to_owned()),
+            "\"soon\"".to_owned(),
+        );
+
+        let err = ConfigCommand::validate_toml_attributed(&doc, &[entry])
+            .expect_err("a mistyped value must fail validation");
+
+        let message = err.to_string();
+
+        assert!(message.contains("edit of `sync.timeout_ms`"), "{message}");
+        assert!(message.contains("line"), "{message}");
+    }
+
+    #[test]
+    fn from_file_overlays_merge_instead_of_replacing_sections() {
+        let mut doc = MINIMAL_CONFIG
+            .parse::<toml_edit::DocumentMut>()
+            .expect("the minimal config is valid TOML");
+
+        let overlay = ConfigCommand::parse_overlay(
+            "[sync]\ntimeout_ms = 5000\n",
+            Utf8Path::new("overlay.toml"),
+        )
+        .expect("a TOML overlay must parse");
+
+        let mut merges = Vec::new();
+
+        ConfigCommand::overlay_edits("", overlay.as_item(), &mut merges)
+            .expect("the overlay flattens into leaf edits");
+
+        for kv in &merges {
+            drop(ConfigCommand::apply_edit(&mut doc, kv).expect("an overlay edit must apply"));
+        }
+
+        // Sibling keys in the merged section survive.
+        assert_eq!(doc["sync"]["timeout_ms"].as_integer(), Some(5000));
+        assert_eq!(doc["sync"]["interval_ms"].as_integer(), Some(30000));
+
+        // Inline args run after the overlay, so they win on conflict.
+        let kv: KeyValuePair = "sync.timeout_ms=7000".parse().expect("valid edit");
+
+        drop(ConfigCommand::apply_edit(&mut doc, &kv).expect("the inline edit must apply"));
+
+        assert_eq!(doc["sync"]["timeout_ms"].as_integer(), Some(7000));
+    }
+
+    #[test]
+    fn from_file_accepts_json_and_names_both_parse_failures() {
+        let overlay = ConfigCommand::parse_overlay(
+            r#"{ "sync": { "timeout_ms": 5000 } }"#,
+            Utf8Path::new("overlay.json"),
+        )
+        .expect("a JSON overlay must parse");
+
+        assert_eq!(overlay["sync"]["timeout_ms"].as_integer(), Some(5000));
+
+        let err = ConfigCommand::parse_overlay("not = [valid", Utf8Path::new("overlay"))
+            .expect_err("text that is neither format must be rejected");
+
+        assert!(err.to_string().contains("as TOML"), "{err}");
+        assert!(err.to_string().contains("as JSON"), "{err}");
+    }
+
+    #[test]
+    fn unset_section_removes_subtrees_and_guards_required_keys() {
+        let mut doc = MINIMAL_CONFIG
+            .parse::<toml_edit::DocumentMut>()
+            .expect("the minimal config is valid TOML");
+
+        // `sync` holds required keys, so it only goes with --force.
+        assert!(ConfigCommand::apply_unset_section(&mut doc, "sync", false).is_err());
+
+        let entry = ConfigCommand::apply_unset_section(&mut doc, "sync", true)
+            .expect("a forced removal must apply");
+
+        assert!(doc.get("sync").is_none());
+        assert_eq!(entry.new, "(section removed)");
+
+        // Leaves and unset paths are not sections.
+        assert!(ConfigCommand::apply_unset_section(&mut doc, "datastore.path", true).is_err());
+        assert!(ConfigCommand::apply_unset_section(&mut doc, "discovery", true).is_err());
+    }
+
+    #[test]
+    fn field_filters_select_exactly_one_array_element() {
+        let mut doc = r#"
+[[peers]]
+name = "foo"
+zone = "x"
+addr = "a"
+
+[[peers]]
+name = "bar"
+zone = "x"
+addr = "b"
+"#
+        .parse::<toml_edit::DocumentMut>()
+        .expect("valid TOML");
+
+        let kv: KeyValuePair = "peers[name=foo].addr='c'".parse().expect("valid edit");
+
+        drop(ConfigCommand::apply_edit(&mut doc, &kv).expect("the filtered edit must apply"));
+
+        assert_eq!(doc["peers"][0]["addr"].as_str(), Some("c"));
+        assert_eq!(doc["peers"][1]["addr"].as_str(), Some("b"));
+
+        // Zero matches and several matches both fail.
+        let kv: KeyValuePair = "peers[name=baz].addr='c'".parse().expect("valid edit");
+
+        assert!(ConfigCommand::apply_edit(&mut doc, &kv).is_err());
+
+        let kv: KeyValuePair = "peers[zone=x].addr='c'".parse().expect("valid edit");
+
+        assert!(ConfigCommand::apply_edit(&mut doc, &kv).is_err());
+    }
+
+    #[test]
+    fn apply_modes_control_what_survives_a_broken_edit() {
+        // The middle edit passes the schema (it is an array) but fails
+        // validation: the element is not a multiaddr.
+        let edits: Vec<KeyValuePair> = [
+            "sync.timeout_ms=5000",
+            "swarm.listen=['not-a-multiaddr']",
+            "sync.interval_ms=7000",
+        ]
+        .iter()
+        .map(|edit| edit.parse().expect("valid edit"))
+        .collect();
+
+        let fresh = || {
+            MINIMAL_CONFIG
+                .parse::<toml_edit::DocumentMut>()
+                .expect("the minimal config is valid TOML")
+        };
+
+        // Transactional: the batch errors and the document is untouched.
+        let mut doc = fresh();
+
+        assert!(ConfigCommand::apply_edits(&mut doc, &edits, ApplyMode::Transactional).is_err());
+        assert_eq!(doc.to_string(), MINIMAL_CONFIG);
+
+        // Partial: the broken edit is skipped, the edits around it land.
+        let mut doc = fresh();
+
+        let outcome = ConfigCommand::apply_edits(&mut doc, &edits, ApplyMode::Partial)
+            .expect("partial mode reports skips in the outcome");
+
+        assert_eq!(outcome.entries.len(), 2);
+        assert!(outcome.stepwise_failure.is_some());
+        assert_eq!(doc["sync"]["interval_ms"].as_integer(), Some(7000));
+        assert_eq!(doc["swarm"]["listen"].as_array().map(toml_edit::Array::len), Some(0));
+
+        // Stepwise: only the prefix before the broken edit survives.
+        let mut doc = fresh();
+
+        let outcome = ConfigCommand::apply_edits(&mut doc, &edits, ApplyMode::Stepwise)
+            .expect("stepwise mode reports the failure in the outcome");
+
+        assert_eq!(outcome.entries.len(), 1);
+        assert_eq!(doc["sync"]["interval_ms"].as_integer(), Some(30000));
+    }
+
+    #[test]
+    fn json_patch_ops_apply_with_schema_checks() {
+        let mut doc = MINIMAL_CONFIG
+            .parse::<toml_edit::DocumentMut>()
+            .expect("the minimal config is valid TOML");
+
+        let ops: Vec<PatchOp> = serde_json::from_str(
+            r#"[
+                {"op": "replace", "path": "/sync/timeout_ms", "value": 5000},
+                {"op": "add", "path": "/discovery/mdns", "value": true},
+                {"op": "remove", "path": "/blobstore/path"}
+            ]"#,
+        )
+        .expect("a well-formed patch parses");
+
+        for op in &ops {
+            drop(ConfigCommand::apply_patch_op(&mut doc, op).expect("each op must apply"));
+        }
+
+        assert_eq!(doc["sync"]["timeout_ms"].as_integer(), Some(5000));
+        assert_eq!(doc["discovery"]["mdns"].as_bool(), Some(true));
+        assert!(doc["blobstore"].get("path").is_none());
+
+        // `replace` insists the key already exists.
+        let op: PatchOp =
+            serde_json::from_str(r#"{"op": "replace", "path": "/sync/missing", "value": 1}"#)
+                .expect("well-formed");
+
+        assert!(ConfigCommand::apply_patch_op(&mut doc, &op).is_err());
+
+        // Patched values go through the same schema checks as edits.
+        let op: PatchOp =
+            serde_json::from_str(r#"{"op": "add", "path": "/sync/timeout_ms", "value": "soon"}"#)
+                .expect("well-formed");
+
+        assert!(ConfigCommand::apply_patch_op(&mut doc, &op).is_err());
+    }
+
+    #[test]
+    fn get_value_checks_requested_and_schema_types() {
+        let doc = MINIMAL_CONFIG
+            .parse::<toml_edit::DocumentMut>()
+            .expect("the minimal config is valid TOML");
+
+        assert_eq!(
+            ConfigCommand::get_value(&doc, "sync.timeout_ms", Some(GetType::U64))
+                .expect("timeout_ms is a u64"),
+            "30000"
+        );
+
+        // Strings come back unquoted, for pipelines.
+        assert_eq!(
+            ConfigCommand::get_value(&doc, "datastore.path", None).expect("path is set"),
+            "data"
+        );
+
+        assert!(ConfigCommand::get_value(&doc, "sync.timeout_ms", Some(GetType::Bool)).is_err());
+        assert!(ConfigCommand::get_value(&doc, "sync", None).is_err());
+        assert!(ConfigCommand::get_value(&doc, "sync.missing", None).is_err());
+    }
+
+    #[test]
+    fn type_tags_override_value_inference() {
+        let kv: KeyValuePair = "discovery.mdns:bool=true".parse().expect("a tagged bool");
+
+        assert_eq!(kv.key, "discovery.mdns");
+        assert!(kv.value.is_bool());
+
+        // Without the tag this would infer an integer.
+        let kv: KeyValuePair = "datastore.path:string=5000".parse().expect("a tagged string");
+
+        assert_eq!(kv.value.as_str(), Some("5000"));
+
+        // The value must actually be of the tagged type.
+        assert!("sync.timeout_ms:bool=5000".parse::<KeyValuePair>().is_err());
+
+        // Unrecognized tags are not tags; the `:` stays in the key.
+        let kv: KeyValuePair = "a:b=1".parse().expect("`:b` is not a type tag");
+
+        assert_eq!(kv.key, "a:b");
+    }
+
+    #[test]
+    fn inline_table_values_parse() {
+        let kv: KeyValuePair = "discovery.relay={ enabled = true, registrations_limit = 10 }"
+            .parse()
+            .expect("inline tables are valid TOML values");
+
+        let table = kv.value.as_inline_table().expect("must parse as a table");
+
+        assert!(table.get("enabled").is_some_and(Value::is_bool));
+        assert!(table.get("registrations_limit").is_some_and(Value::is_integer));
+    }
+
+    #[test]
+    fn nested_inline_tables_parse() {
+        let kv: KeyValuePair = "context.config={ near = { signer = 'self' } }"
+            .parse()
+            .expect("nested inline tables are valid TOML values");
+
+        let nested = kv
+            .value
+            .as_inline_table()
+            .and_then(|table| table.get("near"))
+            .and_then(Value::as_inline_table)
+            .expect("nested table must survive parsing");
+
+        assert_eq!(nested.get("signer").and_then(Value::as_str), Some("self"));
+    }
+
+    #[test]
+    fn trailing_comments_split_off() {
+        let kv: KeyValuePair = "sync.timeout_ms=5000 # bumped for slow link"
+            .parse()
+            .expect("a trailing comment is not part of the value");
+
+        assert_eq!(kv.value.as_integer(), Some(5000));
+        assert_eq!(kv.comment.as_deref(), Some("bumped for slow link"));
+
+        let kv: KeyValuePair = "datastore.path='da#ta'".parse().expect("valid");
+
+        assert_eq!(kv.value.as_str(), Some("da#ta"));
+        assert_eq!(kv.comment, None);
+    }
+
+    #[test]
+    fn canonical_form_sorts_and_normalizes_spacing() {
+        let doc = "[b]\nz=1\n  a =  2   # keep\n\n\n[a]\n# why\nk='v'\n"
+            .parse()
+            .expect("valid TOML");
+
+        let canonical = ConfigCommand::canonicalize(&doc);
+
+        // Tables and keys are sorted, spacing is `key = value`, and
+        // comments survive.
+        assert!(canonical.find("[a]") < canonical.find("[b]"));
+        assert!(canonical.find("a = 2") < canonical.find("z = 1"));
+        assert!(canonical.contains("a = 2 # keep"));
+        assert!(canonical.contains("# why\nk = 'v'"));
+
+        // Canonical output must be a fixed point, or `--check` would
+        // never pass.
+        let reparsed = canonical.parse().expect("canonical form is valid TOML");
+
+        assert_eq!(ConfigCommand::canonicalize(&reparsed), canonical);
+    }
+
+    #[test]
+    fn unset_removes_leaves_and_skips_absent_keys() {
+        let mut doc = MINIMAL_CONFIG
+            .parse::<toml_edit::DocumentMut>()
+            .expect("the minimal config is valid TOML");
+
+        let entry = ConfigCommand::apply_unset_key(&mut doc, "sync.timeout_ms")
+            .expect("a set leaf can be removed")
+            .expect("the removal is recorded");
+
+        assert_eq!(entry.old.as_deref(), Some("30000"));
+        assert!(doc["sync"].get("timeout_ms").is_none());
+
+        // Absent keys - removed already, or under a missing parent - are
+        // no-ops, not errors.
+        assert!(ConfigCommand::apply_unset_key(&mut doc, "sync.timeout_ms")
+            .expect("removing an absent key is a no-op")
+            .is_none());
+        assert!(ConfigCommand::apply_unset_key(&mut doc, "discovery.mdns")
+            .expect("a missing parent is a no-op too")
+            .is_none());
+
+        // Sections go through --unset-section, not --unset.
+        assert!(ConfigCommand::apply_unset_key(&mut doc, "sync").is_err());
+    }
+
+    #[test]
+    fn human_print_indexes_array_elements() {
+        let doc: toml_edit::DocumentMut =
+            "[swarm]\nlisten = ['/ip4/0.0.0.0/tcp/1', '/ip4/0.0.0.0/tcp/2']\n\n[[peers]]\nname = 'a'\n"
+                .parse()
+                .expect("valid TOML");
+
+        let mut lines = Vec::new();
+
+        ConfigCommand::add_to_table("", doc.as_item(), &mut lines);
+
+        assert!(lines.contains(&"swarm.listen[0] = '/ip4/0.0.0.0/tcp/1'".to_owned()));
+        assert!(lines.contains(&"swarm.listen[1] = '/ip4/0.0.0.0/tcp/2'".to_owned()));
+        assert!(lines.contains(&"peers[0].name = 'a'".to_owned()));
     }
 }
@@ -0,0 +1,62 @@
+use camino::Utf8Path;
+use chrono::{DateTime, Utc};
+use eyre::Result as EyreResult;
+use serde::{Deserialize, Serialize};
+use tokio::fs::{read_to_string, write};
+
+/// Append-only record of config edits, one JSON entry per line, kept next to
+/// the config file in the node home.
+pub const JOURNAL_FILE: &str = "config.journal";
+
+/// A single applied config edit.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct JournalEntry {
+    pub at: DateTime<Utc>,
+    pub key: String,
+    /// The previous value, if the key existed.
+    pub old: Option<String>,
+    pub new: String,
+}
+
+impl JournalEntry {
+    pub fn new(key: &str, old: Option<String>, new: String) -> Self {
+        Self {
+            at: Utc::now(),
+            key: key.to_owned(),
+            old,
+            new,
+        }
+    }
+}
+
+/// Appends the given entries to the journal, creating it if absent.
+pub async fn append(dir: &Utf8Path, entries: &[JournalEntry]) -> EyreResult<()> {
+    if entries.is_empty() {
+        return Ok(());
+    }
+
+    let path = dir.join(JOURNAL_FILE);
+
+    let mut contents = read_to_string(&path).await.unwrap_or_default();
+
+    for entry in entries {
+        contents.push_str(&serde_json::to_string(entry)?);
+        contents.push('\n');
+    }
+
+    write(&path, contents).await?;
+
+    Ok(())
+}
+
+/// Reads the journal back, oldest entry first.
+pub async fn read(dir: &Utf8Path) -> EyreResult<Vec<JournalEntry>> {
+    let path = dir.join(JOURNAL_FILE);
+
+    let contents = read_to_string(&path).await.unwrap_or_default();
+
+    contents
+        .lines()
+        .map(|line| serde_json::from_str(line).map_err(Into::into))
+        .collect()
+}
@@ -0,0 +1,1102 @@
+use core::fmt::{self, Formatter};
+use core::time::Duration;
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+
+use calimero_config::{
+    BlobStoreConfig, ConfigFile, DataStoreConfig, NetworkConfig, ServerConfig, SyncConfig,
+};
+use calimero_context::config::ContextConfig;
+use calimero_context_config::client::config::{
+    ClientConfig, ClientRelayerSigner, ClientSigner, LocalConfig,
+};
+use calimero_network::config::{BootstrapConfig, DiscoveryConfig, SwarmConfig};
+use toml_edit::Value;
+
+/// Description of a single node in the configuration schema.
+#[derive(Debug)]
+pub enum SchemaNode {
+    /// A table of nested keys.
+    Object {
+        description: &'static str,
+        children: BTreeMap<String, SchemaNode>,
+    },
+    /// A settable value.
+    Leaf {
+        description: &'static str,
+        ty: SchemaType,
+        /// Whether the config fails to load without this key.
+        required: bool,
+        /// Whether the value is a secret that printed output should mask.
+        sensitive: bool,
+        /// Whether the key is prominent enough for the one-line summary.
+        summary: bool,
+    },
+}
+
+/// The TOML value type a leaf expects.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SchemaType {
+    Bool,
+    Integer,
+    /// An integer within an inclusive range.
+    IntegerRange(i64, i64),
+    Float,
+    /// A float within an inclusive range.
+    FloatRange(f64, f64),
+    String,
+    /// A string drawn from a fixed set of options.
+    Enum(&'static [&'static str]),
+    /// A TOML datetime, e.g. `2024-01-01T00:00:00Z`.
+    Datetime,
+    Array,
+}
+
+impl fmt::Display for SchemaType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool => f.write_str("boolean"),
+            Self::Integer => f.write_str("integer"),
+            Self::IntegerRange(min, max) => write!(f, "integer in {min}..={max}"),
+            Self::Float => f.write_str("float"),
+            Self::FloatRange(min, max) => write!(f, "float in {min}..={max}"),
+            Self::String => f.write_str("string"),
+            Self::Enum(options) => write!(f, "one of [{}]", options.join(", ")),
+            Self::Datetime => f.write_str("datetime"),
+            Self::Array => f.write_str("array"),
+        }
+    }
+}
+
+impl SchemaType {
+    pub fn matches(self, value: &Value) -> bool {
+        match self {
+            Self::Bool => value.is_bool(),
+            Self::Integer => value.is_integer(),
+            Self::IntegerRange(min, max) => value
+                .as_integer()
+                .is_some_and(|value| (min..=max).contains(&value)),
+            Self::Float => value.is_float() || value.is_integer(),
+            Self::FloatRange(min, max) => value
+                .as_float()
+                .or_else(|| value.as_integer().map(|value| value as f64))
+                .is_some_and(|value| (min..=max).contains(&value)),
+            Self::String => value.is_str(),
+            Self::Enum(options) => value
+                .as_str()
+                .is_some_and(|value| options.contains(&value)),
+            Self::Datetime => value.is_datetime(),
+            Self::Array => value.is_array(),
+        }
+    }
+}
+
+impl SchemaNode {
+    fn object<const N: usize>(
+        description: &'static str,
+        children: [(&'static str, SchemaNode); N],
+    ) -> Self {
+        Self::Object {
+            description,
+            children: children
+                .into_iter()
+                .map(|(name, node)| (name.to_owned(), node))
+                .collect(),
+        }
+    }
+
+    const fn leaf(description: &'static str, ty: SchemaType) -> Self {
+        Self::Leaf {
+            description,
+            ty,
+            required: false,
+            sensitive: false,
+            summary: false,
+        }
+    }
+
+    const fn required_leaf(description: &'static str, ty: SchemaType) -> Self {
+        Self::Leaf {
+            description,
+            ty,
+            required: true,
+            sensitive: false,
+            summary: false,
+        }
+    }
+
+    const fn sensitive_leaf(description: &'static str, ty: SchemaType) -> Self {
+        Self::Leaf {
+            description,
+            ty,
+            required: false,
+            sensitive: true,
+            summary: false,
+        }
+    }
+
+    /// Flags the leaf for inclusion in the one-line config summary.
+    const fn summarized(self) -> Self {
+        match self {
+            Self::Leaf {
+                description,
+                ty,
+                required,
+                sensitive,
+                ..
+            } => Self::Leaf {
+                description,
+                ty,
+                required,
+                sensitive,
+                summary: true,
+            },
+            node @ Self::Object { .. } => node,
+        }
+    }
+
+    /// Whether printed output should mask this node's value.
+    pub const fn is_sensitive(&self) -> bool {
+        matches!(
+            self,
+            Self::Leaf {
+                sensitive: true,
+                ..
+            }
+        )
+    }
+
+    /// Walks the schema along a dotted key, returning the node it denotes.
+    pub fn lookup(&self, key: &str) -> Option<&Self> {
+        let mut current = self;
+
+        for part in key.split('.') {
+            let Self::Object { children, .. } = current else {
+                return None;
+            };
+
+            // `*` matches any one segment, e.g. a protocol name.
+            current = children.get(part).or_else(|| children.get("*"))?;
+        }
+
+        Some(current)
+    }
+
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::Object { description, .. } | Self::Leaf { description, .. } => description,
+        }
+    }
+
+    /// Whether this subtree contains any required leaf.
+    pub fn has_required(&self) -> bool {
+        match self {
+            Self::Leaf { required, .. } => *required,
+            Self::Object { children, .. } => children.values().any(Self::has_required),
+        }
+    }
+
+    /// Walks the document alongside the schema, collecting required keys the
+    /// document doesn't set, with the type each one expects.
+    pub fn missing_required(&self, doc: &toml_edit::DocumentMut) -> Vec<(String, SchemaType)> {
+        fn walk(
+            node: &SchemaNode,
+            prefix: &str,
+            item: Option<&toml_edit::Item>,
+            out: &mut Vec<(String, SchemaType)>,
+        ) {
+            match node {
+                SchemaNode::Leaf {
+                    ty, required: true, ..
+                } => {
+                    if item.is_none() {
+                        out.push((prefix.to_owned(), *ty));
+                    }
+                }
+                SchemaNode::Leaf { .. } => {}
+                SchemaNode::Object { children, .. } => {
+                    for (name, child) in children {
+                        if *name == "*" {
+                            continue;
+                        }
+
+                        let key = if prefix.is_empty() {
+                            name.clone()
+                        } else {
+                            format!("{prefix}.{name}")
+                        };
+
+                        let child_item = item
+                            .and_then(toml_edit::Item::as_table_like)
+                            .and_then(|table| table.get(name));
+
+                        walk(child, &key, child_item, out);
+                    }
+                }
+            }
+        }
+
+        let mut out = Vec::new();
+
+        walk(self, "", Some(doc.as_item()), &mut out);
+
+        out
+    }
+
+    /// Flattens the schema into sorted dotted keys, one per settable leaf.
+    ///
+    /// Shell completion scripts consume this via `merod config --complete-keys`.
+    pub fn flat_keys(&self) -> Vec<String> {
+        self.flat_leaves()
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect()
+    }
+
+    /// Flattens the schema into the sorted dotted keys flagged as
+    /// summary-worthy, for the one-line `--print summary` overview.
+    pub fn summary_keys(&self) -> Vec<String> {
+        fn walk(node: &SchemaNode, prefix: &str, out: &mut Vec<String>) {
+            match node {
+                SchemaNode::Leaf { summary: true, .. } => out.push(prefix.to_owned()),
+                SchemaNode::Leaf { .. } => {}
+                SchemaNode::Object { children, .. } => {
+                    for (name, child) in children {
+                        let key = if prefix.is_empty() {
+                            name.clone()
+                        } else {
+                            format!("{prefix}.{name}")
+                        };
+
+                        walk(child, &key, out);
+                    }
+                }
+            }
+        }
+
+        let mut out = Vec::new();
+
+        walk(self, "", &mut out);
+
+        out.sort();
+
+        out
+    }
+
+    /// Flattens the schema into sorted `(dotted key, type)` pairs, one per
+    /// settable leaf. [`Self::flat_keys`] and `merod config keys` are both
+    /// views over this.
+    pub fn flat_leaves(&self) -> Vec<(String, SchemaType)> {
+        fn walk(node: &SchemaNode, prefix: &str, out: &mut Vec<(String, SchemaType)>) {
+            match node {
+                SchemaNode::Leaf { ty, .. } => out.push((prefix.to_owned(), *ty)),
+                SchemaNode::Object { children, .. } => {
+                    for (name, child) in children {
+                        let key = if prefix.is_empty() {
+                            name.clone()
+                        } else {
+                            format!("{prefix}.{name}")
+                        };
+
+                        walk(child, &key, out);
+                    }
+                }
+            }
+        }
+
+        let mut out = Vec::new();
+
+        walk(self, "", &mut out);
+
+        out.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        out
+    }
+
+    /// Walks the whole schema looking for definition bugs - empty
+    /// descriptions, inverted ranges, degenerate enums - and returns one
+    /// message per problem. The annotations are maintained by hand; this
+    /// is the guard rail that keeps typos from failing silently at
+    /// runtime.
+    pub fn self_check(&self) -> Vec<String> {
+        fn walk(node: &SchemaNode, prefix: &str, out: &mut Vec<String>) {
+            let name = if prefix.is_empty() { "(root)" } else { prefix };
+
+            if node.description().is_empty() {
+                out.push(format!("`{name}` has an empty description"));
+            }
+
+            match node {
+                SchemaNode::Leaf { ty, .. } => match *ty {
+                    SchemaType::IntegerRange(min, max) if min > max => {
+                        out.push(format!("`{name}` has an inverted range {min}..={max}"));
+                    }
+                    SchemaType::FloatRange(min, max) if min > max => {
+                        out.push(format!("`{name}` has an inverted range {min}..={max}"));
+                    }
+                    SchemaType::Enum(options) => {
+                        if options.is_empty() {
+                            out.push(format!("`{name}` is an enum with no options"));
+                        }
+
+                        for (index, option) in options.iter().enumerate() {
+                            if option.is_empty() {
+                                out.push(format!("`{name}` has an empty enum option"));
+                            }
+
+                            if options[..index].contains(option) {
+                                out.push(format!("`{name}` repeats enum option `{option}`"));
+                            }
+                        }
+                    }
+                    _ => {}
+                },
+                SchemaNode::Object { children, .. } => {
+                    for (child_name, child) in children {
+                        if child_name.is_empty() {
+                            out.push(format!("`{name}` has a child with an empty name"));
+                        }
+
+                        let key = if prefix.is_empty() {
+                            child_name.clone()
+                        } else {
+                            format!("{prefix}.{child_name}")
+                        };
+
+                        walk(child, &key, out);
+                    }
+                }
+            }
+        }
+
+        let mut out = Vec::new();
+
+        walk(self, "", &mut out);
+
+        out
+    }
+
+    /// Renders this node as JSON, carrying descriptions in `$comment` fields
+    /// the way JSON Schema does, since JSON proper has no comments.
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::Leaf {
+                description,
+                ty,
+                required,
+                sensitive,
+                summary,
+            } => serde_json::json!({
+                "$comment": description,
+                "type": ty.to_string(),
+                "required": required,
+                "sensitive": sensitive,
+                "summary": summary,
+            }),
+            Self::Object {
+                description,
+                children,
+            } => {
+                let mut map = serde_json::Map::new();
+
+                let _ = map.insert(
+                    "$comment".to_owned(),
+                    serde_json::Value::String((*description).to_owned()),
+                );
+
+                for (name, child) in children {
+                    let _ = map.insert(name.clone(), child.to_json());
+                }
+
+                serde_json::Value::Object(map)
+            }
+        }
+    }
+
+    /// Renders this node as CSV rows of `key,type,required,description`,
+    /// one per leaf, for tooling that ingests hints.
+    pub fn to_csv(&self, key: &str) -> Vec<String> {
+        fn quote(field: &str) -> String {
+            if field.contains([',', '"']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_owned()
+            }
+        }
+
+        fn walk(node: &SchemaNode, key: &str, out: &mut Vec<String>) {
+            match node {
+                SchemaNode::Leaf {
+                    description,
+                    ty,
+                    required,
+                    ..
+                } => out.push(format!(
+                    "{},{},{required},{}",
+                    quote(key),
+                    quote(&ty.to_string()),
+                    quote(description)
+                )),
+                SchemaNode::Object { children, .. } => {
+                    for (name, child) in children {
+                        walk(child, &format!("{key}.{name}"), out);
+                    }
+                }
+            }
+        }
+
+        let mut out = vec!["key,type,required,description".to_owned()];
+
+        walk(self, key, &mut out);
+
+        out
+    }
+
+    /// Renders this node and its children as an indented, human-readable tree.
+    pub fn print_human(&self, key: &str, indent: usize) {
+        self.print_human_depth(key, indent, None);
+    }
+
+    /// As [`Self::print_human`], but expanding at most `depth` levels below
+    /// this node; `None` is unbounded. Subtrees past the limit collapse
+    /// into a one-line summary.
+    pub fn print_human_depth(&self, key: &str, indent: usize, depth: Option<usize>) {
+        let pad = "  ".repeat(indent);
+
+        match self {
+            Self::Leaf {
+                description, ty, ..
+            } => {
+                println!("{pad}{key}: {ty} - {description}");
+            }
+            Self::Object {
+                description,
+                children,
+            } => {
+                println!("{pad}{key}: {description}");
+
+                if depth == Some(0) {
+                    println!(
+                        "{pad}  ... {} nested keys; raise --depth to expand",
+                        children.len()
+                    );
+
+                    return;
+                }
+
+                let next = depth.map(|depth| depth - 1);
+
+                // Render an enable switch first, with the keys it gates
+                // indented beneath it.
+                let enabled = children.get("enabled");
+
+                let gated = usize::from(enabled.is_some());
+
+                if let Some(node) = enabled {
+                    node.print_human_depth("enabled", indent + 1, next);
+                }
+
+                for (name, node) in children {
+                    if *name == "enabled" {
+                        continue;
+                    }
+
+                    node.print_human_depth(name, indent + 1 + gated, next);
+                }
+            }
+        }
+    }
+}
+
+/// Schema for the keys `merod config` knows how to edit and describe.
+///
+/// The tree's *structure* is derived from [`ConfigFile`] itself: a
+/// reference instance is serialized and walked, so a field added to the
+/// config surfaces here automatically, with its type inferred. The
+/// hand-written [`annotations`] overlay contributes what the struct
+/// can't express - descriptions, ranges, enums, required and sensitive
+/// flags, wildcard segments - and underived keys fall back to a
+/// placeholder description.
+pub static CONFIG_SCHEMA: LazyLock<SchemaNode> = LazyLock::new(|| {
+    let reference =
+        serde_json::to_value(reference_config()).expect("the reference config must serialize");
+
+    derive_node(&reference, Some(annotations()))
+});
+
+/// A representative [`ConfigFile`] whose serialized form drives the
+/// schema's structure. The values are throwaway; only the shape matters.
+fn reference_config() -> ConfigFile {
+    ConfigFile::new(
+        libp2p::identity::Keypair::generate_ed25519(),
+        NetworkConfig::new(
+            SwarmConfig::new(vec![]),
+            BootstrapConfig::default(),
+            DiscoveryConfig::default(),
+            ServerConfig::new(vec![], None, None, None),
+        ),
+        SyncConfig {
+            timeout: Duration::from_secs(30),
+            interval: Duration::from_secs(30),
+        },
+        DataStoreConfig::new("data".into()),
+        BlobStoreConfig::new("blobs".into()),
+        ContextConfig {
+            client: ClientConfig {
+                params: BTreeMap::default(),
+                signer: ClientSigner {
+                    relayer: ClientRelayerSigner {
+                        url: "http://127.0.0.1:63529"
+                            .parse()
+                            .expect("the reference relayer URL is valid"),
+                    },
+                    local: LocalConfig {
+                        protocols: BTreeMap::default(),
+                    },
+                },
+            },
+        },
+    )
+}
+
+/// Hint for keys the annotations don't describe yet; the key still
+/// resolves, with its type inferred from the struct.
+const UNDOCUMENTED_LEAF: &str = "settable key; no description written yet";
+const UNDOCUMENTED_SECTION: &str = "configuration section; no description written yet";
+
+/// Builds the schema node for `value`, a piece of the serialized
+/// reference config, taking descriptions, ranges and flags from the
+/// matching annotation node where one exists. Annotated children the
+/// reference doesn't serialize - wildcard segments, optional keys - are
+/// kept, so the result is a superset of both trees.
+fn derive_node(value: &serde_json::Value, annotation: Option<SchemaNode>) -> SchemaNode {
+    let serde_json::Value::Object(map) = value else {
+        // A leaf: the annotation wins outright when it agrees this is a
+        // leaf, since it carries ranges and enums inference can't see.
+        if let Some(node @ SchemaNode::Leaf { .. }) = annotation {
+            return node;
+        }
+
+        return SchemaNode::leaf(UNDOCUMENTED_LEAF, infer_type(value));
+    };
+
+    let (description, mut curated) = match annotation {
+        Some(SchemaNode::Object {
+            description,
+            children,
+        }) => (description, children),
+        _ => (UNDOCUMENTED_SECTION, BTreeMap::new()),
+    };
+
+    let mut children = BTreeMap::new();
+
+    for (name, child) in map {
+        let curated_child = curated.remove(name.as_str());
+
+        // Optional keys the reference leaves unset carry no structure to
+        // derive from; they appear only when annotated.
+        if child.is_null() {
+            if let Some(node) = curated_child {
+                let _ = children.insert(name.clone(), node);
+            }
+
+            continue;
+        }
+
+        let _ = children.insert(name.clone(), derive_node(child, curated_child));
+    }
+
+    // Whatever the reference didn't mention: wildcard segments and
+    // annotated optional keys.
+    children.append(&mut curated);
+
+    SchemaNode::Object {
+        description,
+        children,
+    }
+}
+
+/// The schema type a serialized scalar or array implies.
+fn infer_type(value: &serde_json::Value) -> SchemaType {
+    match value {
+        serde_json::Value::Bool(_) => SchemaType::Bool,
+        serde_json::Value::Number(number) if number.is_f64() => SchemaType::Float,
+        serde_json::Value::Number(_) => SchemaType::Integer,
+        serde_json::Value::String(_) => SchemaType::String,
+        serde_json::Value::Array(_) => SchemaType::Array,
+        serde_json::Value::Null | serde_json::Value::Object(_) => {
+            unreachable!("the caller only infers scalar and array values")
+        }
+    }
+}
+
+/// The hand-maintained half of the schema: descriptions, value ranges,
+/// required/sensitive/summary flags and wildcard segments, overlaid on
+/// the structure derived from [`ConfigFile`].
+fn annotations() -> SchemaNode {
+    SchemaNode::object(
+        "node configuration",
+        [
+            (
+                "identity",
+                SchemaNode::object(
+                    "node identity",
+                    [
+                        (
+                            "peer_id",
+                            SchemaNode::leaf(
+                                "peer id derived from the keypair",
+                                SchemaType::String,
+                            ),
+                        ),
+                        (
+                            "keypair",
+                            SchemaNode::sensitive_leaf(
+                                "base58-encoded protobuf keypair",
+                                SchemaType::String,
+                            ),
+                        ),
+                    ],
+                ),
+            ),
+            (
+                "swarm",
+                SchemaNode::object(
+                    "libp2p swarm",
+                    [(
+                        "listen",
+                        SchemaNode::required_leaf(
+                            "multiaddrs the swarm listens on",
+                            SchemaType::Array,
+                        ),
+                    )],
+                ),
+            ),
+            (
+                "server",
+                SchemaNode::object(
+                    "client-facing server",
+                    [
+                        (
+                            "listen",
+                            SchemaNode::required_leaf(
+                                "multiaddrs the server listens on",
+                                SchemaType::Array,
+                            ),
+                        ),
+                        (
+                            "admin",
+                            SchemaNode::object(
+                                "admin API",
+                                [(
+                                    "enabled",
+                                    SchemaNode::leaf(
+                                        "whether the admin API is served",
+                                        SchemaType::Bool,
+                                    )
+                                    .summarized(),
+                                )],
+                            ),
+                        ),
+                        (
+                            "jsonrpc",
+                            SchemaNode::object(
+                                "JSON-RPC API",
+                                [(
+                                    "enabled",
+                                    SchemaNode::leaf(
+                                        "whether the JSON-RPC API is served",
+                                        SchemaType::Bool,
+                                    )
+                                    .summarized(),
+                                )],
+                            ),
+                        ),
+                        (
+                            "websocket",
+                            SchemaNode::object(
+                                "WebSocket API",
+                                [(
+                                    "enabled",
+                                    SchemaNode::leaf(
+                                        "whether the WebSocket API is served",
+                                        SchemaType::Bool,
+                                    ),
+                                )],
+                            ),
+                        ),
+                    ],
+                ),
+            ),
+            (
+                "bootstrap",
+                SchemaNode::object(
+                    "bootstrap peers",
+                    [(
+                        "nodes",
+                        SchemaNode::leaf(
+                            "multiaddrs of peers to bootstrap from",
+                            SchemaType::Array,
+                        ),
+                    )],
+                ),
+            ),
+            (
+                "sync",
+                SchemaNode::object(
+                    "state synchronization",
+                    [
+                        (
+                            "timeout_ms",
+                            SchemaNode::required_leaf(
+                                "timeout for a sync round, in milliseconds",
+                                SchemaType::IntegerRange(0, i64::MAX),
+                            )
+                            .summarized(),
+                        ),
+                        (
+                            "interval_ms",
+                            SchemaNode::required_leaf(
+                                "interval between sync rounds, in milliseconds",
+                                SchemaType::IntegerRange(0, i64::MAX),
+                            )
+                            .summarized(),
+                        ),
+                    ],
+                ),
+            ),
+            (
+                "discovery",
+                SchemaNode::object(
+                    "peer discovery",
+                    [
+                        (
+                            "mdns",
+                            SchemaNode::leaf(
+                                "discover peers on the local network via mDNS",
+                                SchemaType::Bool,
+                            )
+                            .summarized(),
+                        ),
+                        (
+                            "advertise_address",
+                            SchemaNode::leaf(
+                                "advertise observed addresses to discovered peers",
+                                SchemaType::Bool,
+                            ),
+                        ),
+                        (
+                            "rendezvous",
+                            SchemaNode::object(
+                                "rendezvous discovery",
+                                [
+                                    (
+                                        "namespace",
+                                        SchemaNode::leaf(
+                                            "namespace to register under",
+                                            SchemaType::String,
+                                        ),
+                                    ),
+                                    (
+                                        "discovery_rpm",
+                                        SchemaNode::leaf(
+                                            "discovery requests per minute",
+                                            SchemaType::FloatRange(0.0, 600.0),
+                                        ),
+                                    ),
+                                    (
+                                        "discovery_interval",
+                                        SchemaNode::object(
+                                            "interval between discovery requests",
+                                            [
+                                                (
+                                                    "secs",
+                                                    SchemaNode::leaf(
+                                                        "whole seconds",
+                                                        SchemaType::IntegerRange(0, i64::MAX),
+                                                    ),
+                                                ),
+                                                (
+                                                    "nanos",
+                                                    SchemaNode::leaf(
+                                                        "subsecond nanoseconds",
+                                                        SchemaType::IntegerRange(0, 999_999_999),
+                                                    ),
+                                                ),
+                                            ],
+                                        ),
+                                    ),
+                                    (
+                                        "registrations_limit",
+                                        SchemaNode::leaf(
+                                            "maximum concurrent registrations",
+                                            SchemaType::IntegerRange(0, 4096),
+                                        ),
+                                    ),
+                                ],
+                            ),
+                        ),
+                        (
+                            "relay",
+                            SchemaNode::object(
+                                "relay discovery",
+                                [
+                                    (
+                                        "enabled",
+                                        SchemaNode::leaf(
+                                            "whether to register with relay peers",
+                                            SchemaType::Bool,
+                                        )
+                                        .summarized(),
+                                    ),
+                                    (
+                                        "registrations_limit",
+                                        SchemaNode::leaf(
+                                            "maximum concurrent relay registrations",
+                                            SchemaType::IntegerRange(0, 4096),
+                                        ),
+                                    ),
+                                ],
+                            ),
+                        ),
+                        (
+                            "autonat",
+                            SchemaNode::object(
+                                "NAT status detection",
+                                [(
+                                    "confidence_threshold",
+                                    SchemaNode::leaf(
+                                        "probes required to conclude NAT status",
+                                        SchemaType::IntegerRange(1, 64),
+                                    ),
+                                )],
+                            ),
+                        ),
+                    ],
+                ),
+            ),
+            (
+                "datastore",
+                SchemaNode::object(
+                    "key-value store",
+                    [(
+                        "path",
+                        SchemaNode::required_leaf(
+                            "path to the data store, relative to the node home",
+                            SchemaType::String,
+                        ),
+                    )],
+                ),
+            ),
+            (
+                "blobstore",
+                SchemaNode::object(
+                    "blob store",
+                    [(
+                        "path",
+                        SchemaNode::required_leaf(
+                            "path to the blob store, relative to the node home",
+                            SchemaType::String,
+                        ),
+                    )],
+                ),
+            ),
+            (
+                "context",
+                SchemaNode::object(
+                    "context client",
+                    [(
+                        "config",
+                        SchemaNode::object(
+                            "per-protocol client parameters and signers",
+                            [
+                                (
+                                    "signer",
+                                    SchemaNode::object(
+                                        "signer endpoints",
+                                        [
+                                            (
+                                                "relayer",
+                                                SchemaNode::object(
+                                                    "relayer signer",
+                                                    [(
+                                                        "url",
+                                                        SchemaNode::leaf(
+                                                            "relayer endpoint the client signs through",
+                                                            SchemaType::String,
+                                                        ),
+                                                    )],
+                                                ),
+                                            ),
+                                            (
+                                                "self",
+                                                SchemaNode::object(
+                                                    "local signers, per protocol and network",
+                                                    [(
+                                                        "*",
+                                                        SchemaNode::object(
+                                                            "local signers for a protocol",
+                                                            [(
+                                                                "*",
+                                                                SchemaNode::object(
+                                                                    "signer credentials for a network",
+                                                                    [
+                                                                        (
+                                                                            "rpc_url",
+                                                                            SchemaNode::leaf(
+                                                                                "RPC endpoint the signer submits to",
+                                                                                SchemaType::String,
+                                                                            ),
+                                                                        ),
+                                                                        (
+                                                                            "account_id",
+                                                                            SchemaNode::leaf(
+                                                                                "account the signer acts as",
+                                                                                SchemaType::String,
+                                                                            ),
+                                                                        ),
+                                                                        (
+                                                                            "public_key",
+                                                                            SchemaNode::leaf(
+                                                                                "public half of the signing key",
+                                                                                SchemaType::String,
+                                                                            ),
+                                                                        ),
+                                                                        (
+                                                                            "secret_key",
+                                                                            SchemaNode::sensitive_leaf(
+                                                                                "private signing key",
+                                                                                SchemaType::String,
+                                                                            ),
+                                                                        ),
+                                                                    ],
+                                                                ),
+                                                            )],
+                                                        ),
+                                                    )],
+                                                ),
+                                            ),
+                                        ],
+                                    ),
+                                ),
+                                (
+                                    "*",
+                                    SchemaNode::object(
+                                        "client parameters for a protocol",
+                                        [
+                                            (
+                                                "signer",
+                                                SchemaNode::leaf(
+                                                    "which signer the client uses",
+                                                    SchemaType::Enum(&["relayer", "self"]),
+                                                ),
+                                            ),
+                                            (
+                                                "network",
+                                                SchemaNode::leaf(
+                                                    "network the client talks to",
+                                                    SchemaType::String,
+                                                ),
+                                            ),
+                                            (
+                                                "contract_id",
+                                                SchemaNode::leaf(
+                                                    "context contract the client targets",
+                                                    SchemaType::String,
+                                                ),
+                                            ),
+                                        ],
+                                    ),
+                                ),
+                            ],
+                        ),
+                    )],
+                ),
+            ),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schema_covers_every_config_file_key() {
+        fn check(value: &serde_json::Value, prefix: &str) {
+            let node = CONFIG_SCHEMA.lookup(prefix);
+
+            assert!(
+                node.is_some(),
+                "`{prefix}` is a ConfigFile key with no schema node"
+            );
+
+            if let serde_json::Value::Object(map) = value {
+                for (name, child) in map {
+                    if child.is_null() {
+                        continue;
+                    }
+
+                    check(child, &format!("{prefix}.{name}"));
+                }
+            }
+        }
+
+        let serialized =
+            serde_json::to_value(reference_config()).expect("the config must serialize");
+
+        let top_level = serialized
+            .as_object()
+            .expect("the config must serialize to a map");
+
+        // Every key of the real struct resolves, all the way down - the
+        // structure is derived, so a new field can't go missing.
+        for (name, child) in top_level {
+            if child.is_null() {
+                continue;
+            }
+
+            check(child, name);
+        }
+    }
+
+    #[test]
+    fn ranges_and_enums_validate() {
+        use std::str::FromStr;
+
+        let matches = |ty: SchemaType, raw: &str| ty.matches(&Value::from_str(raw).unwrap());
+
+        assert!(matches(SchemaType::IntegerRange(0, 10), "10"));
+        assert!(!matches(SchemaType::IntegerRange(0, 10), "11"));
+        assert!(!matches(SchemaType::IntegerRange(0, 10), "-1"));
+
+        assert!(matches(SchemaType::FloatRange(0.0, 1.0), "0.5"));
+        assert!(matches(SchemaType::FloatRange(0.0, 1.0), "1"));
+        assert!(!matches(SchemaType::FloatRange(0.0, 1.0), "1.5"));
+
+        let signer = SchemaType::Enum(&["relayer", "self"]);
+        assert!(matches(signer, "'relayer'"));
+        assert!(!matches(signer, "'remote'"));
+
+        assert!(matches(SchemaType::Datetime, "2024-01-01T00:00:00Z"));
+        assert!(!matches(SchemaType::Datetime, "'2024-01-01T00:00:00Z'"));
+    }
+
+    #[test]
+    fn wildcard_segments_resolve() {
+        assert!(matches!(
+            CONFIG_SCHEMA.lookup("context.config.near.signer"),
+            Some(SchemaNode::Leaf {
+                ty: SchemaType::Enum(_),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn schema_self_check_is_clean() {
+        let problems = CONFIG_SCHEMA.self_check();
+
+        assert!(problems.is_empty(), "schema problems: {problems:#?}");
+    }
+}
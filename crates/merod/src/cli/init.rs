@@ -210,187 +210,11 @@ impl InitCommand {
             }
         }
 
-        let mut local_signers = LocalConfig {
-            protocols: BTreeMap::default(),
-        };
-
-        let mut client_params = BTreeMap::default();
-
-        {
-            let _ignored = client_params.insert(
-                "near".to_owned(),
-                ClientConfigParams {
-                    network: "testnet".into(),
-                    contract_id: "calimero-context-config.testnet".parse()?,
-                    signer: ClientSelectedSigner::Relayer,
-                },
-            );
-
-            let mut local_config = ClientLocalConfig {
-                signers: Default::default(),
-            };
-
-            let _ignored = local_config.signers.insert(
-                "mainnet".to_owned(),
-                generate_local_signer(
-                    "https://rpc.mainnet.near.org".parse()?,
-                    ConfigProtocol::Near,
-                )?,
-            );
-
-            let _ignored = local_config.signers.insert(
-                "testnet".to_owned(),
-                generate_local_signer(
-                    "https://rpc.testnet.near.org".parse()?,
-                    ConfigProtocol::Near,
-                )?,
-            );
-
-            let _ignored = local_signers
-                .protocols
-                .insert("near".to_owned(), local_config);
-        }
-
-        {
-            let _ignored = client_params.insert(
-                "starknet".to_owned(),
-                ClientConfigParams {
-                    network: "sepolia".into(),
-                    contract_id:
-                        "0x1b991ee006e2d1e372ab96d0a957401fa200358f317b681df2948f30e17c29c"
-                            .parse()?,
-                    signer: ClientSelectedSigner::Relayer,
-                },
-            );
-
-            let mut local_config = ClientLocalConfig {
-                signers: Default::default(),
-            };
-
-            let _ignored = local_config.signers.insert(
-                "mainnet".to_owned(),
-                generate_local_signer(
-                    "https://cloud.argent-api.com/v1/starknet/mainnet/rpc/v0.7".parse()?,
-                    ConfigProtocol::Starknet,
-                )?,
-            );
-
-            let _ignored = local_config.signers.insert(
-                "sepolia".to_owned(),
-                generate_local_signer(
-                    "https://free-rpc.nethermind.io/sepolia-juno/".parse()?,
-                    ConfigProtocol::Starknet,
-                )?,
-            );
-
-            let _ignored = local_signers
-                .protocols
-                .insert("starknet".to_owned(), local_config);
-        }
-
-        {
-            let _ignored = client_params.insert(
-                "icp".to_owned(),
-                ClientConfigParams {
-                    network: "local".into(),
-                    contract_id: "bkyz2-fmaaa-aaaaa-qaaaq-cai".parse()?,
-                    signer: ClientSelectedSigner::Local,
-                },
-            );
-
-            let mut local_config = ClientLocalConfig {
-                signers: Default::default(),
-            };
-
-            let _ignored = local_config.signers.insert(
-                "ic".to_owned(),
-                generate_local_signer("https://ic0.app".parse()?, ConfigProtocol::Icp)?,
-            );
-
-            let _ignored = local_config.signers.insert(
-                "local".to_owned(),
-                generate_local_signer("http://127.0.0.1:4943".parse()?, ConfigProtocol::Icp)?,
-            );
-
-            let _ignored = local_signers
-                .protocols
-                .insert("icp".to_owned(), local_config);
-        }
-
-        {
-            let _ignored = client_params.insert(
-                "stellar".to_owned(),
-                ClientConfigParams {
-                    network: "testnet".into(),
-                    contract_id: "CDZ25SJ65YRXTCWMJNLTNZXPFPBGHOOB7BUBYQE7W3PU7I357BTX6QZY"
-                        .parse()?,
-                    signer: ClientSelectedSigner::Relayer,
-                },
-            );
-
-            let mut local_config = ClientLocalConfig {
-                signers: Default::default(),
-            };
-
-            let _ignored = local_config.signers.insert(
-                "mainnet".to_owned(),
-                generate_local_signer(
-                    "https://soroban.stellar.org".parse()?,
-                    ConfigProtocol::Stellar,
-                )?,
-            );
-
-            let _ignored = local_config.signers.insert(
-                "testnet".to_owned(),
-                generate_local_signer(
-                    "https://soroban-testnet.stellar.org".parse()?,
-                    ConfigProtocol::Stellar,
-                )?,
-            );
-
-            let _ignored = local_signers
-                .protocols
-                .insert("stellar".to_owned(), local_config);
-        }
-
-        {
-            let _ignored = client_params.insert(
-                "ethereum".to_owned(),
-                ClientConfigParams {
-                    network: "sepolia".into(),
-                    contract_id: "0x83365DE41E1247511F4C5D10Fb1AFe59b96aD4dB".parse()?,
-                    signer: ClientSelectedSigner::Relayer,
-                },
-            );
-
-            let mut local_config = ClientLocalConfig {
-                signers: Default::default(),
-            };
-
-            let _ignored = local_config.signers.insert(
-                "sepolia".to_owned(),
-                generate_local_signer(
-                    "https://sepolia.drpc.org".parse()?,
-                    ConfigProtocol::Ethereum,
-                )?,
-            );
-
-            let _ignored = local_signers
-                .protocols
-                .insert("ethereum".to_owned(), local_config);
-        }
-
         let relayer = self
             .relayer_url
             .unwrap_or_else(defaults::default_relayer_url);
 
-        let client_config = ClientConfig {
-            signer: ClientSigner {
-                relayer: ClientRelayerSigner { url: relayer },
-                local: local_signers,
-            },
-            params: client_params,
-        };
+        let client_config = default_client_config(relayer)?;
 
         let config = ConfigFile::new(
             identity,
@@ -437,6 +261,188 @@ impl InitCommand {
     }
 }
 
+/// Builds the context client configuration `init` writes when no overrides
+/// are given. Also serves as the baseline for `config diff-defaults`.
+pub fn default_client_config(relayer_url: Url) -> EyreResult<ClientConfig> {
+    let mut local_signers = LocalConfig {
+        protocols: BTreeMap::default(),
+    };
+
+    let mut client_params = BTreeMap::default();
+
+    {
+        let _ignored = client_params.insert(
+            "near".to_owned(),
+            ClientConfigParams {
+                network: "testnet".into(),
+                contract_id: "calimero-context-config.testnet".parse()?,
+                signer: ClientSelectedSigner::Relayer,
+            },
+        );
+
+        let mut local_config = ClientLocalConfig {
+            signers: Default::default(),
+        };
+
+        let _ignored = local_config.signers.insert(
+            "mainnet".to_owned(),
+            generate_local_signer(
+                "https://rpc.mainnet.near.org".parse()?,
+                ConfigProtocol::Near,
+            )?,
+        );
+
+        let _ignored = local_config.signers.insert(
+            "testnet".to_owned(),
+            generate_local_signer(
+                "https://rpc.testnet.near.org".parse()?,
+                ConfigProtocol::Near,
+            )?,
+        );
+
+        let _ignored = local_signers
+            .protocols
+            .insert("near".to_owned(), local_config);
+    }
+
+    {
+        let _ignored = client_params.insert(
+            "starknet".to_owned(),
+            ClientConfigParams {
+                network: "sepolia".into(),
+                contract_id:
+                    "0x1b991ee006e2d1e372ab96d0a957401fa200358f317b681df2948f30e17c29c"
+                        .parse()?,
+                signer: ClientSelectedSigner::Relayer,
+            },
+        );
+
+        let mut local_config = ClientLocalConfig {
+            signers: Default::default(),
+        };
+
+        let _ignored = local_config.signers.insert(
+            "mainnet".to_owned(),
+            generate_local_signer(
+                "https://cloud.argent-api.com/v1/starknet/mainnet/rpc/v0.7".parse()?,
+                ConfigProtocol::Starknet,
+            )?,
+        );
+
+        let _ignored = local_config.signers.insert(
+            "sepolia".to_owned(),
+            generate_local_signer(
+                "https://free-rpc.nethermind.io/sepolia-juno/".parse()?,
+                ConfigProtocol::Starknet,
+            )?,
+        );
+
+        let _ignored = local_signers
+            .protocols
+            .insert("starknet".to_owned(), local_config);
+    }
+
+    {
+        let _ignored = client_params.insert(
+            "icp".to_owned(),
+            ClientConfigParams {
+                network: "local".into(),
+                contract_id: "bkyz2-fmaaa-aaaaa-qaaaq-cai".parse()?,
+                signer: ClientSelectedSigner::Local,
+            },
+        );
+
+        let mut local_config = ClientLocalConfig {
+            signers: Default::default(),
+        };
+
+        let _ignored = local_config.signers.insert(
+            "ic".to_owned(),
+            generate_local_signer("https://ic0.app".parse()?, ConfigProtocol::Icp)?,
+        );
+
+        let _ignored = local_config.signers.insert(
+            "local".to_owned(),
+            generate_local_signer("http://127.0.0.1:4943".parse()?, ConfigProtocol::Icp)?,
+        );
+
+        let _ignored = local_signers
+            .protocols
+            .insert("icp".to_owned(), local_config);
+    }
+
+    {
+        let _ignored = client_params.insert(
+            "stellar".to_owned(),
+            ClientConfigParams {
+                network: "testnet".into(),
+                contract_id: "CDZ25SJ65YRXTCWMJNLTNZXPFPBGHOOB7BUBYQE7W3PU7I357BTX6QZY"
+                    .parse()?,
+                signer: ClientSelectedSigner::Relayer,
+            },
+        );
+
+        let mut local_config = ClientLocalConfig {
+            signers: Default::default(),
+        };
+
+        let _ignored = local_config.signers.insert(
+            "mainnet".to_owned(),
+            generate_local_signer(
+                "https://soroban.stellar.org".parse()?,
+                ConfigProtocol::Stellar,
+            )?,
+        );
+
+        let _ignored = local_config.signers.insert(
+            "testnet".to_owned(),
+            generate_local_signer(
+                "https://soroban-testnet.stellar.org".parse()?,
+                ConfigProtocol::Stellar,
+            )?,
+        );
+
+        let _ignored = local_signers
+            .protocols
+            .insert("stellar".to_owned(), local_config);
+    }
+
+    {
+        let _ignored = client_params.insert(
+            "ethereum".to_owned(),
+            ClientConfigParams {
+                network: "sepolia".into(),
+                contract_id: "0x83365DE41E1247511F4C5D10Fb1AFe59b96aD4dB".parse()?,
+                signer: ClientSelectedSigner::Relayer,
+            },
+        );
+
+        let mut local_config = ClientLocalConfig {
+            signers: Default::default(),
+        };
+
+        let _ignored = local_config.signers.insert(
+            "sepolia".to_owned(),
+            generate_local_signer(
+                "https://sepolia.drpc.org".parse()?,
+                ConfigProtocol::Ethereum,
+            )?,
+        );
+
+        let _ignored = local_signers
+            .protocols
+            .insert("ethereum".to_owned(), local_config);
+    }
+
+    Ok(ClientConfig {
+        signer: ClientSigner {
+            relayer: ClientRelayerSigner { url: relayer_url },
+            local: local_signers,
+        },
+        params: client_params,
+    })
+}
+
 fn generate_local_signer(
     rpc_url: Url,
     config_protocol: ConfigProtocol,
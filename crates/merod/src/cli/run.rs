@@ -7,8 +7,12 @@ use calimero_server::config::ServerConfig;
 use calimero_store::config::StoreConfig;
 use clap::Parser;
 use eyre::{bail, Result as EyreResult};
+use tokio::fs::{remove_file, write};
+use tokio::signal::unix::{signal, SignalKind};
+use tokio::spawn;
+use tracing::{error, info, warn};
 
-use crate::cli::RootArgs;
+use crate::cli::{RootArgs, PID_FILE};
 
 /// Run a node
 #[derive(Debug, Parser)]
@@ -42,7 +46,33 @@ impl RunCommand {
             jsonrpc.auth_enabled = self.auth;
         }
 
-        start(NodeConfig::new(
+        // Best-effort marker so other commands can tell the node is running.
+        let pid_path = path.join(PID_FILE);
+        write(&pid_path, std::process::id().to_string()).await?;
+
+        // `merod config --reload` signals us with SIGHUP; without a handler
+        // the default disposition would terminate the node. Re-read and
+        // re-validate the config on each hangup - keys read once at startup
+        // still need a restart.
+        let reload_path = path.clone();
+        let reload_task = spawn(async move {
+            let Ok(mut hangups) = signal(SignalKind::hangup()) else {
+                warn!("could not install the SIGHUP handler; `merod config --reload` will not work");
+                return;
+            };
+
+            while hangups.recv().await.is_some() {
+                match ConfigFile::load(&reload_path).await {
+                    Ok(_) => info!("SIGHUP received; config re-read from disk"),
+                    Err(err) => error!(
+                        %err,
+                        "SIGHUP received, but the config on disk no longer loads; keeping the old one"
+                    ),
+                }
+            }
+        });
+
+        let result = start(NodeConfig::new(
             path.clone(),
             config.identity.clone(),
             NetworkConfig::new(
@@ -60,6 +90,12 @@ impl RunCommand {
             config.context,
             server_config,
         ))
-        .await
+        .await;
+
+        reload_task.abort();
+
+        let _ignored = remove_file(&pid_path).await;
+
+        result
     }
 }
@@ -51,7 +51,7 @@ impl NetworkConfig {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct SwarmConfig {
     pub listen: Vec<Multiaddr>,
@@ -64,7 +64,7 @@ impl SwarmConfig {
     }
 }
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct BootstrapConfig {
     #[serde(default)]
@@ -78,7 +78,7 @@ impl BootstrapConfig {
     }
 }
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[serde(transparent)]
 #[non_exhaustive]
 pub struct BootstrapNodes {
@@ -113,7 +113,7 @@ impl BootstrapNodes {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct DiscoveryConfig {
     #[serde(default = "calimero_primitives::common::bool_true")]
@@ -162,6 +162,9 @@ impl Default for DiscoveryConfig {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct RelayConfig {
+    #[serde(default = "calimero_primitives::common::bool_true")]
+    pub enabled: bool,
+
     pub registrations_limit: usize,
 }
 
@@ -169,6 +172,7 @@ impl RelayConfig {
     #[must_use]
     pub const fn new(registrations_limit: usize) -> Self {
         Self {
+            enabled: true,
             registrations_limit,
         }
     }
@@ -177,6 +181,7 @@ impl RelayConfig {
 impl Default for RelayConfig {
     fn default() -> Self {
         Self {
+            enabled: true,
             registrations_limit: 3,
         }
     }
@@ -245,10 +245,11 @@ impl EventLoop {
             .get_peer_info(relay_peer)
             .wrap_err("Failed to get peer info")?;
 
-        if !self
-            .discovery
-            .state
-            .is_relay_reservation_required(self.discovery.relay_config.registrations_limit)
+        if !self.discovery.relay_config.enabled
+            || !self
+                .discovery
+                .state
+                .is_relay_reservation_required(self.discovery.relay_config.registrations_limit)
         {
             return Ok(());
         }
@@ -395,7 +395,7 @@ impl ContextCommand {
 
                 if let Some(invitation_payload) = node
                     .ctx_manager
-                    .invite_to_context(context_id, inviter_id, invitee_id)
+                    .invite_to_context(context_id, inviter_id, invitee_id, None)
                     .await?
                 {
                     if let Some(alias) = name {
@@ -114,6 +114,14 @@ impl fmt::Debug for ContextInvitationPayload {
     }
 }
 
+impl ContextInvitationPayload {
+    /// The raw invitation bytes that the textual encodings wrap.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
 impl fmt::Display for ContextInvitationPayload {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.pad(&bs58::encode(self.0.as_slice()).into_string())
@@ -161,6 +169,20 @@ const _: () = {
         protocol: Cow<'a, str>,
         network: Cow<'a, str>,
         contract_id: Cow<'a, str>,
+        /// Unix seconds after which the invitation must be refused;
+        /// absent on payloads minted before expiry existed.
+        expires_at: Option<u64>,
+    }
+
+    /// The payload layout before expiry existed; still decoded so
+    /// invitations minted by older nodes keep working.
+    #[derive(BorshDeserialize)]
+    struct LegacyInvitationPayload<'a> {
+        context_id: [u8; 32],
+        invitee_id: [u8; 32],
+        protocol: Cow<'a, str>,
+        network: Cow<'a, str>,
+        contract_id: Cow<'a, str>,
     }
 
     impl ContextInvitationPayload {
@@ -170,6 +192,7 @@ const _: () = {
             protocol: Cow<'_, str>,
             network: Cow<'_, str>,
             contract_id: Cow<'_, str>,
+            expires_at: Option<u64>,
         ) -> io::Result<Self> {
             let payload = InvitationPayload {
                 context_id: *context_id,
@@ -177,13 +200,29 @@ const _: () = {
                 protocol,
                 network,
                 contract_id,
+                expires_at,
             };
 
             borsh::to_vec(&payload).map(Self)
         }
 
+        fn decode(&self) -> io::Result<InvitationPayload<'_>> {
+            borsh::from_slice(&self.0).or_else(|_| {
+                let legacy: LegacyInvitationPayload<'_> = borsh::from_slice(&self.0)?;
+
+                Ok(InvitationPayload {
+                    context_id: legacy.context_id,
+                    invitee_id: legacy.invitee_id,
+                    protocol: legacy.protocol,
+                    network: legacy.network,
+                    contract_id: legacy.contract_id,
+                    expires_at: None,
+                })
+            })
+        }
+
         pub fn parts(&self) -> io::Result<(ContextId, PublicKey, String, String, String)> {
-            let payload: InvitationPayload<'_> = borsh::from_slice(&self.0)?;
+            let payload = self.decode()?;
 
             Ok((
                 payload.context_id.into(),
@@ -193,6 +232,12 @@ const _: () = {
                 payload.contract_id.into_owned(),
             ))
         }
+
+        /// When the invitation lapses, as unix seconds, where the minting
+        /// node set a deadline.
+        pub fn expires_at(&self) -> io::Result<Option<u64>> {
+            Ok(self.decode()?.expires_at)
+        }
     }
 };
 
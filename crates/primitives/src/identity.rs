@@ -85,7 +85,7 @@ impl FromStr for PrivateKey {
     }
 }
 
-#[derive(Eq, Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Eq, Copy, Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(
     feature = "borsh",
     derive(borsh::BorshDeserialize, borsh::BorshSerialize)
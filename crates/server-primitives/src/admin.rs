@@ -1,5 +1,5 @@
 use calimero_context_config::repr::Repr;
-use calimero_context_config::types::{ContextIdentity, ContextStorageEntry};
+use calimero_context_config::types::{Capability, ContextIdentity, ContextStorageEntry, SignerId};
 use calimero_context_config::{Proposal, ProposalWithApprovals};
 use calimero_primitives::alias::Alias;
 use calimero_primitives::application::{Application, ApplicationId};
@@ -7,6 +7,7 @@ use calimero_primitives::context::{Context, ContextId, ContextInvitationPayload}
 use calimero_primitives::hash::Hash;
 use calimero_primitives::identity::{ClientKey, ContextUser, PrivateKey, PublicKey, WalletType};
 use camino::Utf8PathBuf;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use url::Url;
@@ -313,20 +314,162 @@ impl GetContextsResponse {
     }
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CapabilityCeilingResponseData {
+    pub capabilities: Vec<Capability>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CapabilityCeilingResponse {
+    pub data: CapabilityCeilingResponseData,
+}
+
+impl CapabilityCeilingResponse {
+    pub const fn new(capabilities: Vec<Capability>) -> Self {
+        Self {
+            data: CapabilityCeilingResponseData { capabilities },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DefaultCapabilitiesResponseData {
+    pub capabilities: Vec<Capability>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DefaultCapabilitiesResponse {
+    pub data: DefaultCapabilitiesResponseData,
+}
+
+impl DefaultCapabilitiesResponse {
+    pub const fn new(capabilities: Vec<Capability>) -> Self {
+        Self {
+            data: DefaultCapabilitiesResponseData { capabilities },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GetCapabilitiesResponseData {
+    pub capabilities: Vec<(Repr<SignerId>, Vec<Capability>)>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GetCapabilitiesResponse {
+    pub data: GetCapabilitiesResponseData,
+}
+
+impl GetCapabilitiesResponse {
+    pub const fn new(capabilities: Vec<(Repr<SignerId>, Vec<Capability>)>) -> Self {
+        Self {
+            data: GetCapabilitiesResponseData { capabilities },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GrantCapabilitiesResponseData {
+    /// The grantees' full capability sets after the grant, so the grant
+    /// is self-verifying without a follow-up query.
+    pub capabilities: Vec<(Repr<SignerId>, Vec<Capability>)>,
+    /// Echo of the expiry the node scheduled, when the grant was
+    /// time-boxed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiry: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GrantCapabilitiesResponse {
+    pub data: GrantCapabilitiesResponseData,
+}
+
+impl GrantCapabilitiesResponse {
+    pub const fn new(
+        capabilities: Vec<(Repr<SignerId>, Vec<Capability>)>,
+        expiry: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            data: GrantCapabilitiesResponseData {
+                capabilities,
+                expiry,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ListPermissionsResponseData {
+    pub permissions: Vec<(Repr<SignerId>, Vec<Capability>)>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ListPermissionsResponse {
+    pub data: ListPermissionsResponseData,
+}
+
+impl ListPermissionsResponse {
+    pub const fn new(permissions: Vec<(Repr<SignerId>, Vec<Capability>)>) -> Self {
+        Self {
+            data: ListPermissionsResponseData { permissions },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RevokeCapabilitiesResponseData {
+    /// What each affected member still holds after the revocation.
+    pub capabilities: Vec<(Repr<SignerId>, Vec<Capability>)>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RevokeCapabilitiesResponse {
+    pub data: RevokeCapabilitiesResponseData,
+}
+
+impl RevokeCapabilitiesResponse {
+    pub const fn new(capabilities: Vec<(Repr<SignerId>, Vec<Capability>)>) -> Self {
+        Self {
+            data: RevokeCapabilitiesResponseData { capabilities },
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InviteToContextRequest {
     pub context_id: ContextId,
     pub inviter_id: PublicKey,
     pub invitee_id: PublicKey,
+    /// Capabilities the invitee is meant to receive once they join; the
+    /// inviter must hold every one of them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub capabilities: Vec<Capability>,
+    /// Replays of a request carrying the same key return the original
+    /// invitation instead of minting a new one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+    /// Seconds until the invitation lapses; left unset, it never does.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_in_secs: Option<u64>,
 }
 
 impl InviteToContextRequest {
-    pub const fn new(context_id: ContextId, inviter_id: PublicKey, invitee_id: PublicKey) -> Self {
+    pub const fn new(
+        context_id: ContextId,
+        inviter_id: PublicKey,
+        invitee_id: PublicKey,
+        capabilities: Vec<Capability>,
+        idempotency_key: Option<String>,
+        expires_in_secs: Option<u64>,
+    ) -> Self {
         Self {
             context_id,
             inviter_id,
             invitee_id,
+            capabilities,
+            idempotency_key,
+            expires_in_secs,
         }
     }
 }
@@ -335,11 +478,28 @@ impl InviteToContextRequest {
 #[serde(rename_all = "camelCase")]
 pub struct InviteToContextResponse {
     pub data: Option<ContextInvitationPayload>,
+    /// The context's registered alias, or its id when none exists, so
+    /// clients can render the invitation with a human-readable name.
+    #[serde(default)]
+    pub context_name: Option<String>,
+    /// The payload re-encoded as requested via the `encoding` query
+    /// parameter; `data` always carries the canonical base58 form.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encoded: Option<Value>,
+    /// Capabilities granted alongside the invitation, in place by the
+    /// time the invitee accepts.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub granted: Vec<Capability>,
 }
 
 impl InviteToContextResponse {
-    pub const fn new(payload: Option<ContextInvitationPayload>) -> Self {
-        Self { data: payload }
+    pub const fn new(payload: Option<ContextInvitationPayload>, context_name: Option<String>) -> Self {
+        Self {
+            data: payload,
+            context_name,
+            encoded: None,
+            granted: Vec::new(),
+        }
     }
 }
 
@@ -17,7 +17,7 @@ use serde_json::from_value as from_json_value;
 use tracing::{error, info};
 
 use crate::admin::handlers::root_keys::store_root_key;
-use crate::admin::service::{parse_api_error, ApiError, ApiResponse};
+use crate::admin::service::{parse_api_error, ApiError, ApiErrorCode, ApiResponse};
 use crate::admin::storage::client_keys::add_client_key;
 use crate::admin::storage::root_key::exists_root_keys;
 use crate::admin::utils::auth::{validate_challenge, validate_root_key_exists};
@@ -33,6 +33,7 @@ pub fn transform_request(
                 from_json_value::<NearSignatureMessageMetadata>(intermediate.payload.metadata)
                     .map_err(|_| ApiError {
                         status_code: StatusCode::BAD_REQUEST,
+                        code: ApiErrorCode::InvalidRequest,
                         message: "Invalid metadata.".into(),
                     })?;
             SignatureMetadataEnum::NEAR(metadata)
@@ -42,6 +43,7 @@ pub fn transform_request(
                 from_json_value::<EthSignatureMessageMetadata>(intermediate.payload.metadata)
                     .map_err(|_| ApiError {
                         status_code: StatusCode::BAD_REQUEST,
+                        code: ApiErrorCode::InvalidRequest,
                         message: "Invalid metadata.".into(),
                     })?;
             SignatureMetadataEnum::ETH(metadata)
@@ -51,6 +53,7 @@ pub fn transform_request(
                 from_json_value::<StarknetSignatureMessageMetadata>(intermediate.payload.metadata)
                     .map_err(|_| ApiError {
                         status_code: StatusCode::BAD_REQUEST,
+                        code: ApiErrorCode::InvalidRequest,
                         message: "Invalid metadata.".into(),
                     })?;
             SignatureMetadataEnum::STARKNET(metadata)
@@ -60,6 +63,7 @@ pub fn transform_request(
                 from_json_value::<ICPSignatureMessageMetadata>(intermediate.payload.metadata)
                     .map_err(|_| ApiError {
                         status_code: StatusCode::BAD_REQUEST,
+                        code: ApiErrorCode::InvalidRequest,
                         message: "Invalid metadata.".into(),
                     })?;
             SignatureMetadataEnum::ICP(metadata)
@@ -67,6 +71,7 @@ pub fn transform_request(
         _ => {
             return Err(ApiError {
                 status_code: StatusCode::BAD_REQUEST,
+                code: ApiErrorCode::InvalidRequest,
                 message: "Unsupported wallet type.".into(),
             });
         }
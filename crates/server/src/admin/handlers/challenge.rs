@@ -15,7 +15,7 @@ use serde_json::to_vec as to_json_vec;
 use tower_sessions::Session;
 use tracing::error;
 
-use crate::admin::service::{ApiError, ApiResponse};
+use crate::admin::service::{ApiError, ApiErrorCode, ApiResponse};
 use crate::AdminState;
 
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
@@ -43,6 +43,7 @@ pub async fn request_challenge_handler(
                 error!("Failed to insert challenge into session: {}", err);
                 return ApiError {
                     status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                    code: ApiErrorCode::Internal,
                     message: "Failed to insert challenge into session".to_owned(),
                 }
                 .into_response();
@@ -56,6 +57,7 @@ pub async fn request_challenge_handler(
             error!("Failed to generate client challenge: {}", err);
             ApiError {
                 status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                code: ApiErrorCode::Internal,
                 message: "Failed to generate challenge".to_owned(),
             }
             .into_response()
@@ -75,6 +77,7 @@ fn generate_challenge(
 
     let message_vec = to_json_vec(&node_challenge_message).map_err(|_| ApiError {
         status_code: StatusCode::INTERNAL_SERVER_ERROR,
+        code: ApiErrorCode::Internal,
         message: "Failed to serialize challenge data".into(),
     })?;
 
@@ -85,6 +88,7 @@ fn generate_challenge(
         }
         Err(e) => Err(ApiError {
             status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            code: ApiErrorCode::Internal,
             message: format!("Failed to sign challenge: {e}"),
         }),
     }
@@ -1,12 +1,55 @@
+use calimero_context::ContextManager;
+use calimero_context_config::repr::ReprTransmute;
+use calimero_context_config::types::{Capability, SignerId};
+use calimero_primitives::context::ContextId;
+use calimero_primitives::identity::PublicKey;
+use reqwest::StatusCode;
+
+use crate::admin::service::{parse_api_error, ApiError, ApiErrorCode};
+
+pub mod capability_ceiling;
 pub mod create_context;
+pub mod default_capabilities;
 pub mod delete_context;
+pub mod get_capabilities;
 pub mod get_context;
 pub mod get_context_client_keys;
 pub mod get_context_identities;
 pub mod get_context_storage;
 pub mod get_contexts;
+pub mod get_invitation_status;
 pub mod grant_capabilities;
 pub mod invite_to_context;
 pub mod join_context;
+pub mod list_permissions;
 pub mod revoke_capabilities;
 pub mod update_context_application;
+
+/// Checks that `actor` holds `capability` in the context, producing the
+/// uniform 403 every permission-gated handler responds with otherwise.
+pub async fn require_capability(
+    ctx_manager: &ContextManager,
+    context_id: ContextId,
+    actor: PublicKey,
+    capability: Capability,
+) -> Result<(), ApiError> {
+    let signer_id: SignerId = actor.rt().expect("infallible conversion");
+
+    let held = ctx_manager
+        .get_capabilities(context_id, &[actor.rt().expect("infallible conversion")])
+        .await
+        .map_err(parse_api_error)?
+        .get(&signer_id)
+        .cloned()
+        .unwrap_or_default();
+
+    if held.contains(&capability) {
+        return Ok(());
+    }
+
+    Err(ApiError {
+        status_code: StatusCode::FORBIDDEN,
+        code: ApiErrorCode::MissingCapability,
+        message: format!("`{actor}` does not hold {capability:?} in context {context_id}"),
+    })
+}
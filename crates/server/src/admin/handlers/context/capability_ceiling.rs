@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use axum::extract::{Json, Path};
+use axum::response::IntoResponse;
+use axum::Extension;
+use calimero_context_config::types::Capability;
+use calimero_primitives::context::ContextId;
+use calimero_primitives::identity::PublicKey;
+use calimero_server_primitives::admin::CapabilityCeilingResponse;
+use serde::Deserialize;
+
+use crate::admin::handlers::context::require_capability;
+use crate::admin::service::{parse_api_error, ApiResponse};
+use crate::admin::storage::capability_ceiling::{
+    clear_capability_ceiling, get_capability_ceiling, set_capability_ceiling,
+};
+use crate::AdminState;
+
+#[derive(Debug, Deserialize)]
+pub struct SetCapabilityCeilingRequest {
+    /// The only capabilities grants in this context may hand out; an
+    /// empty list clears the ceiling.
+    pub capabilities: Vec<Capability>,
+    /// Who is asking; raising or clearing the ceiling is gated on the
+    /// same capability as handing out grants under it.
+    pub signer_id: PublicKey,
+}
+
+/// Returns the context's capability ceiling: the allowlist grants are
+/// checked against. An empty list means no ceiling is set.
+pub async fn get_handler(
+    Path(context_id): Path<ContextId>,
+    Extension(state): Extension<Arc<AdminState>>,
+) -> impl IntoResponse {
+    let capabilities = match get_capability_ceiling(&state.store, context_id) {
+        Ok(ceiling) => ceiling.unwrap_or_default(),
+        Err(err) => {
+            return parse_api_error(err).into_response();
+        }
+    };
+
+    ApiResponse {
+        payload: CapabilityCeilingResponse::new(capabilities),
+    }
+    .into_response()
+}
+
+/// Sets the context's capability ceiling. Grants of capabilities outside
+/// the ceiling are rejected with 403 until the ceiling is raised. The
+/// ceiling is persisted and keeps constraining grants across restarts.
+pub async fn set_handler(
+    Path(context_id): Path<ContextId>,
+    Extension(state): Extension<Arc<AdminState>>,
+    Json(request): Json<SetCapabilityCeilingRequest>,
+) -> impl IntoResponse {
+    match state.ctx_manager.get_context(&context_id) {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            return parse_api_error(eyre::eyre!("Context not found")).into_response();
+        }
+        Err(err) => {
+            return parse_api_error(err).into_response();
+        }
+    }
+
+    // The ceiling constrains what grants may hand out, so changing it is
+    // itself a membership-management action.
+    if let Err(err) = require_capability(
+        &state.ctx_manager,
+        context_id,
+        request.signer_id,
+        Capability::ManageMembers,
+    )
+    .await
+    {
+        return err.into_response();
+    }
+
+    let stored = if request.capabilities.is_empty() {
+        clear_capability_ceiling(&state.store, context_id)
+    } else {
+        set_capability_ceiling(&state.store, context_id, request.capabilities.clone())
+    };
+
+    if let Err(err) = stored {
+        return parse_api_error(err).into_response();
+    }
+
+    ApiResponse {
+        payload: CapabilityCeilingResponse::new(request.capabilities),
+    }
+    .into_response()
+}
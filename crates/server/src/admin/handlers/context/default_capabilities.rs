@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use axum::extract::{Json, Path};
+use axum::response::IntoResponse;
+use axum::Extension;
+use calimero_context_config::types::Capability;
+use calimero_primitives::context::ContextId;
+use calimero_server_primitives::admin::DefaultCapabilitiesResponse;
+use serde::Deserialize;
+
+use crate::admin::service::{parse_api_error, ApiResponse};
+use crate::admin::storage::default_capabilities::{
+    clear_default_capabilities, get_default_capabilities, set_default_capabilities,
+};
+use crate::AdminState;
+
+#[derive(Debug, Deserialize)]
+pub struct SetDefaultCapabilitiesRequest {
+    /// The capabilities every invitation without its own set pre-grants;
+    /// an empty list clears the policy.
+    pub capabilities: Vec<Capability>,
+}
+
+/// Returns the capability set invitations in this context pre-grant by
+/// default.
+pub async fn get_handler(
+    Path(context_id): Path<ContextId>,
+    Extension(state): Extension<Arc<AdminState>>,
+) -> impl IntoResponse {
+    let capabilities = match get_default_capabilities(&state.store, context_id) {
+        Ok(defaults) => defaults.unwrap_or_default(),
+        Err(err) => {
+            return parse_api_error(err).into_response();
+        }
+    };
+
+    ApiResponse {
+        payload: DefaultCapabilitiesResponse::new(capabilities),
+    }
+    .into_response()
+}
+
+/// Sets the context's onboarding policy: the capabilities granted along
+/// with every invitation that doesn't name its own set. The policy is
+/// persisted and keeps applying across restarts.
+pub async fn set_handler(
+    Path(context_id): Path<ContextId>,
+    Extension(state): Extension<Arc<AdminState>>,
+    Json(request): Json<SetDefaultCapabilitiesRequest>,
+) -> impl IntoResponse {
+    match state.ctx_manager.get_context(&context_id) {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            return parse_api_error(eyre::eyre!("Context not found")).into_response();
+        }
+        Err(err) => {
+            return parse_api_error(err).into_response();
+        }
+    }
+
+    let stored = if request.capabilities.is_empty() {
+        clear_default_capabilities(&state.store, context_id)
+    } else {
+        set_default_capabilities(&state.store, context_id, request.capabilities.clone())
+    };
+
+    if let Err(err) = stored {
+        return parse_api_error(err).into_response();
+    }
+
+    ApiResponse {
+        payload: DefaultCapabilitiesResponse::new(request.capabilities),
+    }
+    .into_response()
+}
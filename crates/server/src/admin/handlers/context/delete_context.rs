@@ -9,7 +9,7 @@ use calimero_server_primitives::admin::{DeleteContextResponse, DeletedContextRes
 use reqwest::StatusCode;
 use tower_sessions::Session;
 
-use crate::admin::service::{parse_api_error, ApiError, ApiResponse};
+use crate::admin::service::{parse_api_error, ApiError, ApiErrorCode, ApiResponse};
 use crate::AdminState;
 
 pub async fn handler(
@@ -20,6 +20,7 @@ pub async fn handler(
     let Ok(context_id_result) = ContextId::from_str(&context_id) else {
         return ApiError {
             status_code: StatusCode::BAD_REQUEST,
+            code: ApiErrorCode::InvalidRequest,
             message: "Invalid context id".into(),
         }
         .into_response();
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use axum::extract::Path;
+use axum::response::IntoResponse;
+use axum::Extension;
+use calimero_context_config::repr::Repr;
+use calimero_primitives::context::ContextId;
+use calimero_server_primitives::admin::GetCapabilitiesResponse;
+
+use crate::admin::service::{parse_api_error, ApiResponse};
+use crate::AdminState;
+
+pub async fn handler(
+    Path(context_id): Path<ContextId>,
+    Extension(state): Extension<Arc<AdminState>>,
+) -> impl IntoResponse {
+    let context = match state.ctx_manager.get_context(&context_id) {
+        Ok(Some(context)) => context,
+        Ok(None) => {
+            return parse_api_error(eyre::eyre!("Context not found")).into_response();
+        }
+        Err(err) => {
+            return parse_api_error(err).into_response();
+        }
+    };
+
+    // An empty identity list asks the config contract for every
+    // member that holds at least one capability.
+    match state.ctx_manager.get_capabilities(context.id, &[]).await {
+        Ok(privileges) => ApiResponse {
+            payload: GetCapabilitiesResponse::new(
+                privileges
+                    .into_iter()
+                    .map(|(signer_id, capabilities)| (Repr::new(signer_id), capabilities))
+                    .collect(),
+            ),
+        }
+        .into_response(),
+        Err(err) => parse_api_error(err).into_response(),
+    }
+}
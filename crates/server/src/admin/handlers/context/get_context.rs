@@ -7,7 +7,7 @@ use calimero_primitives::context::ContextId;
 use calimero_server_primitives::admin::GetContextResponse;
 use reqwest::StatusCode;
 
-use crate::admin::service::{parse_api_error, ApiError, ApiResponse};
+use crate::admin::service::{parse_api_error, ApiError, ApiErrorCode, ApiResponse};
 use crate::AdminState;
 
 pub async fn handler(
@@ -29,6 +29,7 @@ pub async fn handler(
             .into_response(),
             None => ApiError {
                 status_code: StatusCode::NOT_FOUND,
+                code: ApiErrorCode::NotFound,
                 message: "Context not found".into(),
             }
             .into_response(),
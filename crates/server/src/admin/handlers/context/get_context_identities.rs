@@ -7,7 +7,7 @@ use calimero_primitives::context::ContextId;
 use calimero_server_primitives::admin::GetContextIdentitiesResponse;
 use reqwest::StatusCode;
 
-use crate::admin::service::{parse_api_error, ApiError, ApiResponse};
+use crate::admin::service::{parse_api_error, ApiError, ApiErrorCode, ApiResponse};
 use crate::AdminState;
 
 pub async fn handler(
@@ -30,6 +30,7 @@ pub async fn handler(
         None => {
             return ApiError {
                 status_code: StatusCode::NOT_FOUND,
+                code: ApiErrorCode::NotFound,
                 message: "Context not found".into(),
             }
             .into_response()
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use axum::extract::Path;
+use axum::response::IntoResponse;
+use axum::Extension;
+use calimero_primitives::context::ContextId;
+use calimero_primitives::identity::PublicKey;
+use chrono::{DateTime, Utc};
+use reqwest::StatusCode;
+use serde::Serialize;
+
+use crate::admin::service::{parse_api_error, ApiError, ApiErrorCode, ApiResponse};
+use crate::AdminState;
+
+/// Where an invitation stands, judged against the contract's current
+/// membership and the recorded expiry.
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InvitationStatus {
+    /// Minted, but the invitee has not joined yet.
+    Pending,
+    /// The invitee is a member of the context.
+    Accepted,
+    /// The invitation lapsed before the invitee joined.
+    Expired,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetInvitationStatusResponse {
+    pub status: InvitationStatus,
+    pub minted_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiry: Option<DateTime<Utc>>,
+}
+
+pub async fn handler(
+    Path((context_id, invitee_id)): Path<(ContextId, PublicKey)>,
+    Extension(state): Extension<Arc<AdminState>>,
+) -> impl IntoResponse {
+    let record = state
+        .invitations
+        .lock()
+        .expect("invitation records poisoned")
+        .get(&(context_id, invitee_id))
+        .copied();
+
+    let Some(record) = record else {
+        return ApiError {
+            status_code: StatusCode::NOT_FOUND,
+            code: ApiErrorCode::NotFound,
+            message: format!("no invitation recorded for `{invitee_id}` in context {context_id}"),
+        }
+        .into_response();
+    };
+
+    // Membership on the contract is the ground truth for acceptance; the
+    // record only supplies the timeline.
+    let members = match state.ctx_manager.get_context_identities(context_id, false) {
+        Ok(members) => members,
+        Err(err) => return parse_api_error(err).into_response(),
+    };
+
+    let status = if members.contains(&invitee_id) {
+        InvitationStatus::Accepted
+    } else if record.expiry.is_some_and(|expiry| expiry <= Utc::now()) {
+        InvitationStatus::Expired
+    } else {
+        InvitationStatus::Pending
+    };
+
+    ApiResponse {
+        payload: GetInvitationStatusResponse {
+            status,
+            minted_at: record.minted_at,
+            expiry: record.expiry,
+        },
+    }
+    .into_response()
+}
@@ -3,19 +3,39 @@ use std::sync::Arc;
 use axum::extract::{Json, Path};
 use axum::response::IntoResponse;
 use axum::Extension;
-use calimero_context_config::repr::Repr;
-use calimero_context_config::types::{Capability, ContextIdentity};
+use calimero_context_config::repr::{Repr, ReprTransmute};
+use calimero_context_config::types::{Capability, ContextIdentity, SignerId};
 use calimero_primitives::context::ContextId;
 use calimero_primitives::identity::PublicKey;
+use calimero_server_primitives::admin::GrantCapabilitiesResponse;
+use chrono::{DateTime, Utc};
+use rand::{thread_rng, Rng};
+use reqwest::StatusCode;
 use serde::Deserialize;
+use tokio::spawn;
+use tokio::time::sleep;
+use tracing::{error, info};
 
-use crate::admin::service::{parse_api_error, ApiResponse};
+use crate::admin::service::{parse_api_error, ApiError, ApiErrorCode, ApiResponse};
+use crate::admin::storage::capability_ceiling::get_capability_ceiling;
+use crate::admin::storage::grant_expiries::{
+    add_pending_expiry, get_pending_expiries, remove_pending_expiry, PendingGrantExpiry,
+};
+use crate::admin::validation::Validate;
 use crate::AdminState;
 
 #[derive(Deserialize, Debug)]
 pub struct GrantCapabilitiesRequest {
     pub capabilities: Vec<(Repr<ContextIdentity>, Capability)>,
     pub signer_id: PublicKey,
+    /// Free-form operator note on why the grant happened, kept in the
+    /// node's log for later audits.
+    #[serde(default)]
+    pub reason: Option<String>,
+    /// When set, the node revokes the granted capabilities again at this
+    /// time. The promise is persisted and survives a restart.
+    #[serde(default)]
+    pub expiry: Option<DateTime<Utc>>,
 }
 
 pub async fn handler(
@@ -23,6 +43,12 @@ pub async fn handler(
     Extension(state): Extension<Arc<AdminState>>,
     Json(request): Json<GrantCapabilitiesRequest>,
 ) -> impl IntoResponse {
+    if let Err(err) = request.validate() {
+        return err.into_response();
+    }
+
+    state.metrics.attempt("grant", context_id);
+
     let context = match state.ctx_manager.get_context(&context_id) {
         Ok(Some(context)) => context,
         Ok(None) => {
@@ -33,18 +59,206 @@ pub async fn handler(
         }
     };
 
+    if let Some(expiry) = request.expiry {
+        if expiry <= Utc::now() {
+            return ApiError {
+                status_code: StatusCode::BAD_REQUEST,
+                code: ApiErrorCode::InvalidRequest,
+                message: format!("expiry {expiry} is in the past"),
+            }
+            .into_response();
+        }
+    }
+
     let capabilities_to_grant: Vec<(ContextIdentity, Capability)> = request
         .capabilities
         .into_iter()
         .map(|(identity_repr, capability)| (*identity_repr, capability))
         .collect();
 
-    match state
+    // Organizational policy: where a ceiling is set for the context, no
+    // grant may hand out a capability outside it.
+    let ceiling = match get_capability_ceiling(&state.store, context_id) {
+        Ok(ceiling) => ceiling,
+        Err(err) => return parse_api_error(err).into_response(),
+    };
+
+    if let Some(ceiling) = ceiling {
+        if let Some((_, capability)) = capabilities_to_grant
+            .iter()
+            .find(|(_, capability)| !ceiling.contains(capability))
+        {
+            state.metrics.denial("grant", context_id);
+
+            return ApiError {
+                status_code: StatusCode::FORBIDDEN,
+                code: ApiErrorCode::CeilingExceeded,
+                message: format!(
+                    "policy forbids granting {capability:?} in this context; the ceiling allows {ceiling:?}"
+                ),
+            }
+            .into_response();
+        }
+    }
+
+    let grantees: Vec<ContextIdentity> = capabilities_to_grant
+        .iter()
+        .map(|(identity, _)| *identity)
+        .collect();
+
+    // A time-boxed grant must not take away what a grantee already held
+    // before it; snapshot their holdings now so the expiry revokes only
+    // the delta. The promise is persisted before the grant itself, so a
+    // restart before the deadline re-arms it instead of forgetting it.
+    let mut pending_expiry = None;
+
+    if let Some(expiry) = request.expiry {
+        let prior = match state.ctx_manager.get_capabilities(context.id, &grantees).await {
+            Ok(privileges) => privileges,
+            Err(err) => return parse_api_error(err).into_response(),
+        };
+
+        let to_revoke: Vec<(Repr<ContextIdentity>, Capability)> = capabilities_to_grant
+            .iter()
+            .filter(|(identity, capability)| {
+                let signer_id: SignerId = identity.rt().expect("infallible conversion");
+
+                !prior
+                    .get(&signer_id)
+                    .is_some_and(|held| held.contains(capability))
+            })
+            .map(|&(identity, capability)| (Repr::new(identity), capability))
+            .collect();
+
+        // Everything in the request was already held, so there is
+        // nothing for the expiry to undo.
+        if !to_revoke.is_empty() {
+            let pending = PendingGrantExpiry {
+                id: thread_rng().gen(),
+                context_id: context.id,
+                signer_id: request.signer_id,
+                capabilities: to_revoke,
+                expiry,
+            };
+
+            if let Err(err) = add_pending_expiry(&state.store, &pending) {
+                return parse_api_error(err).into_response();
+            }
+
+            pending_expiry = Some(pending);
+        }
+    }
+
+    if let Err(err) = state
         .ctx_manager
         .grant_capabilities(context.id, request.signer_id, &capabilities_to_grant)
         .await
     {
-        Ok(_) => ApiResponse { payload: () }.into_response(),
+        state.metrics.denial("grant", context_id);
+
+        // The grant never happened, so neither will its expiry.
+        if let Some(pending) = &pending_expiry {
+            if let Err(err) = remove_pending_expiry(&state.store, pending.id) {
+                error!(?err, "failed to drop the expiry record of a failed grant");
+            }
+        }
+
+        return parse_api_error(err).into_response();
+    }
+
+    state.metrics.success("grant", context_id);
+
+    if let Some(reason) = &request.reason {
+        info!(
+            %context_id,
+            signer_id=%request.signer_id,
+            reason,
+            "capabilities granted"
+        );
+    }
+
+    // The node itself undoes the time-boxed part once the expiry passes.
+    if let Some(pending) = pending_expiry {
+        schedule_expiry(Arc::clone(&state), pending);
+    }
+
+    // Echo the grantees' full capability sets so the grant is
+    // self-verifying without a follow-up query.
+    match state.ctx_manager.get_capabilities(context.id, &grantees).await {
+        Ok(privileges) => ApiResponse {
+            payload: GrantCapabilitiesResponse::new(
+                privileges
+                    .into_iter()
+                    .map(|(signer_id, capabilities)| (Repr::new(signer_id), capabilities))
+                    .collect(),
+                request.expiry,
+            ),
+        }
+        .into_response(),
         Err(err) => parse_api_error(err).into_response(),
     }
 }
+
+/// Spawns the task that revokes a time-boxed grant once its expiry
+/// passes, dropping the persisted record when the revocation lands.
+fn schedule_expiry(state: Arc<AdminState>, pending: PendingGrantExpiry) {
+    drop(spawn(async move {
+        let delay = (pending.expiry - Utc::now()).to_std().unwrap_or_default();
+
+        sleep(delay).await;
+
+        let to_revoke: Vec<(ContextIdentity, Capability)> = pending
+            .capabilities
+            .iter()
+            .map(|&(identity, capability)| (*identity, capability))
+            .collect();
+
+        match state
+            .ctx_manager
+            .revoke_capabilities(pending.context_id, pending.signer_id, &to_revoke)
+            .await
+        {
+            Ok(_) => info!(
+                context_id=%pending.context_id,
+                expiry=%pending.expiry,
+                "time-boxed grant expired and was revoked"
+            ),
+            Err(err) => {
+                // The record stays put: the next restart retries the
+                // revocation rather than letting the grant live on.
+                error!(
+                    context_id=%pending.context_id,
+                    expiry=%pending.expiry,
+                    ?err,
+                    "failed to revoke an expired grant"
+                );
+
+                return;
+            }
+        }
+
+        if let Err(err) = remove_pending_expiry(&state.store, pending.id) {
+            error!(?err, "failed to drop a fired expiry record");
+        }
+    }));
+}
+
+/// Re-arms every expiry persisted before the last shutdown; deadlines
+/// that passed while the node was down are enforced immediately.
+pub fn rearm_pending_expiries(state: &Arc<AdminState>) {
+    let pending = match get_pending_expiries(&state.store) {
+        Ok(pending) => pending,
+        Err(err) => {
+            error!(
+                ?err,
+                "failed to load pending grant expiries; time-boxed grants may outlive their deadline"
+            );
+
+            return;
+        }
+    };
+
+    for record in pending {
+        schedule_expiry(Arc::clone(state), record);
+    }
+}
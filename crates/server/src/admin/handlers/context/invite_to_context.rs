@@ -1,27 +1,322 @@
 use std::sync::Arc;
 
-use axum::response::IntoResponse;
+use axum::http::header::LOCATION;
+use axum::http::HeaderValue;
+use axum::response::{IntoResponse, Response};
 use axum::{Extension, Json};
+use axum::extract::Query;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use calimero_context::InviteToContextError;
+use calimero_context_config::repr::ReprTransmute;
+use calimero_context_config::types::{Capability, ContextIdentity};
+use calimero_primitives::context::{ContextId, ContextInvitationPayload};
+use calimero_primitives::identity::PublicKey;
 use calimero_server_primitives::admin::{InviteToContextRequest, InviteToContextResponse};
+use chrono::{Duration, Utc};
+use reqwest::StatusCode;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tracing::error;
 
-use crate::admin::service::{parse_api_error, ApiResponse};
-use crate::AdminState;
+use crate::admin::handlers::context::require_capability;
+use crate::admin::service::{parse_api_error, ApiError, ApiErrorCode, ApiResponse};
+use crate::admin::storage::default_capabilities::get_default_capabilities;
+use crate::admin::validation::Validate;
+use crate::{AdminState, IdempotencyRecord, InvitationRecord};
+
+/// How often an invite is retried when concurrent membership changes
+/// conflict with it before giving up with a 503.
+const MAX_INVITE_ATTEMPTS: usize = 3;
+
+#[derive(Debug, Deserialize)]
+pub struct InviteQueryParams {
+    /// Extra encoding of the payload to include in the response.
+    pub encoding: Option<InviteEncoding>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InviteEncoding {
+    /// The raw invitation bytes as base64, for copy-paste links.
+    Base64,
+    /// The decoded invitation fields as JSON, for programmatic use.
+    Json,
+}
+
+/// Wraps a freshly minted invitation in a `201 Created` with a
+/// `Location` header naming the invitation's status resource, which is
+/// the only addressable representation an invitation has.
+fn created(
+    response: InviteToContextResponse,
+    context_id: ContextId,
+    invitee_id: PublicKey,
+) -> Response {
+    let location = format!("/admin-api/contexts/{context_id}/invitations/{invitee_id}/status");
+
+    let mut response = ApiResponse { payload: response }.into_response();
+
+    *response.status_mut() = StatusCode::CREATED;
+
+    if let Ok(value) = HeaderValue::from_str(&location) {
+        let _ignored = response.headers_mut().insert(LOCATION, value);
+    }
+
+    response
+}
+
+fn encode_payload(
+    payload: &ContextInvitationPayload,
+    encoding: Option<InviteEncoding>,
+) -> Option<Value> {
+    match encoding? {
+        InviteEncoding::Base64 => Some(Value::String(STANDARD.encode(payload.as_bytes()))),
+        InviteEncoding::Json => {
+            let (context_id, invitee_id, protocol, network, contract_id) = payload.parts().ok()?;
+
+            Some(json!({
+                "contextId": context_id,
+                "inviteeId": invitee_id,
+                "protocol": protocol,
+                "network": network,
+                "contractId": contract_id,
+            }))
+        }
+    }
+}
 
 pub async fn handler(
     Extension(state): Extension<Arc<AdminState>>,
+    Query(params): Query<InviteQueryParams>,
     Json(req): Json<InviteToContextRequest>,
 ) -> impl IntoResponse {
-    let result = state
+    if let Err(err) = req.validate() {
+        return err.into_response();
+    }
+
+    state.metrics.attempt("invite", req.context_id);
+
+    // Invites that don't name capabilities fall back to the context's
+    // configured onboarding defaults; the delegation check below still
+    // applies to them.
+    let mut req = req;
+
+    if req.capabilities.is_empty() {
+        req.capabilities = match get_default_capabilities(&state.store, req.context_id) {
+            Ok(defaults) => defaults.unwrap_or_default(),
+            Err(err) => {
+                return parse_api_error(err).into_response();
+            }
+        };
+    }
+
+    // Least-privilege delegation: the inviter can only pass on
+    // capabilities they themselves hold, checked one by one so the
+    // rejection names the specific missing capability.
+    for &capability in &req.capabilities {
+        if let Err(err) =
+            require_capability(&state.ctx_manager, req.context_id, req.inviter_id, capability)
+                .await
+        {
+            state.metrics.denial("invite", req.context_id);
+
+            return err.into_response();
+        }
+    }
+
+    // The TTL is checked only after the permission check so an
+    // unauthorized inviter learns nothing about accepted parameters.
+    let expiry = match req.expires_in_secs {
+        None => None,
+        Some(0) => {
+            return ApiError {
+                status_code: StatusCode::BAD_REQUEST,
+                code: ApiErrorCode::InvalidRequest,
+                message: "expiresInSecs must be at least 1 second".into(),
+            }
+            .into_response();
+        }
+        Some(secs) => {
+            let Some(ttl) = i64::try_from(secs).ok().and_then(Duration::try_seconds) else {
+                return ApiError {
+                    status_code: StatusCode::BAD_REQUEST,
+                    code: ApiErrorCode::InvalidRequest,
+                    message: "expiresInSecs is too far in the future".into(),
+                }
+                .into_response();
+            };
+
+            Some(Utc::now() + ttl)
+        }
+    };
+
+    // Label the invitation with the context's registered alias where one
+    // exists, falling back to the opaque id.
+    let context_name = state
         .ctx_manager
-        .invite_to_context(req.context_id, req.inviter_id, req.invitee_id)
-        .await
-        .map_err(parse_api_error);
+        .list_aliases::<ContextId>(None)
+        .ok()
+        .and_then(|aliases| {
+            aliases
+                .into_iter()
+                .find(|(_, context_id, _)| *context_id == req.context_id)
+                .map(|(alias, _, _)| alias.to_string())
+        })
+        .or_else(|| Some(req.context_id.to_string()));
+
+    // Replaying a keyed request returns the invitation minted the first
+    // time around, so retries don't create duplicate invitations. A
+    // lapsed invitation is never replayed; the request falls through and
+    // mints a fresh one.
+    if let Some(key) = &req.idempotency_key {
+        let now = Utc::now();
 
-    match result {
-        Ok(invitation_payload) => ApiResponse {
-            payload: InviteToContextResponse::new(invitation_payload),
+        let minted = {
+            let mut cache = state
+                .invite_idempotency
+                .lock()
+                .expect("idempotency cache poisoned");
+
+            // Eviction rides along on use, keeping the cache bounded by
+            // the retention window.
+            cache.retain(|_, record| !record.is_stale(now));
+
+            cache.get(key).cloned()
+        };
+
+        if let Some(record) = minted {
+            let mut response =
+                InviteToContextResponse::new(Some(record.payload), context_name.clone());
+
+            response.encoded = response
+                .data
+                .as_ref()
+                .and_then(|payload| encode_payload(payload, params.encoding));
+
+            state.metrics.success("invite", req.context_id);
+
+            return ApiResponse { payload: response }.into_response();
+        }
+    }
+
+    // The payload carries the deadline itself, so any node asked to
+    // accept the invitation can refuse it once lapsed.
+    let expires_at = expiry.and_then(|deadline| u64::try_from(deadline.timestamp()).ok());
+
+    let mut invitation_payload = None;
+
+    for _attempt in 0..MAX_INVITE_ATTEMPTS {
+        match state
+            .ctx_manager
+            .invite_to_context(req.context_id, req.inviter_id, req.invitee_id, expires_at)
+            .await
+        {
+            Ok(payload) => {
+                invitation_payload = Some(payload);
+
+                break;
+            }
+            Err(err) => {
+                // Concurrent membership changes invalidate the nonce we
+                // signed with; minting again picks up the fresh state.
+                if matches!(
+                    err.downcast_ref::<InviteToContextError>(),
+                    Some(InviteToContextError::MembershipConflict)
+                ) {
+                    continue;
+                }
+
+                return parse_api_error(err).into_response();
+            }
+        }
+    }
+
+    let Some(invitation_payload) = invitation_payload else {
+        return ApiError {
+            status_code: StatusCode::SERVICE_UNAVAILABLE,
+            code: ApiErrorCode::Conflict,
+            message: "invite conflicted with concurrent membership changes; retry later".into(),
         }
-        .into_response(),
-        Err(err) => err.into_response(),
+        .into_response();
+    };
+
+    // Remember the invitation so its status can be queried later.
+    if invitation_payload.is_some() {
+        let _ignored = state
+            .invitations
+            .lock()
+            .expect("invitation records poisoned")
+            .insert(
+                (req.context_id, req.invitee_id),
+                InvitationRecord {
+                    minted_at: Utc::now(),
+                    expiry,
+                },
+            );
+    }
+
+    if let (Some(key), Some(payload)) = (&req.idempotency_key, &invitation_payload) {
+        let _ignored = state
+            .invite_idempotency
+            .lock()
+            .expect("idempotency cache poisoned")
+            .insert(
+                key.clone(),
+                IdempotencyRecord {
+                    payload: payload.clone(),
+                    minted_at: Utc::now(),
+                    expiry,
+                },
+            );
+    }
+
+    // Inviting put the member on the contract's books, so the intended
+    // capability set can be granted right away - it is in place by the
+    // time they accept. `granted` stays empty if the grant failed, so
+    // admins can tell and re-grant manually.
+    let mut granted = Vec::new();
+
+    if !req.capabilities.is_empty() {
+        let invitee: ContextIdentity = req.invitee_id.rt().expect("infallible conversion");
+
+        let grants: Vec<(ContextIdentity, Capability)> = req
+            .capabilities
+            .iter()
+            .map(|&capability| (invitee, capability))
+            .collect();
+
+        match state
+            .ctx_manager
+            .grant_capabilities(req.context_id, req.inviter_id, &grants)
+            .await
+        {
+            Ok(_) => granted = req.capabilities.clone(),
+            Err(err) => error!(
+                context_id=%req.context_id,
+                invitee_id=%req.invitee_id,
+                ?err,
+                "failed to pre-grant capabilities with the invitation"
+            ),
+        }
+    }
+
+    let mut response = InviteToContextResponse::new(invitation_payload, context_name);
+
+    response.encoded = response
+        .data
+        .as_ref()
+        .and_then(|payload| encode_payload(payload, params.encoding));
+
+    response.granted = granted;
+
+    state.metrics.success("invite", req.context_id);
+
+    // No invitation was minted (unknown context or inviter), so there is
+    // no created resource to point a Location header at: plain 200 with
+    // `data: null`, as before invitations became addressable.
+    if response.data.is_none() {
+        return ApiResponse { payload: response }.into_response();
     }
+
+    created(response, req.context_id, req.invitee_id)
 }
@@ -3,8 +3,10 @@ use std::sync::Arc;
 use axum::response::IntoResponse;
 use axum::{Extension, Json};
 use calimero_server_primitives::admin::{JoinContextRequest, JoinContextResponse};
+use chrono::Utc;
+use reqwest::StatusCode;
 
-use crate::admin::service::{parse_api_error, ApiResponse};
+use crate::admin::service::{parse_api_error, ApiError, ApiErrorCode, ApiResponse};
 use crate::AdminState;
 
 pub async fn handler(
@@ -14,6 +16,28 @@ pub async fn handler(
         invitation_payload,
     }): Json<JoinContextRequest>,
 ) -> impl IntoResponse {
+    // An invitation minted with a TTL is refused once it lapses; the
+    // record kept at mint time is the authority on the deadline.
+    if let Ok((context_id, invitee_id, ..)) = invitation_payload.parts() {
+        let expired = state
+            .invitations
+            .lock()
+            .expect("invitation records poisoned")
+            .get(&(context_id, invitee_id))
+            .is_some_and(|record| record.expiry.is_some_and(|expiry| expiry <= Utc::now()));
+
+        if expired {
+            return ApiError {
+                status_code: StatusCode::BAD_REQUEST,
+                code: ApiErrorCode::InvalidRequest,
+                message: format!(
+                    "the invitation for `{invitee_id}` in context {context_id} has expired"
+                ),
+            }
+            .into_response();
+        }
+    }
+
     let result = state
         .ctx_manager
         .join_context(private_key, invitation_payload)
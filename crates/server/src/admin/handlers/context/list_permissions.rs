@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use axum::response::IntoResponse;
+use axum::{Extension, Json};
+use calimero_context_config::repr::{Repr, ReprTransmute};
+use calimero_context_config::types::ContextIdentity;
+use calimero_primitives::context::ContextId;
+use calimero_primitives::identity::PublicKey;
+use calimero_server_primitives::admin::ListPermissionsResponse;
+use serde::Deserialize;
+
+use crate::admin::service::{parse_api_error, ApiResponse};
+use crate::AdminState;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListPermissionsRequest {
+    pub context_id: ContextId,
+    /// Scope the listing to one member; left unset, every member that
+    /// holds at least one capability is returned.
+    #[serde(default)]
+    pub member_id: Option<PublicKey>,
+}
+
+pub async fn handler(
+    Extension(state): Extension<Arc<AdminState>>,
+    Json(req): Json<ListPermissionsRequest>,
+) -> impl IntoResponse {
+    let context = match state.ctx_manager.get_context(&req.context_id) {
+        Ok(Some(context)) => context,
+        Ok(None) => {
+            return parse_api_error(eyre::eyre!("Context not found")).into_response();
+        }
+        Err(err) => {
+            return parse_api_error(err).into_response();
+        }
+    };
+
+    // Who holds what is readable by anyone who can reach the admin API,
+    // same as `GET /contexts/:id/capabilities`; a freshly joined member
+    // needs it to discover their own standing.
+
+    // One identity scopes the lookup to that member; an empty list asks
+    // the config contract for everyone holding at least one capability.
+    let identities: Vec<ContextIdentity> = req
+        .member_id
+        .map(|member| member.rt().expect("infallible conversion"))
+        .into_iter()
+        .collect();
+
+    match state
+        .ctx_manager
+        .get_capabilities(context.id, &identities)
+        .await
+    {
+        Ok(privileges) => ApiResponse {
+            payload: ListPermissionsResponse::new(
+                privileges
+                    .into_iter()
+                    .map(|(signer_id, capabilities)| (Repr::new(signer_id), capabilities))
+                    .collect(),
+            ),
+        }
+        .into_response(),
+        Err(err) => parse_api_error(err).into_response(),
+    }
+}
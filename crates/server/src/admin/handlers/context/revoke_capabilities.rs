@@ -3,19 +3,29 @@ use std::sync::Arc;
 use axum::extract::{Json, Path};
 use axum::response::IntoResponse;
 use axum::Extension;
-use calimero_context_config::repr::Repr;
-use calimero_context_config::types::{Capability, ContextIdentity};
+use calimero_context_config::repr::{Repr, ReprTransmute};
+use calimero_context_config::types::{Capability, ContextIdentity, SignerId};
 use calimero_primitives::context::ContextId;
 use calimero_primitives::identity::PublicKey;
+use calimero_server_primitives::admin::RevokeCapabilitiesResponse;
 use serde::Deserialize;
+use tracing::info;
 
 use crate::admin::service::{parse_api_error, ApiResponse};
+use crate::admin::validation::Validate;
 use crate::AdminState;
 
 #[derive(Deserialize, Debug)]
 pub struct RevokeCapabilitiesRequest {
     pub capabilities: Vec<(Repr<ContextIdentity>, Capability)>,
+    /// Members to strip of every capability, without enumerating them.
+    #[serde(default)]
+    pub revoke_all: Vec<Repr<ContextIdentity>>,
     pub signer_id: PublicKey,
+    /// Free-form operator note on why the revocation happened, kept in
+    /// the node's log for later audits.
+    #[serde(default)]
+    pub reason: Option<String>,
 }
 
 pub async fn handler(
@@ -23,6 +33,12 @@ pub async fn handler(
     Extension(state): Extension<Arc<AdminState>>,
     Json(request): Json<RevokeCapabilitiesRequest>,
 ) -> impl IntoResponse {
+    if let Err(err) = request.validate() {
+        return err.into_response();
+    }
+
+    state.metrics.attempt("revoke", context_id);
+
     let context = match state.ctx_manager.get_context(&context_id) {
         Ok(Some(context)) => context,
         Ok(None) => {
@@ -37,14 +53,66 @@ pub async fn handler(
         .capabilities
         .into_iter()
         .map(|(identity_repr, capability)| (*identity_repr, capability))
+        .chain(request.revoke_all.into_iter().flat_map(|identity_repr| {
+            Capability::ALL
+                .into_iter()
+                .map(move |capability| (*identity_repr, capability))
+        }))
         .collect();
 
-    match state
+    if let Err(err) = state
         .ctx_manager
         .revoke_capabilities(context.id, request.signer_id, &capabilities_to_revoke)
         .await
     {
-        Ok(_) => ApiResponse { payload: () }.into_response(),
+        state.metrics.denial("revoke", context_id);
+
+        return parse_api_error(err).into_response();
+    }
+
+    state.metrics.success("revoke", context_id);
+
+    if let Some(reason) = &request.reason {
+        info!(
+            %context_id,
+            signer_id=%request.signer_id,
+            reason,
+            "capabilities revoked"
+        );
+    }
+
+    // Echo what the affected members still hold, so the revocation is
+    // self-verifying without a follow-up query.
+    let mut affected: Vec<ContextIdentity> = capabilities_to_revoke
+        .iter()
+        .map(|(identity, _)| *identity)
+        .collect();
+
+    affected.sort_unstable();
+    affected.dedup();
+
+    let revokees = affected;
+
+    match state.ctx_manager.get_capabilities(context.id, &revokees).await {
+        Ok(privileges) => {
+            // Members whose last capability went away drop out of the
+            // contract's answer; list them explicitly as empty.
+            let capabilities = revokees
+                .into_iter()
+                .map(|identity| {
+                    let signer_id: SignerId = identity.rt().expect("infallible conversion");
+
+                    let held = privileges.get(&signer_id).cloned().unwrap_or_default();
+
+                    (Repr::new(signer_id), held)
+                })
+                .collect();
+
+            ApiResponse {
+                payload: RevokeCapabilitiesResponse::new(capabilities),
+            }
+            .into_response()
+        }
         Err(err) => parse_api_error(err).into_response(),
     }
 }
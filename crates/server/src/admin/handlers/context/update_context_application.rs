@@ -10,7 +10,7 @@ use calimero_server_primitives::admin::{
 };
 use reqwest::StatusCode;
 
-use crate::admin::service::{parse_api_error, ApiError, ApiResponse};
+use crate::admin::service::{parse_api_error, ApiError, ApiErrorCode, ApiResponse};
 use crate::AdminState;
 
 pub async fn handler(
@@ -21,6 +21,7 @@ pub async fn handler(
     let Ok(context_id_result) = ContextId::from_str(&context_id) else {
         return ApiError {
             status_code: StatusCode::BAD_REQUEST,
+            code: ApiErrorCode::InvalidRequest,
             message: "Invalid context id".into(),
         }
         .into_response();
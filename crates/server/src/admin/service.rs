@@ -19,7 +19,9 @@ use tower_sessions::{MemoryStore, SessionManagerLayer};
 use tracing::info;
 
 use super::handlers::alias;
-use super::handlers::context::{grant_capabilities, revoke_capabilities};
+use super::handlers::context::{
+    get_capabilities, grant_capabilities, list_permissions, revoke_capabilities,
+};
 use super::handlers::did::delete_did_handler;
 use super::handlers::proposals::{
     get_context_storage_entries_handler, get_context_value_handler,
@@ -37,8 +39,9 @@ use crate::admin::handlers::applications::{
 };
 use crate::admin::handlers::challenge::request_challenge_handler;
 use crate::admin::handlers::context::{
-    create_context, delete_context, get_context, get_context_client_keys, get_context_identities,
-    get_context_storage, get_contexts, invite_to_context, join_context, update_context_application,
+    capability_ceiling, create_context, default_capabilities, delete_context, get_context,
+    get_context_client_keys, get_context_identities, get_context_storage, get_contexts,
+    get_invitation_status, invite_to_context, join_context, update_context_application,
 };
 use crate::admin::handlers::did::fetch_did_handler;
 use crate::admin::handlers::identity::generate_context_identity;
@@ -136,6 +139,10 @@ pub(crate) fn setup(
             "/contexts/:context_id/identities-owned",
             get(get_context_identities::handler),
         )
+        .route(
+            "/contexts/:context_id/capabilities",
+            get(get_capabilities::handler),
+        )
         .route(
             "/contexts/:context_id/capabilities/grant",
             post(grant_capabilities::handler),
@@ -144,7 +151,23 @@ pub(crate) fn setup(
             "/contexts/:context_id/capabilities/revoke",
             post(revoke_capabilities::handler),
         )
+        .route(
+            "/contexts/list-permissions",
+            post(list_permissions::handler),
+        )
+        .route(
+            "/contexts/:context_id/default-capabilities",
+            get(default_capabilities::get_handler).post(default_capabilities::set_handler),
+        )
+        .route(
+            "/contexts/:context_id/capability-ceiling",
+            get(capability_ceiling::get_handler).post(capability_ceiling::set_handler),
+        )
         .route("/contexts/invite", post(invite_to_context::handler))
+        .route(
+            "/contexts/:context_id/invitations/:invitee_id/status",
+            get(get_invitation_status::handler),
+        )
         .route("/contexts/join", post(join_context::handler))
         .route("/contexts", get(get_contexts::handler))
         .route(
@@ -154,6 +177,7 @@ pub(crate) fn setup(
         .route("/identity/keys", delete(delete_auth_keys_handler))
         .route("/generate-jwt-token", post(generate_jwt_token_handler))
         .route("/peers", get(get_peers_count_handler))
+        .route("/metrics", get(crate::metrics::handler))
         .nest("/alias", alias::service())
         .layer(Extension(Arc::clone(&shared_state)));
 
@@ -221,7 +245,35 @@ pub(crate) fn setup(
             get(get_contexts::handler).post(create_context::handler),
         )
         .route("/dev/contexts/invite", post(invite_to_context::handler))
+        .route(
+            "/dev/contexts/:context_id/invitations/:invitee_id/status",
+            get(get_invitation_status::handler),
+        )
         .route("/dev/contexts/join", post(join_context::handler))
+        .route(
+            "/dev/contexts/:context_id/capabilities",
+            get(get_capabilities::handler),
+        )
+        .route(
+            "/dev/contexts/:context_id/capabilities/grant",
+            post(grant_capabilities::handler),
+        )
+        .route(
+            "/dev/contexts/:context_id/capabilities/revoke",
+            post(revoke_capabilities::handler),
+        )
+        .route(
+            "/dev/contexts/list-permissions",
+            post(list_permissions::handler),
+        )
+        .route(
+            "/dev/contexts/:context_id/default-capabilities",
+            get(default_capabilities::get_handler).post(default_capabilities::set_handler),
+        )
+        .route(
+            "/dev/contexts/:context_id/capability-ceiling",
+            get(capability_ceiling::get_handler).post(capability_ceiling::set_handler),
+        )
         .route(
             "/dev/contexts/:context_id/application",
             post(update_context_application::handler),
@@ -243,6 +295,7 @@ pub(crate) fn setup(
             "/dev/contexts/:context_id/identities-owned",
             get(get_context_identities::handler),
         )
+        .route("/dev/metrics", get(crate::metrics::handler))
         .route("/dev/contexts/:context_id", delete(delete_context::handler))
         .route(
             "/dev/identity/context",
@@ -412,9 +465,36 @@ where
     }
 }
 
+/// Machine-readable reason for a failed request, serialized alongside
+/// the message so clients can branch on it without parsing message text
+/// or the HTTP status line.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum ApiErrorCode {
+    /// The request is malformed or failed validation.
+    InvalidRequest,
+    /// The presented token is missing, malformed, expired or revoked.
+    InvalidToken,
+    /// The acting identity does not hold the capability the operation
+    /// requires.
+    MissingCapability,
+    /// The grant asked for capabilities above the context's policy
+    /// ceiling.
+    CeilingExceeded,
+    /// The named resource does not exist.
+    NotFound,
+    /// Concurrent administration conflicted with the operation; it may
+    /// succeed when retried.
+    Conflict,
+    /// The node failed internally; the request itself was fine.
+    Internal,
+}
+
 #[derive(Debug)]
 pub struct ApiError {
     pub(crate) status_code: StatusCode,
+    pub(crate) code: ApiErrorCode,
     pub(crate) message: String,
 }
 
@@ -428,7 +508,16 @@ impl Error for ApiError {}
 
 impl IntoResponse for ApiError {
     fn into_response(self) -> Response<Body> {
-        let body = json!({ "error": self.message }).to_string();
+        // Every handler funnels errors through here, so clients can rely on
+        // this shape regardless of which endpoint failed.
+        let body = json!({
+            "error": {
+                "code": self.code,
+                "status": self.status_code.canonical_reason().unwrap_or("Unknown"),
+                "message": self.message,
+            }
+        })
+        .to_string();
         Response::builder()
             .status(&self.status_code)
             .header("Content-Type", "application/json")
@@ -443,6 +532,7 @@ pub fn parse_api_error(err: Report) -> ApiError {
         Ok(api_error) => api_error,
         Err(original_error) => ApiError {
             status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            code: ApiErrorCode::Internal,
             message: original_error.to_string(),
         },
     }
@@ -1,5 +1,8 @@
+pub mod capability_ceiling;
 pub mod client_keys;
+pub mod default_capabilities;
 pub mod did;
+pub mod grant_expiries;
 pub mod jwt_secret;
 pub mod jwt_token;
 pub mod root_key;
@@ -0,0 +1,64 @@
+use calimero_context_config::types::Capability;
+use calimero_primitives::context::ContextId;
+use calimero_store::entry::{Entry, Json};
+use calimero_store::key::Generic;
+use calimero_store::Store;
+use eyre::Result as EyreResult;
+
+struct CapabilityCeilingEntry {
+    key: Generic,
+}
+
+impl Entry for CapabilityCeilingEntry {
+    type Key = Generic;
+    type Codec = Json;
+    type DataType<'a> = Vec<Capability>;
+
+    fn key(&self) -> &Self::Key {
+        &self.key
+    }
+}
+
+impl CapabilityCeilingEntry {
+    fn new(context_id: ContextId) -> Self {
+        Self {
+            key: Generic::new(*b"ctx_cap_ceiling:", *context_id),
+        }
+    }
+}
+
+/// The context's policy ceiling, where one is set.
+pub fn get_capability_ceiling(
+    store: &Store,
+    context_id: ContextId,
+) -> EyreResult<Option<Vec<Capability>>> {
+    let entry = CapabilityCeilingEntry::new(context_id);
+    let handle = store.handle();
+
+    handle.get(&entry).map_err(Into::into)
+}
+
+/// Replaces the context's policy ceiling. Persisted, so the policy keeps
+/// constraining grants across restarts.
+pub fn set_capability_ceiling(
+    store: &Store,
+    context_id: ContextId,
+    capabilities: Vec<Capability>,
+) -> EyreResult<()> {
+    let entry = CapabilityCeilingEntry::new(context_id);
+    let mut handle = store.handle();
+
+    handle.put(&entry, &capabilities)?;
+
+    Ok(())
+}
+
+/// Clears the ceiling, so grants in the context are unconstrained again.
+pub fn clear_capability_ceiling(store: &Store, context_id: ContextId) -> EyreResult<()> {
+    let entry = CapabilityCeilingEntry::new(context_id);
+    let mut handle = store.handle();
+
+    handle.delete(&entry)?;
+
+    Ok(())
+}
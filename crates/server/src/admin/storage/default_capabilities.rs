@@ -0,0 +1,64 @@
+use calimero_context_config::types::Capability;
+use calimero_primitives::context::ContextId;
+use calimero_store::entry::{Entry, Json};
+use calimero_store::key::Generic;
+use calimero_store::Store;
+use eyre::Result as EyreResult;
+
+struct DefaultCapabilitiesEntry {
+    key: Generic,
+}
+
+impl Entry for DefaultCapabilitiesEntry {
+    type Key = Generic;
+    type Codec = Json;
+    type DataType<'a> = Vec<Capability>;
+
+    fn key(&self) -> &Self::Key {
+        &self.key
+    }
+}
+
+impl DefaultCapabilitiesEntry {
+    fn new(context_id: ContextId) -> Self {
+        Self {
+            key: Generic::new(*b"ctx_default_caps", *context_id),
+        }
+    }
+}
+
+/// The context's onboarding defaults, where a policy is set.
+pub fn get_default_capabilities(
+    store: &Store,
+    context_id: ContextId,
+) -> EyreResult<Option<Vec<Capability>>> {
+    let entry = DefaultCapabilitiesEntry::new(context_id);
+    let handle = store.handle();
+
+    handle.get(&entry).map_err(Into::into)
+}
+
+/// Replaces the context's onboarding defaults. Persisted, so invitations
+/// keep pre-granting the configured set across restarts.
+pub fn set_default_capabilities(
+    store: &Store,
+    context_id: ContextId,
+    capabilities: Vec<Capability>,
+) -> EyreResult<()> {
+    let entry = DefaultCapabilitiesEntry::new(context_id);
+    let mut handle = store.handle();
+
+    handle.put(&entry, &capabilities)?;
+
+    Ok(())
+}
+
+/// Clears the policy, so invitations grant nothing unless asked.
+pub fn clear_default_capabilities(store: &Store, context_id: ContextId) -> EyreResult<()> {
+    let entry = DefaultCapabilitiesEntry::new(context_id);
+    let mut handle = store.handle();
+
+    handle.delete(&entry)?;
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use calimero_context_config::repr::Repr;
+use calimero_context_config::types::{Capability, ContextIdentity};
+use calimero_primitives::context::ContextId;
+use calimero_primitives::identity::PublicKey;
+use calimero_store::entry::{Entry, Json};
+use calimero_store::key::Generic;
+use calimero_store::Store;
+use chrono::{DateTime, Utc};
+use eyre::Result as EyreResult;
+use serde::{Deserialize, Serialize};
+
+struct GrantExpiriesEntry {
+    key: Generic,
+}
+
+impl Entry for GrantExpiriesEntry {
+    type Key = Generic;
+    type Codec = Json;
+    type DataType<'a> = Vec<PendingGrantExpiry>;
+
+    fn key(&self) -> &Self::Key {
+        &self.key
+    }
+}
+
+impl GrantExpiriesEntry {
+    fn new() -> Self {
+        Self {
+            key: Generic::new(*b"grant_expiries::", [0; 32]),
+        }
+    }
+}
+
+/// A time-boxed grant the node still has to undo. Persisted so that a
+/// restart before the deadline re-arms the revocation instead of
+/// silently turning a temporary elevation into a permanent one.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PendingGrantExpiry {
+    /// Distinguishes this record from others with the same deadline.
+    pub id: u64,
+    pub context_id: ContextId,
+    /// The identity that signed the grant, reused to sign the
+    /// revocation.
+    pub signer_id: PublicKey,
+    /// Only the capabilities the grant actually added; whatever the
+    /// grantees held beforehand is not listed and survives the expiry.
+    pub capabilities: Vec<(Repr<ContextIdentity>, Capability)>,
+    pub expiry: DateTime<Utc>,
+}
+
+/// Every expiry the node has promised to enforce but not yet fired.
+pub fn get_pending_expiries(store: &Store) -> EyreResult<Vec<PendingGrantExpiry>> {
+    let entry = GrantExpiriesEntry::new();
+    let handle = store.handle();
+
+    Ok(handle.get(&entry)?.unwrap_or_default())
+}
+
+/// Records an expiry before the grant it undoes is scheduled, so the
+/// promise survives a restart.
+pub fn add_pending_expiry(store: &Store, pending: &PendingGrantExpiry) -> EyreResult<()> {
+    let mut expiries = get_pending_expiries(store)?;
+
+    expiries.push(pending.clone());
+
+    let entry = GrantExpiriesEntry::new();
+    let mut handle = store.handle();
+
+    handle.put(&entry, &expiries)?;
+
+    Ok(())
+}
+
+/// Drops the record once its revocation fired (or the grant it belonged
+/// to never happened).
+pub fn remove_pending_expiry(store: &Store, id: u64) -> EyreResult<()> {
+    let mut expiries = get_pending_expiries(store)?;
+
+    expiries.retain(|pending| pending.id != id);
+
+    let entry = GrantExpiriesEntry::new();
+    let mut handle = store.handle();
+
+    handle.put(&entry, &expiries)?;
+
+    Ok(())
+}
@@ -16,7 +16,7 @@ use tracing::info;
 use web3::signing::{keccak256, recover};
 
 use crate::admin::handlers::root_keys::store_root_key;
-use crate::admin::service::{parse_api_error, ApiError};
+use crate::admin::service::{parse_api_error, ApiError, ApiErrorCode};
 use crate::admin::storage::root_key::{get_root_key, has_near_account_root_key};
 use crate::verifywalletsignatures::icp::verify_internet_identity_signature;
 use crate::verifywalletsignatures::near::{has_near_key, verify_near_signature};
@@ -47,12 +47,14 @@ pub async fn verify_node_signature(
                 SignatureMetadataEnum::ETH(_) => {
                     return Err(ApiError {
                         status_code: StatusCode::BAD_REQUEST,
+                        code: ApiErrorCode::InvalidRequest,
                         message: "Invalid metadata.".into(),
                     })
                 }
                 _ => {
                     return Err(ApiError {
                         status_code: StatusCode::BAD_REQUEST,
+                        code: ApiErrorCode::InvalidRequest,
                         message: "Unsupported metadata.".into(),
                     })
                 }
@@ -61,6 +63,7 @@ pub async fn verify_node_signature(
             let WalletSignature::String(signature_str) = wallet_signature else {
                 return Err(ApiError {
                     status_code: StatusCode::BAD_REQUEST,
+                    code: ApiErrorCode::InvalidRequest,
                     message: "Invalid wallet signature type.".into(),
                 });
             };
@@ -77,6 +80,7 @@ pub async fn verify_node_signature(
             if !result {
                 return Err(ApiError {
                     status_code: StatusCode::BAD_REQUEST,
+                    code: ApiErrorCode::InvalidRequest,
                     message: "Node signature is invalid. Please check the signature.".into(),
                 });
             }
@@ -88,10 +92,12 @@ pub async fn verify_node_signature(
                 SignatureMetadataEnum::ETH(metadata) => Ok(metadata), // Return Ok for the valid case
                 SignatureMetadataEnum::NEAR(_) => Err(ApiError {
                     status_code: StatusCode::BAD_REQUEST,
+                    code: ApiErrorCode::InvalidRequest,
                     message: "Invalid metadata.".into(),
                 }),
                 _ => Err(ApiError {
                     status_code: StatusCode::BAD_REQUEST,
+                    code: ApiErrorCode::InvalidRequest,
                     message: "Unsupported metadata.".into(),
                 }),
             }?;
@@ -99,6 +105,7 @@ pub async fn verify_node_signature(
             let WalletSignature::String(signature_str) = wallet_signature else {
                 return Err(ApiError {
                     status_code: StatusCode::BAD_REQUEST,
+                    code: ApiErrorCode::InvalidRequest,
                     message: "Invalid wallet signature type.".into(),
                 });
             };
@@ -120,6 +127,7 @@ pub async fn verify_node_signature(
                 _ => {
                     return Err(ApiError {
                         status_code: StatusCode::BAD_REQUEST,
+                        code: ApiErrorCode::InvalidRequest,
                         message: "Invalid metadata.".into(),
                     })
                 }
@@ -133,6 +141,7 @@ pub async fn verify_node_signature(
                 _ => {
                     return Err(ApiError {
                         status_code: StatusCode::BAD_REQUEST,
+                        code: ApiErrorCode::InvalidRequest,
                         message: "Invalid wallet signature type for Starknet.".into(),
                     })
                 }
@@ -141,6 +150,7 @@ pub async fn verify_node_signature(
             let Some(network_metadata) = &wallet_metadata.network_metadata else {
                 return Err(ApiError {
                     status_code: StatusCode::BAD_REQUEST,
+                    code: ApiErrorCode::InvalidRequest,
                     message: "Missing network_metadata for Starknet.".into(),
                 });
             };
@@ -165,6 +175,7 @@ pub async fn verify_node_signature(
                     let Some(wallet_address) = &wallet_metadata.wallet_address else {
                         return Err(ApiError {
                             status_code: StatusCode::BAD_REQUEST,
+                            code: ApiErrorCode::InvalidRequest,
                             message: "Wallet address not present.".into(),
                         });
                     };
@@ -180,6 +191,7 @@ pub async fn verify_node_signature(
                 _ => {
                     return Err(ApiError {
                         status_code: StatusCode::BAD_REQUEST,
+                        code: ApiErrorCode::InvalidRequest,
                         message: "Invalid wallet name for Starknet.".into(),
                     })
                 }
@@ -198,6 +210,7 @@ pub async fn verify_node_signature(
             let WalletSignature::String(delegation_chain) = wallet_signature else {
                 return Err(ApiError {
                     status_code: StatusCode::BAD_REQUEST,
+                    code: ApiErrorCode::InvalidRequest,
                     message: "Invalid wallet signature type.".into(),
                 });
             };
@@ -206,6 +219,7 @@ pub async fn verify_node_signature(
                 let signed_delegation_chain_json =
                     serde_json::from_str(delegation_chain).map_err(|_| ApiError {
                         status_code: StatusCode::BAD_REQUEST,
+                        code: ApiErrorCode::InvalidRequest,
                         message: "Failed to serialize delegation chain.".into(),
                     })?;
                 verify_internet_identity_signature(
@@ -217,6 +231,7 @@ pub async fn verify_node_signature(
             } else {
                 return Err(ApiError {
                     status_code: StatusCode::BAD_REQUEST,
+                    code: ApiErrorCode::InvalidRequest,
                     message: "Invalid wallet name for Internet Computer.".into(),
                 });
             }
@@ -225,6 +240,7 @@ pub async fn verify_node_signature(
         }
         _ => Err(ApiError {
             status_code: StatusCode::BAD_REQUEST,
+            code: ApiErrorCode::InvalidRequest,
             message: "Unsupported wallet type.".into(),
         }),
     }
@@ -253,6 +269,7 @@ pub async fn validate_challenge(
     if is_older_than_15_minutes(req.payload.message.timestamp) {
         return Err(ApiError {
             status_code: StatusCode::BAD_REQUEST,
+            code: ApiErrorCode::InvalidRequest,
             message: " Challenge is too old. Please request a new challenge.".into(),
         });
     }
@@ -306,6 +323,7 @@ pub fn construct_node_challenge(
 pub fn decode_signature(encoded_sig: &String) -> Result<Vec<u8>, ApiError> {
     STANDARD.decode(encoded_sig).map_err(|_| ApiError {
         status_code: StatusCode::BAD_REQUEST,
+        code: ApiErrorCode::InvalidRequest,
         message: "Failed to decode signature".into(),
     })
 }
@@ -321,6 +339,7 @@ pub fn decode_signature(encoded_sig: &String) -> Result<Vec<u8>, ApiError> {
 pub fn serialize_node_challenge(challenge: &NodeChallengeMessage) -> Result<String, ApiError> {
     to_json_string(challenge).map_err(|_| ApiError {
         status_code: StatusCode::INTERNAL_SERVER_ERROR,
+        code: ApiErrorCode::Internal,
         message: "Failed to deserialize challenge data".into(),
     })
 }
@@ -345,6 +364,7 @@ pub fn verify_signature(
     } else {
         Err(ApiError {
             status_code: StatusCode::BAD_REQUEST,
+            code: ApiErrorCode::InvalidRequest,
             message: "Node signature is invalid.".into(),
         })
     }
@@ -385,6 +405,7 @@ pub async fn validate_root_key_exists(
             info!("Error getting root key: {}", e);
             ApiError {
                 status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                code: ApiErrorCode::Internal,
                 message: e.to_string(),
             }
         })?
@@ -397,6 +418,7 @@ pub async fn validate_root_key_exists(
                 .as_deref()
                 .ok_or(ApiError {
                     status_code: StatusCode::BAD_REQUEST,
+                    code: ApiErrorCode::InvalidRequest,
                     message: "Wallet address not present".to_owned(),
                 })?;
 
@@ -405,6 +427,7 @@ pub async fn validate_root_key_exists(
                     if keys.is_empty() {
                         Err(ApiError {
                             status_code: StatusCode::BAD_REQUEST,
+                            code: ApiErrorCode::InvalidRequest,
                             message: "Root key does not exist".into(),
                         })
                     } else {
@@ -415,6 +438,7 @@ pub async fn validate_root_key_exists(
                     info!("Error checking if near client key exists: {}", err);
                     ApiError {
                         status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                        code: ApiErrorCode::Internal,
                         message: err.to_string(),
                     }
                 })??;
@@ -425,6 +449,7 @@ pub async fn validate_root_key_exists(
                 NearNetworkId::Testnet => Ok("https://rpc.testnet.near.org"),
                 NearNetworkId::Custom(_) | _ => Err(ApiError {
                     status_code: StatusCode::BAD_REQUEST,
+                    code: ApiErrorCode::InvalidRequest,
                     message: "Unknown NEAR network ID".into(),
                 }),
             }?;
@@ -432,6 +457,7 @@ pub async fn validate_root_key_exists(
             if !has_near_key(&req.wallet_metadata.verifying_key, wallet_address, rpc_url).await? {
                 return Err(ApiError {
                     status_code: StatusCode::BAD_REQUEST,
+                    code: ApiErrorCode::InvalidRequest,
                     message: format!(
                         "Provided public key does not belong to account {wallet_address:?}",
                     ),
@@ -448,6 +474,7 @@ pub async fn validate_root_key_exists(
             } else {
                 return Err(ApiError {
                     status_code: StatusCode::BAD_REQUEST,
+                    code: ApiErrorCode::InvalidRequest,
                     message: "Root key does not exist for given wallet".into(),
                 });
             }
@@ -457,6 +484,7 @@ pub async fn validate_root_key_exists(
 
         return Err(ApiError {
             status_code: StatusCode::BAD_REQUEST,
+            code: ApiErrorCode::InvalidRequest,
             message: "Root key does not exist".into(),
         });
     }
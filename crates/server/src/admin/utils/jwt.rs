@@ -9,7 +9,7 @@ use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation}
 use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 
-use crate::admin::service::ApiError;
+use crate::admin::service::{ApiError, ApiErrorCode};
 use crate::admin::storage::jwt_secret::get_jwt_secret;
 use crate::admin::storage::jwt_token::{
     create_refresh_token, delete_refresh_token, get_refresh_token,
@@ -43,12 +43,14 @@ pub fn generate_jwt_tokens(req: JwtTokenRequest, store: &Store) -> Result<JwtTok
         Ok(None) => {
             return Err(ApiError {
                 status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                code: ApiErrorCode::Internal,
                 message: "JWT secret not found".into(),
             });
         }
         Err(err) => {
             return Err(ApiError {
                 status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                code: ApiErrorCode::Internal,
                 message: format!("Failed to get JWT secret: {err}"),
             });
         }
@@ -79,6 +81,7 @@ pub fn generate_jwt_tokens(req: JwtTokenRequest, store: &Store) -> Result<JwtTok
     )
     .map_err(|err| ApiError {
         status_code: StatusCode::BAD_REQUEST,
+        code: ApiErrorCode::InvalidRequest,
         message: format!("Failed to generate access token: {err}"),
     })?;
 
@@ -105,6 +108,7 @@ pub fn generate_jwt_tokens(req: JwtTokenRequest, store: &Store) -> Result<JwtTok
     )
     .map_err(|err| ApiError {
         status_code: StatusCode::BAD_REQUEST,
+        code: ApiErrorCode::InvalidRequest,
         message: format!("Failed to generate refresh token: {err}"),
     })?;
 
@@ -118,6 +122,7 @@ pub fn generate_jwt_tokens(req: JwtTokenRequest, store: &Store) -> Result<JwtTok
     )
     .map_err(|err| ApiError {
         status_code: StatusCode::INTERNAL_SERVER_ERROR,
+        code: ApiErrorCode::Internal,
         message: format!("Failed to store refresh token: {err}"),
     })?;
 
@@ -137,12 +142,14 @@ pub fn refresh_access_token(refresh_token: &str, store: &Store) -> Result<JwtTok
         Ok(None) => {
             return Err(ApiError {
                 status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                code: ApiErrorCode::Internal,
                 message: "JWT secret not found".into(),
             });
         }
         Err(err) => {
             return Err(ApiError {
                 status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                code: ApiErrorCode::Internal,
                 message: format!("Failed to get JWT secret: {err}"),
             });
         }
@@ -156,6 +163,7 @@ pub fn refresh_access_token(refresh_token: &str, store: &Store) -> Result<JwtTok
     )
     .map_err(|err| ApiError {
         status_code: StatusCode::FORBIDDEN,
+        code: ApiErrorCode::InvalidToken,
         message: format!("Invalid refresh token: {err}"),
     })?;
 
@@ -163,6 +171,7 @@ pub fn refresh_access_token(refresh_token: &str, store: &Store) -> Result<JwtTok
     if token_data.claims.token_type != TokenType::Refresh {
         return Err(ApiError {
             status_code: StatusCode::FORBIDDEN,
+            code: ApiErrorCode::InvalidToken,
             message: "Invalid token type".into(),
         });
     }
@@ -177,6 +186,7 @@ pub fn refresh_access_token(refresh_token: &str, store: &Store) -> Result<JwtTok
     if token_data.claims.exp < now {
         return Err(ApiError {
             status_code: StatusCode::FORBIDDEN,
+            code: ApiErrorCode::InvalidToken,
             message: "Refresh token has expired".into(),
         });
     }
@@ -192,6 +202,7 @@ pub fn refresh_access_token(refresh_token: &str, store: &Store) -> Result<JwtTok
         Ok(Some(token)) => {
             let refresh_token = from_utf8(token.refresh_token()).map_err(|err| ApiError {
                 status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                code: ApiErrorCode::Internal,
                 message: format!("Failed to parse refresh token: {err}"),
             })?;
             refresh_token.to_owned()
@@ -199,12 +210,14 @@ pub fn refresh_access_token(refresh_token: &str, store: &Store) -> Result<JwtTok
         Ok(None) => {
             return Err(ApiError {
                 status_code: StatusCode::FORBIDDEN,
+                code: ApiErrorCode::InvalidToken,
                 message: "Refresh token not found".into(),
             });
         }
         Err(err) => {
             return Err(ApiError {
                 status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                code: ApiErrorCode::Internal,
                 message: format!("Failed to get refresh token: {err}"),
             });
         }
@@ -213,12 +226,14 @@ pub fn refresh_access_token(refresh_token: &str, store: &Store) -> Result<JwtTok
     if refresh_token_db != refresh_token {
         return Err(ApiError {
             status_code: StatusCode::FORBIDDEN,
+            code: ApiErrorCode::InvalidToken,
             message: "Refresh token mismatch".into(),
         });
     }
 
     delete_refresh_token(store, &db_key_hash).map_err(|err| ApiError {
         status_code: StatusCode::INTERNAL_SERVER_ERROR,
+        code: ApiErrorCode::Internal,
         message: format!("Failed to delete refresh token: {err}"),
     })?;
 
@@ -245,12 +260,14 @@ pub fn refresh_access_token(refresh_token: &str, store: &Store) -> Result<JwtTok
     )
     .map_err(|err| ApiError {
         status_code: StatusCode::BAD_REQUEST,
+        code: ApiErrorCode::InvalidRequest,
         message: format!("Failed to generate access token: {err}"),
     })?;
 
     let payload = JwtTokenRequest::new(context_id, executor);
     let jwt_tokens = generate_jwt_tokens(payload, store).map_err(|err| ApiError {
         status_code: StatusCode::BAD_REQUEST,
+        code: ApiErrorCode::InvalidRequest,
         message: format!("Failed to generate access token: {err}"),
     })?;
 
@@ -0,0 +1,80 @@
+use calimero_server_primitives::admin::InviteToContextRequest;
+use reqwest::StatusCode;
+
+use crate::admin::handlers::context::grant_capabilities::GrantCapabilitiesRequest;
+use crate::admin::handlers::context::revoke_capabilities::RevokeCapabilitiesRequest;
+use crate::admin::service::{ApiError, ApiErrorCode};
+
+/// Field-level validation run on a deserialized request body before any
+/// business logic; failures become a 400 naming the offending field.
+pub trait Validate {
+    fn validate(&self) -> Result<(), ApiError>;
+}
+
+fn bad_request(message: impl Into<String>) -> ApiError {
+    ApiError {
+        status_code: StatusCode::BAD_REQUEST,
+        code: ApiErrorCode::InvalidRequest,
+        message: message.into(),
+    }
+}
+
+impl Validate for InviteToContextRequest {
+    fn validate(&self) -> Result<(), ApiError> {
+        if *self.context_id == [0; 32] {
+            return Err(bad_request("contextId must not be the zero id"));
+        }
+
+        if *self.inviter_id == [0; 32] {
+            return Err(bad_request("inviterId must not be the zero key"));
+        }
+
+        if *self.invitee_id == [0; 32] {
+            return Err(bad_request("inviteeId must not be the zero key"));
+        }
+
+        if self.inviter_id == self.invitee_id {
+            return Err(bad_request("inviteeId must differ from inviterId"));
+        }
+
+        if self
+            .idempotency_key
+            .as_ref()
+            .is_some_and(|key| key.is_empty())
+        {
+            return Err(bad_request("idempotencyKey must not be empty"));
+        }
+
+        Ok(())
+    }
+}
+
+impl Validate for GrantCapabilitiesRequest {
+    fn validate(&self) -> Result<(), ApiError> {
+        if self.capabilities.is_empty() {
+            return Err(bad_request("capabilities must not be empty"));
+        }
+
+        if *self.signer_id == [0; 32] {
+            return Err(bad_request("signer_id must not be the zero key"));
+        }
+
+        Ok(())
+    }
+}
+
+impl Validate for RevokeCapabilitiesRequest {
+    fn validate(&self) -> Result<(), ApiError> {
+        if self.capabilities.is_empty() && self.revoke_all.is_empty() {
+            return Err(bad_request(
+                "one of capabilities or revoke_all must be non-empty",
+            ));
+        }
+
+        if *self.signer_id == [0; 32] {
+            return Err(bad_request("signer_id must not be the zero key"));
+        }
+
+        Ok(())
+    }
+}
@@ -1,13 +1,17 @@
 use core::net::{IpAddr, SocketAddr};
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
 use admin::storage::jwt_secret::get_or_create_jwt_secret;
 use axum::http::Method;
 use axum::Router;
 use calimero_context::ContextManager;
 use calimero_node_primitives::ServerSender;
+use calimero_primitives::context::{ContextId, ContextInvitationPayload};
 use calimero_primitives::events::NodeEvent;
+use calimero_primitives::identity::PublicKey;
 use calimero_store::Store;
+use chrono::{DateTime, Duration, Utc};
 use config::ServerConfig;
 use eyre::{bail, Result as EyreResult};
 use libp2p::identity::Keypair;
@@ -25,6 +29,7 @@ pub mod admin;
 pub mod config;
 #[cfg(feature = "jsonrpc")]
 pub mod jsonrpc;
+pub mod metrics;
 #[cfg(feature = "admin")]
 mod middleware;
 mod verifywalletsignatures;
@@ -37,15 +42,65 @@ pub struct AdminState {
     pub store: Store,
     pub keypair: Keypair,
     pub ctx_manager: ContextManager,
+    /// Invitations already minted for a client-supplied idempotency key.
+    /// Stale records are evicted on use, so the map stays bounded by the
+    /// retention window no matter how many keys a client mints.
+    pub invite_idempotency: Mutex<HashMap<String, IdempotencyRecord>>,
+    /// Invitations minted by this node, keyed by context and invitee, so
+    /// admins can query whether they were accepted.
+    pub invitations: Mutex<HashMap<(ContextId, PublicKey), InvitationRecord>>,
+    /// Counters over admin operations, served at `/metrics` for scraping.
+    pub metrics: metrics::AdminMetrics,
+}
+
+/// How long idempotency records are retained. Records past the window
+/// are evicted whenever the cache is touched, which bounds how much
+/// memory client-supplied keys can pin.
+const IDEMPOTENCY_RETENTION_HOURS: i64 = 24;
+
+/// An invitation minted for a client-supplied idempotency key, kept so a
+/// retried request replays the original response instead of minting a
+/// duplicate invitation.
+#[derive(Clone, Debug)]
+pub struct IdempotencyRecord {
+    pub payload: ContextInvitationPayload,
+    /// When the record was created.
+    pub minted_at: DateTime<Utc>,
+    /// The invitation's own deadline, where one was set.
+    pub expiry: Option<DateTime<Utc>>,
+}
+
+impl IdempotencyRecord {
+    /// Whether the record must no longer be replayed: the invitation it
+    /// holds lapsed, or the record outlived the retention window.
+    #[must_use]
+    pub fn is_stale(&self, now: DateTime<Utc>) -> bool {
+        let aged_out = now - self.minted_at > Duration::hours(IDEMPOTENCY_RETENTION_HOURS);
+        let lapsed = self.expiry.is_some_and(|expiry| expiry <= now);
+
+        aged_out || lapsed
+    }
+}
+
+/// What the node remembers about an invitation it minted.
+#[derive(Clone, Copy, Debug)]
+pub struct InvitationRecord {
+    /// When the invitation was minted.
+    pub minted_at: DateTime<Utc>,
+    /// When the invitation lapses, where a deadline was set.
+    pub expiry: Option<DateTime<Utc>>,
 }
 
 impl AdminState {
     #[must_use]
-    pub const fn new(store: Store, keypair: Keypair, ctx_manager: ContextManager) -> Self {
+    pub fn new(store: Store, keypair: Keypair, ctx_manager: ContextManager) -> Self {
         Self {
             store,
             keypair,
             ctx_manager,
+            invite_idempotency: Mutex::new(HashMap::new()),
+            invitations: Mutex::new(HashMap::new()),
+            metrics: metrics::AdminMetrics::default(),
         }
     }
 }
@@ -132,6 +187,10 @@ pub async fn start(
 
     #[cfg(feature = "admin")]
     {
+        // Time-boxed grants promised before the last shutdown are kept:
+        // their revocations are re-armed from the store.
+        admin::handlers::context::grant_capabilities::rearm_pending_expiries(&shared_state);
+
         if let Some((api_path, router)) = setup(&config, store.clone(), shared_state) {
             if let Some((site_path, serve_dir)) = site(&config) {
                 app = app.nest_service(site_path, serve_dir);
@@ -0,0 +1,149 @@
+//! In-memory counters over administrative operations.
+//!
+//! Administrative churn - and failed permission checks in particular -
+//! often precedes security incidents, so the admin handlers count their
+//! attempts, denials and successes here, labelled by context. The
+//! counters reset on restart; a scraping dashboard is expected to handle
+//! that the way it handles any counter reset.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use axum::response::IntoResponse;
+use axum::Extension;
+use calimero_primitives::context::ContextId;
+
+use crate::AdminState;
+
+/// Most distinct contexts tracked per operation. Contexts beyond the cap
+/// share one `other` bucket, bounding label cardinality no matter how
+/// many contexts a node hosts.
+const MAX_TRACKED_CONTEXTS: usize = 64;
+
+/// Counters for one admin operation within one context.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OpCounters {
+    /// Requests that reached the handler, whatever their outcome.
+    pub attempts: u64,
+    /// Requests rejected by a permission check.
+    pub denials: u64,
+    /// Requests that completed.
+    pub successes: u64,
+}
+
+/// Counters over admin operations, keyed by operation name and context.
+#[derive(Debug, Default)]
+pub struct AdminMetrics {
+    counters: Mutex<HashMap<(&'static str, Option<ContextId>), OpCounters>>,
+}
+
+impl AdminMetrics {
+    pub fn attempt(&self, op: &'static str, context_id: ContextId) {
+        self.bump(op, context_id, |counters| counters.attempts += 1);
+    }
+
+    pub fn denial(&self, op: &'static str, context_id: ContextId) {
+        self.bump(op, context_id, |counters| counters.denials += 1);
+    }
+
+    pub fn success(&self, op: &'static str, context_id: ContextId) {
+        self.bump(op, context_id, |counters| counters.successes += 1);
+    }
+
+    fn bump(&self, op: &'static str, context_id: ContextId, bump: impl FnOnce(&mut OpCounters)) {
+        let mut counters = self.counters.lock().expect("admin metrics poisoned");
+
+        // A context gets its own label only while the operation's cap
+        // has room; every context past it folds into the shared bucket.
+        // The cap is per operation, so a busy operation cannot starve
+        // another of context labels.
+        let context_id = if counters.contains_key(&(op, Some(context_id))) {
+            Some(context_id)
+        } else {
+            let tracked = counters
+                .keys()
+                .filter(|(tracked_op, context)| *tracked_op == op && context.is_some())
+                .count();
+
+            (tracked < MAX_TRACKED_CONTEXTS).then_some(context_id)
+        };
+
+        bump(counters.entry((op, context_id)).or_default());
+    }
+
+    /// Renders every counter in the Prometheus text exposition format,
+    /// one sample per operation, context and outcome.
+    pub fn render(&self) -> String {
+        let counters = self.counters.lock().expect("admin metrics poisoned");
+
+        let mut samples: Vec<(String, u64)> = Vec::new();
+
+        for ((op, context_id), op_counters) in counters.iter() {
+            let context = context_id.map_or_else(|| "other".to_owned(), |id| id.to_string());
+
+            for (outcome, value) in [
+                ("attempt", op_counters.attempts),
+                ("denial", op_counters.denials),
+                ("success", op_counters.successes),
+            ] {
+                samples.push((
+                    format!(
+                        "calimero_admin_operations_total{{op=\"{op}\",context=\"{context}\",outcome=\"{outcome}\"}}"
+                    ),
+                    value,
+                ));
+            }
+        }
+
+        samples.sort();
+
+        let mut out = String::from(
+            "# HELP calimero_admin_operations_total Admin operations by context and outcome.\n# TYPE calimero_admin_operations_total counter\n",
+        );
+
+        for (label, value) in samples {
+            out.push_str(&format!("{label} {value}\n"));
+        }
+
+        out
+    }
+}
+
+/// Serves the counters as `text/plain` for a Prometheus-style scraper.
+pub async fn handler(Extension(state): Extension<Arc<AdminState>>) -> impl IntoResponse {
+    state.metrics.render()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn context_labels_are_bounded() {
+        let metrics = AdminMetrics::default();
+
+        for seed in 0..=MAX_TRACKED_CONTEXTS {
+            #[expect(clippy::cast_possible_truncation, reason = "seed stays below 256")]
+            let context_id = ContextId::from([seed as u8; 32]);
+
+            metrics.attempt("invite", context_id);
+        }
+
+        let counters = metrics.counters.lock().expect("not poisoned");
+
+        let tracked = counters
+            .keys()
+            .filter(|(_, context)| context.is_some())
+            .count();
+
+        assert_eq!(tracked, MAX_TRACKED_CONTEXTS);
+
+        // The context past the cap landed in the shared bucket.
+        assert_eq!(
+            counters
+                .get(&("invite", None))
+                .map(|counters| counters.attempts),
+            Some(1)
+        );
+    }
+}
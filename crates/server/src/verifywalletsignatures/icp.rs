@@ -9,7 +9,7 @@ use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use crate::admin::service::ApiError;
+use crate::admin::service::{ApiError, ApiErrorCode};
 
 /// A custom structure representing a `Delegation`, which includes a public key, an expiration time, and optional targets.
 /// This struct is used to parse values from JSON, where the public key and expiration are provided as hex values.
@@ -94,6 +94,7 @@ pub async fn verify_internet_identity_signature(
     let signed_delegation_chain: DelegationChain =
         serde_json::from_value(signed_delegation_chain_json).map_err(|e| ApiError {
             status_code: StatusCode::BAD_REQUEST,
+            code: ApiErrorCode::InvalidRequest,
             message: format!("Error parsing delegation_chain: {e}"),
         })?;
 
@@ -104,6 +105,7 @@ pub async fn verify_internet_identity_signature(
     if delegation.pubkey != challenge {
         return Err(ApiError {
             status_code: StatusCode::BAD_REQUEST,
+            code: ApiErrorCode::InvalidRequest,
             message: format!(
                 "delegation.pubkey {} does not match the challenge",
                 hex::encode(delegation.pubkey.clone())
@@ -115,17 +117,20 @@ pub async fn verify_internet_identity_signature(
     let cs_pk = CanisterSigPublicKey::try_from(signed_delegation_chain.public_key.as_slice())
         .map_err(|e| ApiError {
             status_code: StatusCode::BAD_REQUEST,
+            code: ApiErrorCode::InvalidRequest,
             message: format!("Invalid publicKey in delegation chain: {e}"),
         })?;
 
     let expected_ii_canister_id = Principal::from_text(ii_canister_id).map_err(|e| ApiError {
         status_code: StatusCode::BAD_REQUEST,
+        code: ApiErrorCode::InvalidRequest,
         message: format!("Invalid ii_canister_id: {e}"),
     })?;
 
     if cs_pk.canister_id != expected_ii_canister_id {
         return Err(ApiError {
             status_code: StatusCode::BAD_REQUEST,
+            code: ApiErrorCode::InvalidRequest,
             message: format!(
                 "Delegation's signing canister {} does not match II canister id {}",
                 cs_pk.canister_id, expected_ii_canister_id
@@ -151,6 +156,7 @@ pub async fn verify_internet_identity_signature(
     )
     .map_err(|e| ApiError {
         status_code: StatusCode::BAD_REQUEST,
+        code: ApiErrorCode::InvalidRequest,
         message: format!("Invalid canister signature: {e}"),
     })?;
 
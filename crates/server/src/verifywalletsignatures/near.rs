@@ -12,7 +12,7 @@ use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use tracing::info;
 
-use crate::admin::service::ApiError;
+use crate::admin::service::{ApiError, ApiErrorCode};
 
 /// A generic structure for NEAR JSON-RPC responses.
 ///
@@ -223,12 +223,14 @@ pub async fn has_near_key(
         .await
         .map_err(|e| ApiError {
             status_code: StatusCode::BAD_REQUEST,
+            code: ApiErrorCode::InvalidRequest,
             message: format!("Request failed: {e}"),
         })?
         .json::<NearJsonRpcResponse<ResultDataWithPermission>>()
         .await
         .map_err(|e| ApiError {
             status_code: StatusCode::BAD_REQUEST,
+            code: ApiErrorCode::InvalidRequest,
             message: format!("Failed to parse response: {e}"),
         })?;
 
@@ -237,6 +239,7 @@ pub async fn has_near_key(
         info!("Top-level error found: {:?}", error);
         return Err(ApiError {
             status_code: StatusCode::BAD_REQUEST,
+            code: ApiErrorCode::InvalidRequest,
             message: format!("Top-level error: {}", error.message),
         });
     }
@@ -247,6 +250,7 @@ pub async fn has_near_key(
             info!("Error within result: {}", error);
             return Err(ApiError {
                 status_code: StatusCode::BAD_REQUEST,
+                code: ApiErrorCode::InvalidRequest,
                 message: format!("Result error: {error}"),
             });
         }
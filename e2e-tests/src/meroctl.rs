@@ -133,6 +133,40 @@ impl Meroctl {
         Ok(data)
     }
 
+    pub async fn context_invite_expiring(
+        &self,
+        node_name: &str,
+        context_id: &str,
+        inviter_public_key: &str,
+        invitee_public_key: &str,
+        expires_in_secs: u64,
+    ) -> EyreResult<String> {
+        let json = self
+            .run_cmd(
+                node_name,
+                [
+                    "context",
+                    "invite",
+                    "--context",
+                    context_id,
+                    invitee_public_key,
+                    "--as",
+                    inviter_public_key,
+                    "--expires-in-secs",
+                    &expires_in_secs.to_string(),
+                ],
+            )
+            .await?;
+
+        let data = self
+            .remove_value_from_object(json, "data")?
+            .as_str()
+            .ok_or_eyre("data is not string")?
+            .to_owned();
+
+        Ok(data)
+    }
+
     pub async fn context_join(
         &self,
         node_name: &str,
@@ -1,6 +1,7 @@
 use application_install::ApplicationInstallStep;
 use context_create::ContextCreateStep;
 use context_create_alias::ContextCreateAliasStep;
+use context_invite_expired::ContextInviteExpiredStep;
 use context_invite_join::ContextInviteJoinStep;
 use eyre::Result as EyreResult;
 use get_proposals::GetProposalsStep;
@@ -14,6 +15,7 @@ use crate::driver::{Test, TestContext};
 mod application_install;
 mod context_create;
 mod context_create_alias;
+mod context_invite_expired;
 mod context_invite_join;
 mod get_proposals;
 mod jsonrpc_call;
@@ -33,6 +35,7 @@ pub enum TestStep {
     ContextCreate(ContextCreateStep),
     ContextCreateAlias(ContextCreateAliasStep),
     ContextInviteJoin(ContextInviteJoinStep),
+    ContextInviteExpired(ContextInviteExpiredStep),
     Call(CallStep),
     Wait(WaitStep),
     VerifyExternalState(VerifyExternalStateStep),
@@ -46,6 +49,7 @@ impl Test for TestStep {
             Self::ContextCreate(step) => step.display_name(),
             Self::ContextCreateAlias(step) => step.display_name(),
             Self::ContextInviteJoin(step) => step.display_name(),
+            Self::ContextInviteExpired(step) => step.display_name(),
             Self::Call(step) => step.display_name(),
             Self::Wait(step) => step.display_name(),
             Self::VerifyExternalState(step) => step.display_name(),
@@ -59,6 +63,7 @@ impl Test for TestStep {
             Self::ContextCreate(step) => step.run_assert(ctx).await,
             Self::ContextCreateAlias(step) => step.run_assert(ctx).await,
             Self::ContextInviteJoin(step) => step.run_assert(ctx).await,
+            Self::ContextInviteExpired(step) => step.run_assert(ctx).await,
             Self::Call(step) => step.run_assert(ctx).await,
             Self::Wait(step) => step.run_assert(ctx).await,
             Self::VerifyExternalState(step) => step.run_assert(ctx).await,
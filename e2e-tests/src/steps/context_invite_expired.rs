@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use eyre::{bail, Result as EyreResult};
+use serde::{Deserialize, Serialize};
+use tokio::time;
+
+use crate::driver::{Test, TestContext};
+
+/// Mints an invitation with a short TTL, waits past the deadline, and
+/// asserts that joining with it is refused.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContextInviteExpiredStep {
+    /// Seconds until the minted invitation lapses.
+    pub expires_in_secs: u64,
+}
+
+impl Test for ContextInviteExpiredStep {
+    fn display_name(&self) -> String {
+        "ctx invite-expired".to_owned()
+    }
+
+    async fn run_assert(&self, ctx: &mut TestContext<'_>) -> EyreResult<()> {
+        let Some(ref context_id) = ctx.context_id else {
+            bail!("Context ID is required for ContextInviteExpiredStep");
+        };
+
+        let Some(ref inviter_public_key) = ctx.inviter_public_key else {
+            bail!("Inviter public key is required for ContextInviteExpiredStep");
+        };
+
+        let Some(invitee) = ctx.invitees.first() else {
+            bail!("An invitee node is required for ContextInviteExpiredStep");
+        };
+
+        let (invitee_public_key, invitee_private_key) =
+            ctx.meroctl.identity_generate(invitee).await?;
+
+        let invitation_payload = ctx
+            .meroctl
+            .context_invite_expiring(
+                &ctx.inviter,
+                context_id,
+                inviter_public_key,
+                &invitee_public_key,
+                self.expires_in_secs,
+            )
+            .await?;
+
+        ctx.output_writer.write_str(&format!(
+            "Report: invitation minted with a {}s TTL; waiting for it to lapse",
+            self.expires_in_secs
+        ));
+
+        time::sleep(Duration::from_secs(self.expires_in_secs + 1)).await;
+
+        match ctx
+            .meroctl
+            .context_join(invitee, &invitee_private_key, &invitation_payload)
+            .await
+        {
+            Ok(_) => bail!("joining with an expired invitation should have been refused"),
+            Err(_) => {
+                ctx.output_writer
+                    .write_str("Report: expired invitation was refused");
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+#!/bin/bash
+# Bash completion for `merod config` key names.
+#
+# Source this file (or drop it in /etc/bash_completion.d) to tab-complete the
+# dotted key namespace, e.g. `merod config discovery.relay.<TAB>`. Key names
+# are produced by the hidden `merod config --complete-keys` flag, so the list
+# always matches the schema compiled into the binary.
+
+_merod_config_keys() {
+    local cur prev words
+    cur="${COMP_WORDS[COMP_CWORD]}"
+
+    # Only complete key names for the `config` subcommand.
+    local seen_config=""
+    for word in "${COMP_WORDS[@]:1:COMP_CWORD-1}"; do
+        [[ "$word" == "config" ]] && seen_config=1
+    done
+    [[ -z "$seen_config" ]] && return 0
+
+    local keys
+    keys="$(merod config --complete-keys 2>/dev/null)" || return 0
+
+    COMPREPLY=($(compgen -W "$keys" -- "${cur%%=*}"))
+
+    # Keys take a `=<value>` suffix; don't add a trailing space.
+    [[ ${#COMPREPLY[@]} -eq 1 ]] && compopt -o nospace 2>/dev/null
+}
+
+complete -o default -F _merod_config_keys merod